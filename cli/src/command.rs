@@ -0,0 +1,400 @@
+//! Subcommand implementations.
+
+use {
+    crate::{read_keypair, Context},
+    solana_loader_v4_program::instruction,
+    solana_loader_v4_program_client::{
+        accounts::LoaderV4ProgramAccount,
+        plan::{plan_deploy, plan_upgrade},
+    },
+    solana_sdk::{
+        account::{from_account, Account},
+        hash::Hash,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        rent::Rent,
+        signature::{write_keypair_file, Keypair, Signer},
+        sysvar,
+        transaction::Transaction,
+    },
+    std::{error::Error, fs, path::Path},
+};
+
+type CommandResult = Result<(), Box<dyn Error>>;
+
+pub fn deploy(
+    context: &Context,
+    program_path: &Path,
+    program_keypair: Option<&str>,
+    authority: Option<&str>,
+) -> CommandResult {
+    let elf = fs::read(program_path)?;
+    let program = match program_keypair {
+        Some(path) => read_keypair(path)?,
+        None => new_program_keypair(program_path)?,
+    };
+    let authority = context.authority(authority)?;
+
+    let plan = plan_deploy(
+        &elf,
+        &program.pubkey(),
+        &authority.pubkey(),
+        &context.payer.pubkey(),
+        &get_rent(context)?,
+    )?;
+    for instructions in &plan.transactions {
+        send(context, instructions, &[&program, &authority])?;
+    }
+
+    println!("Program Id: {}", program.pubkey());
+    Ok(())
+}
+
+/// Generates the keypair of a new program account and saves it next to the
+/// program ELF before anything is sent, so that a deployment failing midway
+/// can be resumed or its account closed.
+fn new_program_keypair(program_path: &Path) -> Result<Keypair, Box<dyn Error>> {
+    let stem = program_path
+        .file_stem()
+        .ok_or_else(|| format!("Invalid program path {}", program_path.display()))?;
+    let mut file_name = stem.to_os_string();
+    file_name.push("-keypair.json");
+    let path = program_path.with_file_name(file_name);
+    if path.exists() {
+        return Err(format!(
+            "{} already exists, pass it with --program-keypair",
+            path.display()
+        )
+        .into());
+    }
+
+    let program = Keypair::new();
+    write_keypair_file(&program, &path)
+        .map_err(|err| format!("Unable to write keypair {}: {err}", path.display()))?;
+    println!("Program keypair saved to {}", path.display());
+    Ok(program)
+}
+
+pub fn upgrade(
+    context: &Context,
+    program_id: &Pubkey,
+    program_path: &Path,
+    authority: Option<&str>,
+) -> CommandResult {
+    let elf = fs::read(program_path)?;
+    let authority = context.authority(authority)?;
+    let account = get_program_account(context, program_id)?;
+    let program_account = LoaderV4ProgramAccount::from_bytes(&account.data)?;
+    let buffer = Keypair::new();
+
+    let plan = plan_upgrade(
+        &elf,
+        program_id,
+        &program_account,
+        account.lamports,
+        &buffer.pubkey(),
+        &authority.pubkey(),
+        &context.payer.pubkey(),
+        &get_rent(context)?,
+    )?;
+    let slot = context.rpc_client.get_slot()?;
+    if slot < plan.earliest_slot {
+        return Err(format!(
+            "Program was deployed recently, cooldown still in effect until slot {}",
+            plan.earliest_slot
+        )
+        .into());
+    }
+    for instructions in &plan.transactions {
+        send(context, instructions, &[&buffer, &authority])?;
+    }
+
+    println!("Program Id: {program_id}");
+    Ok(())
+}
+
+pub fn retract(context: &Context, program_id: &Pubkey, authority: Option<&str>) -> CommandResult {
+    let authority = context.authority(authority)?;
+    send(
+        context,
+        &[instruction::retract(program_id, &authority.pubkey())],
+        &[&authority],
+    )
+}
+
+pub fn close(
+    context: &Context,
+    program_id: &Pubkey,
+    recipient: Option<Pubkey>,
+    authority: Option<&str>,
+) -> CommandResult {
+    let authority = context.authority(authority)?;
+    let recipient = recipient.unwrap_or_else(|| context.payer.pubkey());
+    send(
+        context,
+        &[instruction::close(
+            program_id,
+            &authority.pubkey(),
+            &recipient,
+        )],
+        &[&authority],
+    )
+}
+
+pub fn set_authority(
+    context: &Context,
+    program_id: &Pubkey,
+    new_authority: &str,
+    authority: Option<&str>,
+) -> CommandResult {
+    let authority = context.authority(authority)?;
+    let new_authority = read_keypair(new_authority)?;
+    send(
+        context,
+        &[instruction::transfer_authority(
+            program_id,
+            &authority.pubkey(),
+            &new_authority.pubkey(),
+        )],
+        &[&authority, &new_authority],
+    )
+}
+
+pub fn finalize(
+    context: &Context,
+    program_id: &Pubkey,
+    next_version: Option<Pubkey>,
+    authority: Option<&str>,
+) -> CommandResult {
+    let authority = context.authority(authority)?;
+    let next_version = next_version.unwrap_or(*program_id);
+    send(
+        context,
+        &[instruction::finalize(
+            program_id,
+            &authority.pubkey(),
+            &next_version,
+        )],
+        &[&authority],
+    )
+}
+
+pub fn show(context: &Context, program_id: &Pubkey) -> CommandResult {
+    let account = get_program_account(context, program_id)?;
+    let program_account = LoaderV4ProgramAccount::from_bytes(&account.data)?;
+
+    println!("Program Id: {program_id}");
+    println!("Status: {:?}", program_account.status);
+    if let Some(next_version) = program_account.next_version() {
+        println!("Next Version: {next_version}");
+    } else {
+        println!(
+            "Authority: {}",
+            program_account.authority_address_or_next_version
+        );
+    }
+    println!("Last Deployed In Slot: {}", program_account.slot);
+    println!("Data Length: {} bytes", program_account.program_data.len());
+    println!("Balance: {} lamports", account.lamports);
+    Ok(())
+}
+
+pub fn dump(context: &Context, program_id: &Pubkey, output_path: &Path) -> CommandResult {
+    let account = get_program_account(context, program_id)?;
+    let program_data = LoaderV4ProgramAccount::from_bytes(&account.data)?.program_data;
+    fs::write(output_path, &program_data)?;
+
+    println!(
+        "Wrote {} bytes to {}",
+        program_data.len(),
+        output_path.display()
+    );
+    Ok(())
+}
+
+fn get_program_account(context: &Context, program_id: &Pubkey) -> Result<Account, Box<dyn Error>> {
+    let account = context.rpc_client.get_account(program_id)?;
+    if account.owner != solana_loader_v4_program::id() {
+        return Err(format!("{program_id} is not owned by the loader").into());
+    }
+    Ok(account)
+}
+
+fn get_rent(context: &Context) -> Result<Rent, Box<dyn Error>> {
+    let account = context.rpc_client.get_account(&sysvar::rent::id())?;
+    from_account(&account).ok_or_else(|| "Invalid rent sysvar".into())
+}
+
+/// Signs a transaction with the payer and whichever of `signers` it requires.
+fn sign(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    blockhash: Hash,
+) -> Result<Transaction, Box<dyn Error>> {
+    let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+    let required = transaction.message.signer_keys();
+    let mut keypairs = vec![payer];
+    for signer in signers {
+        let pubkey = signer.pubkey();
+        if required.contains(&&pubkey) && keypairs.iter().all(|k| k.pubkey() != pubkey) {
+            keypairs.push(*signer);
+        }
+    }
+    transaction.try_sign(&keypairs[..], blockhash)?;
+    Ok(transaction)
+}
+
+/// Signs a transaction with the payer and whichever of `signers` it requires,
+/// then sends it.
+fn send(context: &Context, instructions: &[Instruction], signers: &[&Keypair]) -> CommandResult {
+    let blockhash = context.rpc_client.get_latest_blockhash()?;
+    let transaction = sign(instructions, &context.payer, signers, blockhash)?;
+
+    let signature = context
+        .rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)?;
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_loader_v4_program::instruction::LoaderV4Instruction,
+        solana_loader_v4_program_client::{accounts::LoaderV4Status, plan::PACKET_DATA_SIZE},
+        solana_sdk::program_utils::limited_deserialize,
+    };
+
+    fn assert_fits(transaction: &Transaction) {
+        let signatures = transaction.signatures.len();
+        // A short vector length prefix of one byte.
+        let size = 1 + signatures * 64 + transaction.message.serialize().len();
+        assert!(size <= PACKET_DATA_SIZE);
+    }
+
+    #[test]
+    fn sign_deploy_plan() {
+        let payer = Keypair::new();
+        let program = Keypair::new();
+        let authority = Keypair::new();
+
+        let plan = plan_deploy(
+            &vec![1; 50_000],
+            &program.pubkey(),
+            &authority.pubkey(),
+            &payer.pubkey(),
+            &Rent::default(),
+        )
+        .unwrap();
+        for (i, instructions) in plan.transactions.iter().enumerate() {
+            let transaction = sign(
+                instructions,
+                &payer,
+                &[&program, &authority],
+                Hash::default(),
+            )
+            .unwrap();
+            transaction.verify().unwrap();
+            assert_fits(&transaction);
+            // Only the creation of the program requires its signature.
+            assert_eq!(
+                transaction
+                    .message
+                    .signer_keys()
+                    .contains(&&program.pubkey()),
+                i == 0
+            );
+        }
+
+        // The program keypair cannot be left out of the creation.
+        assert!(sign(
+            &plan.transactions[0],
+            &payer,
+            &[&authority],
+            Hash::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn sign_upgrade_plan() {
+        let payer = Keypair::new();
+        let program = Pubkey::new_unique();
+        let buffer = Keypair::new();
+        let program_account = LoaderV4ProgramAccount {
+            slot: 0,
+            authority_address_or_next_version: payer.pubkey(),
+            status: LoaderV4Status::Deployed,
+            extensions: None,
+            program_data: vec![0; 2_000],
+        };
+        let rent = Rent::default();
+
+        // The payer is also the authority, so that it signs only once.
+        let plan = plan_upgrade(
+            &[5; 3_000],
+            &program,
+            &program_account,
+            rent.minimum_balance(48 + 2_000),
+            &buffer.pubkey(),
+            &payer.pubkey(),
+            &payer.pubkey(),
+            &rent,
+        )
+        .unwrap();
+        for instructions in &plan.transactions {
+            let transaction =
+                sign(instructions, &payer, &[&buffer, &payer], Hash::default()).unwrap();
+            transaction.verify().unwrap();
+            assert_fits(&transaction);
+            assert!(transaction.signatures.len() <= 2);
+        }
+    }
+
+    #[test]
+    fn save_new_program_keypair() {
+        let dir = std::env::temp_dir().join(Pubkey::new_unique().to_string());
+        fs::create_dir(&dir).unwrap();
+        let program_path = dir.join("program.so");
+
+        let program = new_program_keypair(&program_path).unwrap();
+        let saved = read_keypair(dir.join("program-keypair.json").to_str().unwrap()).unwrap();
+        assert_eq!(saved.pubkey(), program.pubkey());
+
+        // An existing keypair is never overwritten.
+        assert!(new_program_keypair(&program_path).is_err());
+        let saved = read_keypair(dir.join("program-keypair.json").to_str().unwrap()).unwrap();
+        assert_eq!(saved.pubkey(), program.pubkey());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sign_close() {
+        let payer = Keypair::new();
+        let authority = Keypair::new();
+        let program = Pubkey::new_unique();
+
+        let transaction = sign(
+            &[instruction::close(
+                &program,
+                &authority.pubkey(),
+                &payer.pubkey(),
+            )],
+            &payer,
+            &[&authority],
+            Hash::default(),
+        )
+        .unwrap();
+        transaction.verify().unwrap();
+        assert_eq!(transaction.signatures.len(), 2);
+
+        let instruction = &transaction.message.instructions[0];
+        assert_eq!(
+            limited_deserialize::<LoaderV4Instruction>(&instruction.data).unwrap(),
+            LoaderV4Instruction::Close
+        );
+    }
+}
@@ -0,0 +1,343 @@
+//! Command-line tool for managing Solana BPF Loader v4 programs.
+
+mod command;
+
+use {
+    clap::{Parser, Subcommand},
+    solana_cli_config::{Config, CONFIG_FILE},
+    solana_rpc_client::rpc_client::RpcClient,
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair},
+    },
+    std::{error::Error, path::PathBuf, process::exit},
+};
+
+/// Manage programs owned by the Solana BPF Loader v4.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Cli {
+    /// Configuration file to use.
+    #[arg(short = 'C', long, global = true)]
+    config: Option<String>,
+    /// URL of the Solana JSON RPC endpoint.
+    #[arg(short = 'u', long, global = true)]
+    url: Option<String>,
+    /// Keypair of the fee payer, also used as the default authority.
+    #[arg(short = 'k', long, global = true)]
+    keypair: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, PartialEq, Subcommand)]
+enum Command {
+    /// Deploy a program to a new program account.
+    Deploy {
+        /// Path to the program ELF.
+        program_path: PathBuf,
+        /// Keypair of the new program account [default: new keypair, saved
+        /// as <PROGRAM_NAME>-keypair.json next to the program ELF].
+        #[arg(long)]
+        program_keypair: Option<String>,
+        /// Keypair of the program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Upgrade a program through a staging buffer.
+    Upgrade {
+        /// Address of the program to upgrade.
+        program_id: Pubkey,
+        /// Path to the new program ELF.
+        program_path: PathBuf,
+        /// Keypair of the program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Retract a deployed program.
+    Retract {
+        /// Address of the program to retract.
+        program_id: Pubkey,
+        /// Keypair of the program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Retract a program if needed and close its account.
+    Close {
+        /// Address of the program to close.
+        program_id: Pubkey,
+        /// Recipient of the reclaimed lamports [default: fee payer].
+        #[arg(long)]
+        recipient: Option<Pubkey>,
+        /// Keypair of the program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Transfer the authority of a program.
+    SetAuthority {
+        /// Address of the program.
+        program_id: Pubkey,
+        /// Keypair of the new authority, which must sign.
+        new_authority: String,
+        /// Keypair of the current program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Finalize a program, making it immutable.
+    Finalize {
+        /// Address of the program to finalize.
+        program_id: Pubkey,
+        /// Address of the next version of the program [default: the program
+        /// itself].
+        #[arg(long)]
+        next_version: Option<Pubkey>,
+        /// Keypair of the program authority [default: fee payer].
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Show the state of a program account.
+    Show {
+        /// Address of the program.
+        program_id: Pubkey,
+    },
+    /// Write the program data of a program account to a file.
+    Dump {
+        /// Address of the program.
+        program_id: Pubkey,
+        /// Path of the output file.
+        output_path: PathBuf,
+    },
+}
+
+/// Shared state of all commands.
+pub struct Context {
+    pub rpc_client: RpcClient,
+    pub payer: Keypair,
+    keypair_path: String,
+}
+
+impl Context {
+    /// Reads the authority keypair, defaulting to the fee payer.
+    pub fn authority(&self, path: Option<&str>) -> Result<Keypair, Box<dyn Error>> {
+        read_keypair(path.unwrap_or(&self.keypair_path))
+    }
+}
+
+pub fn read_keypair(path: &str) -> Result<Keypair, Box<dyn Error>> {
+    read_keypair_file(path).map_err(|err| format!("Unable to read keypair {path}: {err}").into())
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let config = match cli.config.as_ref().or(CONFIG_FILE.as_ref()) {
+        Some(config_file) => Config::load(config_file).unwrap_or_default(),
+        None => Config::default(),
+    };
+    let keypair_path = cli.keypair.unwrap_or(config.keypair_path);
+    let context = Context {
+        rpc_client: RpcClient::new_with_commitment(
+            cli.url.unwrap_or(config.json_rpc_url),
+            CommitmentConfig::confirmed(),
+        ),
+        payer: read_keypair(&keypair_path)?,
+        keypair_path,
+    };
+
+    match cli.command {
+        Command::Deploy {
+            program_path,
+            program_keypair,
+            authority,
+        } => command::deploy(
+            &context,
+            &program_path,
+            program_keypair.as_deref(),
+            authority.as_deref(),
+        ),
+        Command::Upgrade {
+            program_id,
+            program_path,
+            authority,
+        } => command::upgrade(&context, &program_id, &program_path, authority.as_deref()),
+        Command::Retract {
+            program_id,
+            authority,
+        } => command::retract(&context, &program_id, authority.as_deref()),
+        Command::Close {
+            program_id,
+            recipient,
+            authority,
+        } => command::close(&context, &program_id, recipient, authority.as_deref()),
+        Command::SetAuthority {
+            program_id,
+            new_authority,
+            authority,
+        } => command::set_authority(&context, &program_id, &new_authority, authority.as_deref()),
+        Command::Finalize {
+            program_id,
+            next_version,
+            authority,
+        } => command::finalize(&context, &program_id, next_version, authority.as_deref()),
+        Command::Show { program_id } => command::show(&context, &program_id),
+        Command::Dump {
+            program_id,
+            output_path,
+        } => command::dump(&context, &program_id, &output_path),
+    }
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("Error: {err}");
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, clap::CommandFactory};
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from([&["loader-v4"][..], args].concat())
+    }
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse_deploy() {
+        let cli = parse(&["deploy", "program.so", "-k", "payer.json"]).unwrap();
+        assert_eq!(cli.keypair.as_deref(), Some("payer.json"));
+        assert_eq!(
+            cli.command,
+            Command::Deploy {
+                program_path: PathBuf::from("program.so"),
+                program_keypair: None,
+                authority: None,
+            }
+        );
+
+        let cli = parse(&[
+            "deploy",
+            "program.so",
+            "--program-keypair",
+            "program.json",
+            "--authority",
+            "authority.json",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Deploy {
+                program_path: PathBuf::from("program.so"),
+                program_keypair: Some("program.json".to_string()),
+                authority: Some("authority.json".to_string()),
+            }
+        );
+
+        assert!(parse(&["deploy"]).is_err());
+    }
+
+    #[test]
+    fn parse_upgrade_and_retract() {
+        let program_id = Pubkey::new_unique();
+
+        let cli = parse(&["upgrade", &program_id.to_string(), "program.so"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Upgrade {
+                program_id,
+                program_path: PathBuf::from("program.so"),
+                authority: None,
+            }
+        );
+
+        let cli = parse(&["retract", &program_id.to_string()]).unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Retract {
+                program_id,
+                authority: None,
+            }
+        );
+
+        assert!(parse(&["retract", "not-a-pubkey"]).is_err());
+    }
+
+    #[test]
+    fn parse_close() {
+        let program_id = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        let cli = parse(&[
+            "close",
+            &program_id.to_string(),
+            "--recipient",
+            &recipient.to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Close {
+                program_id,
+                recipient: Some(recipient),
+                authority: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_set_authority_and_finalize() {
+        let program_id = Pubkey::new_unique();
+        let next_version = Pubkey::new_unique();
+
+        let cli = parse(&["set-authority", &program_id.to_string(), "new.json"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Command::SetAuthority {
+                program_id,
+                new_authority: "new.json".to_string(),
+                authority: None,
+            }
+        );
+        // The new authority must sign, so a keypair is required.
+        assert!(parse(&["set-authority", &program_id.to_string()]).is_err());
+
+        let cli = parse(&[
+            "finalize",
+            &program_id.to_string(),
+            "--next-version",
+            &next_version.to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Finalize {
+                program_id,
+                next_version: Some(next_version),
+                authority: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_show_and_dump() {
+        let program_id = Pubkey::new_unique();
+
+        let cli = parse(&["-u", "localhost", "show", &program_id.to_string()]).unwrap();
+        assert_eq!(cli.url.as_deref(), Some("localhost"));
+        assert_eq!(cli.command, Command::Show { program_id });
+
+        let cli = parse(&["dump", &program_id.to_string(), "program.so"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Dump {
+                program_id,
+                output_path: PathBuf::from("program.so"),
+            }
+        );
+        assert!(parse(&["dump", &program_id.to_string()]).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+/** ProgramNotWritable: Program is not writeable */
+export const LOADER_V4_ERROR__PROGRAM_NOT_WRITABLE = 0x0; // 0
+/** ProgramNotRetracted: Program is not retracted */
+export const LOADER_V4_ERROR__PROGRAM_NOT_RETRACTED = 0x1; // 1
+/** ProgramNotDeployed: Program is not deployed */
+export const LOADER_V4_ERROR__PROGRAM_NOT_DEPLOYED = 0x2; // 2
+/** DeploymentCooldown: Program was deployed recently, cooldown still in effect */
+export const LOADER_V4_ERROR__DEPLOYMENT_COOLDOWN = 0x3; // 3
+/** SourceProgramNotRetracted: Source program is not retracted */
+export const LOADER_V4_ERROR__SOURCE_PROGRAM_NOT_RETRACTED = 0x4; // 4
+/** DestinationNotWritable: Recipient is not writeable */
+export const LOADER_V4_ERROR__DESTINATION_NOT_WRITABLE = 0x5; // 5
+/** AuthorityUnchanged: No change */
+export const LOADER_V4_ERROR__AUTHORITY_UNCHANGED = 0x6; // 6
+/** InvalidElf: Program data is not a valid ELF */
+export const LOADER_V4_ERROR__INVALID_ELF = 0x7; // 7
+/** InvalidProgramState: Program account state is corrupt */
+export const LOADER_V4_ERROR__INVALID_PROGRAM_STATE = 0x8; // 8
+/** ProgramDataHashMismatch: Program data does not match the expected hash */
+export const LOADER_V4_ERROR__PROGRAM_DATA_HASH_MISMATCH = 0x9; // 9
+/** NoPendingAuthority: No pending authority */
+export const LOADER_V4_ERROR__NO_PENDING_AUTHORITY = 0xa; // 10
+/** ScheduledDeployNotDue: Scheduled deployment is not due yet */
+export const LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_DUE = 0xb; // 11
+/** NoScheduledDeploy: No scheduled deployment */
+export const LOADER_V4_ERROR__NO_SCHEDULED_DEPLOY = 0xc; // 12
+/** SourceIsProgram: Source program must differ from the program */
+export const LOADER_V4_ERROR__SOURCE_IS_PROGRAM = 0xd; // 13
+/** IncorrectSourceProgram: Incorrect source program provided */
+export const LOADER_V4_ERROR__INCORRECT_SOURCE_PROGRAM = 0xe; // 14
+/** ScheduledDeployNotInFuture: Scheduled deployment must be in a future slot */
+export const LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_IN_FUTURE = 0xf; // 15
+/** DeployScheduled: Program has a scheduled deployment */
+export const LOADER_V4_ERROR__DEPLOY_SCHEDULED = 0x10; // 16
+/** DestinationIsProgram: Destination must differ from the program */
+export const LOADER_V4_ERROR__DESTINATION_IS_PROGRAM = 0x11; // 17
+/** CreateSizeTooLarge: Program account is too large to create */
+export const LOADER_V4_ERROR__CREATE_SIZE_TOO_LARGE = 0x12; // 18
+/** InvalidMultisig: Invalid multisig */
+export const LOADER_V4_ERROR__INVALID_MULTISIG = 0x13; // 19
+/** NoMultisig: No multisig */
+export const LOADER_V4_ERROR__NO_MULTISIG = 0x14; // 20
+/** ProposalNotApproved: Proposal is not approved */
+export const LOADER_V4_ERROR__PROPOSAL_NOT_APPROVED = 0x15; // 21
+/** AlreadyApproved: Already approved */
+export const LOADER_V4_ERROR__ALREADY_APPROVED = 0x16; // 22
+/** ProposalNotWritable: Proposal is not writeable */
+export const LOADER_V4_ERROR__PROPOSAL_NOT_WRITABLE = 0x17; // 23
+/** IncorrectProposal: Proposal is for another program */
+export const LOADER_V4_ERROR__INCORRECT_PROPOSAL = 0x18; // 24
+/** IncorrectPayer: Incorrect payer provided */
+export const LOADER_V4_ERROR__INCORRECT_PAYER = 0x19; // 25
+/** NoWriter: No writer */
+export const LOADER_V4_ERROR__NO_WRITER = 0x1a; // 26
+/** InvalidWriter: Writer must differ from the program and its authority */
+export const LOADER_V4_ERROR__INVALID_WRITER = 0x1b; // 27
+
+export type LoaderV4Error =
+  | typeof LOADER_V4_ERROR__PROGRAM_NOT_WRITABLE
+  | typeof LOADER_V4_ERROR__PROGRAM_NOT_RETRACTED
+  | typeof LOADER_V4_ERROR__PROGRAM_NOT_DEPLOYED
+  | typeof LOADER_V4_ERROR__DEPLOYMENT_COOLDOWN
+  | typeof LOADER_V4_ERROR__SOURCE_PROGRAM_NOT_RETRACTED
+  | typeof LOADER_V4_ERROR__DESTINATION_NOT_WRITABLE
+  | typeof LOADER_V4_ERROR__AUTHORITY_UNCHANGED
+  | typeof LOADER_V4_ERROR__INVALID_ELF
+  | typeof LOADER_V4_ERROR__INVALID_PROGRAM_STATE
+  | typeof LOADER_V4_ERROR__PROGRAM_DATA_HASH_MISMATCH
+  | typeof LOADER_V4_ERROR__NO_PENDING_AUTHORITY
+  | typeof LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_DUE
+  | typeof LOADER_V4_ERROR__NO_SCHEDULED_DEPLOY
+  | typeof LOADER_V4_ERROR__SOURCE_IS_PROGRAM
+  | typeof LOADER_V4_ERROR__INCORRECT_SOURCE_PROGRAM
+  | typeof LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_IN_FUTURE
+  | typeof LOADER_V4_ERROR__DEPLOY_SCHEDULED
+  | typeof LOADER_V4_ERROR__DESTINATION_IS_PROGRAM
+  | typeof LOADER_V4_ERROR__CREATE_SIZE_TOO_LARGE
+  | typeof LOADER_V4_ERROR__INVALID_MULTISIG
+  | typeof LOADER_V4_ERROR__NO_MULTISIG
+  | typeof LOADER_V4_ERROR__PROPOSAL_NOT_APPROVED
+  | typeof LOADER_V4_ERROR__ALREADY_APPROVED
+  | typeof LOADER_V4_ERROR__PROPOSAL_NOT_WRITABLE
+  | typeof LOADER_V4_ERROR__INCORRECT_PROPOSAL
+  | typeof LOADER_V4_ERROR__INCORRECT_PAYER
+  | typeof LOADER_V4_ERROR__NO_WRITER
+  | typeof LOADER_V4_ERROR__INVALID_WRITER;
+
+let loaderV4ErrorMessages: Record<LoaderV4Error, string> | undefined;
+if (__DEV__) {
+  loaderV4ErrorMessages = {
+    [LOADER_V4_ERROR__ALREADY_APPROVED]: `Already approved`,
+    [LOADER_V4_ERROR__AUTHORITY_UNCHANGED]: `No change`,
+    [LOADER_V4_ERROR__CREATE_SIZE_TOO_LARGE]: `Program account is too large to create`,
+    [LOADER_V4_ERROR__DEPLOYMENT_COOLDOWN]: `Program was deployed recently, cooldown still in effect`,
+    [LOADER_V4_ERROR__DEPLOY_SCHEDULED]: `Program has a scheduled deployment`,
+    [LOADER_V4_ERROR__DESTINATION_IS_PROGRAM]: `Destination must differ from the program`,
+    [LOADER_V4_ERROR__DESTINATION_NOT_WRITABLE]: `Recipient is not writeable`,
+    [LOADER_V4_ERROR__INCORRECT_PAYER]: `Incorrect payer provided`,
+    [LOADER_V4_ERROR__INCORRECT_PROPOSAL]: `Proposal is for another program`,
+    [LOADER_V4_ERROR__INCORRECT_SOURCE_PROGRAM]: `Incorrect source program provided`,
+    [LOADER_V4_ERROR__INVALID_ELF]: `Program data is not a valid ELF`,
+    [LOADER_V4_ERROR__INVALID_MULTISIG]: `Invalid multisig`,
+    [LOADER_V4_ERROR__INVALID_PROGRAM_STATE]: `Program account state is corrupt`,
+    [LOADER_V4_ERROR__INVALID_WRITER]: `Writer must differ from the program and its authority`,
+    [LOADER_V4_ERROR__NO_MULTISIG]: `No multisig`,
+    [LOADER_V4_ERROR__NO_PENDING_AUTHORITY]: `No pending authority`,
+    [LOADER_V4_ERROR__NO_SCHEDULED_DEPLOY]: `No scheduled deployment`,
+    [LOADER_V4_ERROR__NO_WRITER]: `No writer`,
+    [LOADER_V4_ERROR__PROGRAM_DATA_HASH_MISMATCH]: `Program data does not match the expected hash`,
+    [LOADER_V4_ERROR__PROGRAM_NOT_DEPLOYED]: `Program is not deployed`,
+    [LOADER_V4_ERROR__PROGRAM_NOT_RETRACTED]: `Program is not retracted`,
+    [LOADER_V4_ERROR__PROGRAM_NOT_WRITABLE]: `Program is not writeable`,
+    [LOADER_V4_ERROR__PROPOSAL_NOT_APPROVED]: `Proposal is not approved`,
+    [LOADER_V4_ERROR__PROPOSAL_NOT_WRITABLE]: `Proposal is not writeable`,
+    [LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_DUE]: `Scheduled deployment is not due yet`,
+    [LOADER_V4_ERROR__SCHEDULED_DEPLOY_NOT_IN_FUTURE]: `Scheduled deployment must be in a future slot`,
+    [LOADER_V4_ERROR__SOURCE_IS_PROGRAM]: `Source program must differ from the program`,
+    [LOADER_V4_ERROR__SOURCE_PROGRAM_NOT_RETRACTED]: `Source program is not retracted`,
+  };
+}
+
+export function getLoaderV4ErrorMessage(code: LoaderV4Error): string {
+  if (__DEV__) {
+    return (loaderV4ErrorMessages as Record<LoaderV4Error, string>)[code];
+  }
+
+  return 'Error message not available in production bundles.';
+}
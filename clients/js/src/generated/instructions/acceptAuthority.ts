@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ACCEPT_AUTHORITY_DISCRIMINATOR = 12;
+
+export function getAcceptAuthorityDiscriminatorBytes() {
+  return getU32Encoder().encode(ACCEPT_AUTHORITY_DISCRIMINATOR);
+}
+
+export type AcceptAuthorityInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountProposedAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountProposedAuthority extends string
+        ? ReadonlySignerAccount<TAccountProposedAuthority> &
+            IAccountSignerMeta<TAccountProposedAuthority>
+        : TAccountProposedAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AcceptAuthorityInstructionData = { discriminator: number };
+
+export type AcceptAuthorityInstructionDataArgs = {};
+
+export function getAcceptAuthorityInstructionDataEncoder(): Encoder<AcceptAuthorityInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: ACCEPT_AUTHORITY_DISCRIMINATOR })
+  );
+}
+
+export function getAcceptAuthorityInstructionDataDecoder(): Decoder<AcceptAuthorityInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getAcceptAuthorityInstructionDataCodec(): Codec<
+  AcceptAuthorityInstructionDataArgs,
+  AcceptAuthorityInstructionData
+> {
+  return combineCodec(
+    getAcceptAuthorityInstructionDataEncoder(),
+    getAcceptAuthorityInstructionDataDecoder()
+  );
+}
+
+export type AcceptAuthorityInput<
+  TAccountProgram extends string = string,
+  TAccountProposedAuthority extends string = string,
+> = {
+  /** Program account to change the authority of. */
+  program: Address<TAccountProgram>;
+  /** Proposed program authority. */
+  proposedAuthority: TransactionSigner<TAccountProposedAuthority>;
+};
+
+export function getAcceptAuthorityInstruction<
+  TAccountProgram extends string,
+  TAccountProposedAuthority extends string,
+>(
+  input: AcceptAuthorityInput<TAccountProgram, TAccountProposedAuthority>
+): AcceptAuthorityInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountProposedAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    proposedAuthority: {
+      value: input.proposedAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.proposedAuthority),
+    ],
+    programAddress,
+    data: getAcceptAuthorityInstructionDataEncoder().encode({}),
+  } as AcceptAuthorityInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountProposedAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedAcceptAuthorityInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to change the authority of. */
+    program: TAccountMetas[0];
+    /** Proposed program authority. */
+    proposedAuthority: TAccountMetas[1];
+  };
+  data: AcceptAuthorityInstructionData;
+};
+
+export function parseAcceptAuthorityInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAcceptAuthorityInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      proposedAuthority: getNextAccount(),
+    },
+    data: getAcceptAuthorityInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,189 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const APPROVE_DISCRIMINATOR = 23;
+
+export function getApproveDiscriminatorBytes() {
+  return getU32Encoder().encode(APPROVE_DISCRIMINATOR);
+}
+
+export type ApproveInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountApprover extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountApprover extends string
+        ? ReadonlySignerAccount<TAccountApprover> &
+            IAccountSignerMeta<TAccountApprover>
+        : TAccountApprover,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ApproveInstructionData = { discriminator: number };
+
+export type ApproveInstructionDataArgs = {};
+
+export function getApproveInstructionDataEncoder(): Encoder<ApproveInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: APPROVE_DISCRIMINATOR })
+  );
+}
+
+export function getApproveInstructionDataDecoder(): Decoder<ApproveInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getApproveInstructionDataCodec(): Codec<
+  ApproveInstructionDataArgs,
+  ApproveInstructionData
+> {
+  return combineCodec(
+    getApproveInstructionDataEncoder(),
+    getApproveInstructionDataDecoder()
+  );
+}
+
+export type ApproveInput<
+  TAccountProgram extends string = string,
+  TAccountProposal extends string = string,
+  TAccountApprover extends string = string,
+> = {
+  /** Program account of the proposal. */
+  program: Address<TAccountProgram>;
+  /** Proposal account. */
+  proposal: Address<TAccountProposal>;
+  /** Member of the authority approving the deployment. */
+  approver: TransactionSigner<TAccountApprover>;
+};
+
+export function getApproveInstruction<
+  TAccountProgram extends string,
+  TAccountProposal extends string,
+  TAccountApprover extends string,
+>(
+  input: ApproveInput<TAccountProgram, TAccountProposal, TAccountApprover>
+): ApproveInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountProposal,
+  TAccountApprover
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    approver: { value: input.approver ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.approver),
+    ],
+    programAddress,
+    data: getApproveInstructionDataEncoder().encode({}),
+  } as ApproveInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountProposal,
+    TAccountApprover
+  >;
+
+  return instruction;
+}
+
+export type ParsedApproveInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account of the proposal. */
+    program: TAccountMetas[0];
+    /** Proposal account. */
+    proposal: TAccountMetas[1];
+    /** Member of the authority approving the deployment. */
+    approver: TAccountMetas[2];
+  };
+  data: ApproveInstructionData;
+};
+
+export function parseApproveInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedApproveInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      proposal: getNextAccount(),
+      approver: getNextAccount(),
+    },
+    data: getApproveInstructionDataDecoder().decode(instruction.data),
+  };
+}
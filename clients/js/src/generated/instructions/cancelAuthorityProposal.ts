@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CANCEL_AUTHORITY_PROPOSAL_DISCRIMINATOR = 13;
+
+export function getCancelAuthorityProposalDiscriminatorBytes() {
+  return getU32Encoder().encode(CANCEL_AUTHORITY_PROPOSAL_DISCRIMINATOR);
+}
+
+export type CancelAuthorityProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrentAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountCurrentAuthority extends string
+        ? ReadonlySignerAccount<TAccountCurrentAuthority> &
+            IAccountSignerMeta<TAccountCurrentAuthority>
+        : TAccountCurrentAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelAuthorityProposalInstructionData = { discriminator: number };
+
+export type CancelAuthorityProposalInstructionDataArgs = {};
+
+export function getCancelAuthorityProposalInstructionDataEncoder(): Encoder<CancelAuthorityProposalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: CANCEL_AUTHORITY_PROPOSAL_DISCRIMINATOR })
+  );
+}
+
+export function getCancelAuthorityProposalInstructionDataDecoder(): Decoder<CancelAuthorityProposalInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getCancelAuthorityProposalInstructionDataCodec(): Codec<
+  CancelAuthorityProposalInstructionDataArgs,
+  CancelAuthorityProposalInstructionData
+> {
+  return combineCodec(
+    getCancelAuthorityProposalInstructionDataEncoder(),
+    getCancelAuthorityProposalInstructionDataDecoder()
+  );
+}
+
+export type CancelAuthorityProposalInput<
+  TAccountProgram extends string = string,
+  TAccountCurrentAuthority extends string = string,
+> = {
+  /** Program account with a pending authority proposal. */
+  program: Address<TAccountProgram>;
+  /** Current program authority. */
+  currentAuthority: TransactionSigner<TAccountCurrentAuthority>;
+};
+
+export function getCancelAuthorityProposalInstruction<
+  TAccountProgram extends string,
+  TAccountCurrentAuthority extends string,
+>(
+  input: CancelAuthorityProposalInput<TAccountProgram, TAccountCurrentAuthority>
+): CancelAuthorityProposalInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountCurrentAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    currentAuthority: {
+      value: input.currentAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.currentAuthority),
+    ],
+    programAddress,
+    data: getCancelAuthorityProposalInstructionDataEncoder().encode({}),
+  } as CancelAuthorityProposalInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountCurrentAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelAuthorityProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account with a pending authority proposal. */
+    program: TAccountMetas[0];
+    /** Current program authority. */
+    currentAuthority: TAccountMetas[1];
+  };
+  data: CancelAuthorityProposalInstructionData;
+};
+
+export function parseCancelAuthorityProposalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelAuthorityProposalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      currentAuthority: getNextAccount(),
+    },
+    data: getCancelAuthorityProposalInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,208 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CANCEL_PROPOSAL_DISCRIMINATOR = 25;
+
+export function getCancelProposalDiscriminatorBytes() {
+  return getU32Encoder().encode(CANCEL_PROPOSAL_DISCRIMINATOR);
+}
+
+export type CancelProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelProposalInstructionData = { discriminator: number };
+
+export type CancelProposalInstructionDataArgs = {};
+
+export function getCancelProposalInstructionDataEncoder(): Encoder<CancelProposalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: CANCEL_PROPOSAL_DISCRIMINATOR })
+  );
+}
+
+export function getCancelProposalInstructionDataDecoder(): Decoder<CancelProposalInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getCancelProposalInstructionDataCodec(): Codec<
+  CancelProposalInstructionDataArgs,
+  CancelProposalInstructionData
+> {
+  return combineCodec(
+    getCancelProposalInstructionDataEncoder(),
+    getCancelProposalInstructionDataDecoder()
+  );
+}
+
+export type CancelProposalInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountProposal extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Program account of the proposal. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Proposal account. */
+  proposal: Address<TAccountProposal>;
+  /** Payer of the proposal account. */
+  payer: Address<TAccountPayer>;
+};
+
+export function getCancelProposalInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountProposal extends string,
+  TAccountPayer extends string,
+>(
+  input: CancelProposalInput<
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountProposal,
+    TAccountPayer
+  >
+): CancelProposalInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountProposal,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.payer),
+    ],
+    programAddress,
+    data: getCancelProposalInstructionDataEncoder().encode({}),
+  } as CancelProposalInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountProposal,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account of the proposal. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Proposal account. */
+    proposal: TAccountMetas[2];
+    /** Payer of the proposal account. */
+    payer: TAccountMetas[3];
+  };
+  data: CancelProposalInstructionData;
+};
+
+export function parseCancelProposalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelProposalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      proposal: getNextAccount(),
+      payer: getNextAccount(),
+    },
+    data: getCancelProposalInstructionDataDecoder().decode(instruction.data),
+  };
+}
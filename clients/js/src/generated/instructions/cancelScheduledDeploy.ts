@@ -0,0 +1,175 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CANCEL_SCHEDULED_DEPLOY_DISCRIMINATOR = 16;
+
+export function getCancelScheduledDeployDiscriminatorBytes() {
+  return getU32Encoder().encode(CANCEL_SCHEDULED_DEPLOY_DISCRIMINATOR);
+}
+
+export type CancelScheduledDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelScheduledDeployInstructionData = { discriminator: number };
+
+export type CancelScheduledDeployInstructionDataArgs = {};
+
+export function getCancelScheduledDeployInstructionDataEncoder(): Encoder<CancelScheduledDeployInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: CANCEL_SCHEDULED_DEPLOY_DISCRIMINATOR })
+  );
+}
+
+export function getCancelScheduledDeployInstructionDataDecoder(): Decoder<CancelScheduledDeployInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getCancelScheduledDeployInstructionDataCodec(): Codec<
+  CancelScheduledDeployInstructionDataArgs,
+  CancelScheduledDeployInstructionData
+> {
+  return combineCodec(
+    getCancelScheduledDeployInstructionDataEncoder(),
+    getCancelScheduledDeployInstructionDataDecoder()
+  );
+}
+
+export type CancelScheduledDeployInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Program account with a scheduled deployment. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+};
+
+export function getCancelScheduledDeployInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+>(
+  input: CancelScheduledDeployInput<TAccountProgram, TAccountAuthority>
+): CancelScheduledDeployInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getCancelScheduledDeployInstructionDataEncoder().encode({}),
+  } as CancelScheduledDeployInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelScheduledDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account with a scheduled deployment. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+  };
+  data: CancelScheduledDeployInstructionData;
+};
+
+export function parseCancelScheduledDeployInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelScheduledDeployInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getCancelScheduledDeployInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
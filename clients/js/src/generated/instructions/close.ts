@@ -0,0 +1,188 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_DISCRIMINATOR = 17;
+
+export function getCloseDiscriminatorBytes() {
+  return getU32Encoder().encode(CLOSE_DISCRIMINATOR);
+}
+
+export type CloseInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseInstructionData = { discriminator: number };
+
+export type CloseInstructionDataArgs = {};
+
+export function getCloseInstructionDataEncoder(): Encoder<CloseInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: CLOSE_DISCRIMINATOR })
+  );
+}
+
+export function getCloseInstructionDataDecoder(): Decoder<CloseInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getCloseInstructionDataCodec(): Codec<
+  CloseInstructionDataArgs,
+  CloseInstructionData
+> {
+  return combineCodec(
+    getCloseInstructionDataEncoder(),
+    getCloseInstructionDataDecoder()
+  );
+}
+
+export type CloseInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountDestination extends string = string,
+> = {
+  /** Program account to close. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Destination account for the lamports of the program account. */
+  destination: Address<TAccountDestination>;
+};
+
+export function getCloseInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountDestination extends string,
+>(
+  input: CloseInput<TAccountProgram, TAccountAuthority, TAccountDestination>
+): CloseInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountDestination
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.destination),
+    ],
+    programAddress,
+    data: getCloseInstructionDataEncoder().encode({}),
+  } as CloseInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountDestination
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to close. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Destination account for the lamports of the program account. */
+    destination: TAccountMetas[2];
+  };
+  data: CloseInstructionData;
+};
+
+export function parseCloseInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      destination: getNextAccount(),
+    },
+    data: getCloseInstructionDataDecoder().decode(instruction.data),
+  };
+}
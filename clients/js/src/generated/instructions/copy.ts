@@ -0,0 +1,216 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const COPY_DISCRIMINATOR = 7;
+
+export function getCopyDiscriminatorBytes() {
+  return getU32Encoder().encode(COPY_DISCRIMINATOR);
+}
+
+export type CopyInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSource extends string
+        ? ReadonlyAccount<TAccountSource>
+        : TAccountSource,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CopyInstructionData = {
+  discriminator: number;
+  destinationOffset: number;
+  sourceOffset: number;
+  length: number;
+};
+
+export type CopyInstructionDataArgs = {
+  destinationOffset: number;
+  sourceOffset: number;
+  length: number;
+};
+
+export function getCopyInstructionDataEncoder(): Encoder<CopyInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['destinationOffset', getU32Encoder()],
+      ['sourceOffset', getU32Encoder()],
+      ['length', getU32Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: COPY_DISCRIMINATOR })
+  );
+}
+
+export function getCopyInstructionDataDecoder(): Decoder<CopyInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['destinationOffset', getU32Decoder()],
+    ['sourceOffset', getU32Decoder()],
+    ['length', getU32Decoder()],
+  ]);
+}
+
+export function getCopyInstructionDataCodec(): Codec<
+  CopyInstructionDataArgs,
+  CopyInstructionData
+> {
+  return combineCodec(
+    getCopyInstructionDataEncoder(),
+    getCopyInstructionDataDecoder()
+  );
+}
+
+export type CopyInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSource extends string = string,
+> = {
+  /** Program account to copy to. */
+  program: Address<TAccountProgram>;
+  /** Program authority or writer. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Source account to copy from. */
+  source: Address<TAccountSource>;
+  destinationOffset: CopyInstructionDataArgs['destinationOffset'];
+  sourceOffset: CopyInstructionDataArgs['sourceOffset'];
+  length: CopyInstructionDataArgs['length'];
+};
+
+export function getCopyInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountSource extends string,
+>(
+  input: CopyInput<TAccountProgram, TAccountAuthority, TAccountSource>
+): CopyInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountSource
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    source: { value: input.source ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.source),
+    ],
+    programAddress,
+    data: getCopyInstructionDataEncoder().encode(
+      args as CopyInstructionDataArgs
+    ),
+  } as CopyInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountSource
+  >;
+
+  return instruction;
+}
+
+export type ParsedCopyInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to copy to. */
+    program: TAccountMetas[0];
+    /** Program authority or writer. */
+    authority: TAccountMetas[1];
+    /** Source account to copy from. */
+    source: TAccountMetas[2];
+  };
+  data: CopyInstructionData;
+};
+
+export function parseCopyInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCopyInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      source: getNextAccount(),
+    },
+    data: getCopyInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,223 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CREATE_DISCRIMINATOR = 18;
+
+export function getCreateDiscriminatorBytes() {
+  return getU32Encoder().encode(CREATE_DISCRIMINATOR);
+}
+
+export type CreateInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableSignerAccount<TAccountProgram> &
+            IAccountSignerMeta<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CreateInstructionData = { discriminator: number; size: number };
+
+export type CreateInstructionDataArgs = { size: number };
+
+export function getCreateInstructionDataEncoder(): Encoder<CreateInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['size', getU32Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CREATE_DISCRIMINATOR })
+  );
+}
+
+export function getCreateInstructionDataDecoder(): Decoder<CreateInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['size', getU32Decoder()],
+  ]);
+}
+
+export function getCreateInstructionDataCodec(): Codec<
+  CreateInstructionDataArgs,
+  CreateInstructionData
+> {
+  return combineCodec(
+    getCreateInstructionDataEncoder(),
+    getCreateInstructionDataDecoder()
+  );
+}
+
+export type CreateInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Program account to create. */
+  program: TransactionSigner<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the program account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram: Address<TAccountSystemProgram>;
+  size: CreateInstructionDataArgs['size'];
+};
+
+export function getCreateInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: CreateInput<
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): CreateInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getCreateInstructionDataEncoder().encode(
+      args as CreateInstructionDataArgs
+    ),
+  } as CreateInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCreateInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to create. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Payer funding the program account. */
+    payer: TAccountMetas[2];
+    /** System program. */
+    systemProgram: TAccountMetas[3];
+  };
+  data: CreateInstructionData;
+};
+
+export function parseCreateInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCreateInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getCreateInstructionDataDecoder().decode(instruction.data),
+  };
+}
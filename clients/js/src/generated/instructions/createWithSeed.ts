@@ -0,0 +1,263 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CREATE_WITH_SEED_DISCRIMINATOR = 19;
+
+export function getCreateWithSeedDiscriminatorBytes() {
+  return getU32Encoder().encode(CREATE_WITH_SEED_DISCRIMINATOR);
+}
+
+export type CreateWithSeedInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountBase extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountBase extends string
+        ? ReadonlySignerAccount<TAccountBase> & IAccountSignerMeta<TAccountBase>
+        : TAccountBase,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CreateWithSeedInstructionData = {
+  discriminator: number;
+  seed: ReadonlyUint8Array;
+  size: number;
+  bumpSeed: number;
+};
+
+export type CreateWithSeedInstructionDataArgs = {
+  seed: ReadonlyUint8Array;
+  size: number;
+  bumpSeed: number;
+};
+
+export function getCreateWithSeedInstructionDataEncoder(): Encoder<CreateWithSeedInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['seed', addEncoderSizePrefix(getBytesEncoder(), getU64Encoder())],
+      ['size', getU32Encoder()],
+      ['bumpSeed', getU8Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CREATE_WITH_SEED_DISCRIMINATOR })
+  );
+}
+
+export function getCreateWithSeedInstructionDataDecoder(): Decoder<CreateWithSeedInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['seed', addDecoderSizePrefix(getBytesDecoder(), getU64Decoder())],
+    ['size', getU32Decoder()],
+    ['bumpSeed', getU8Decoder()],
+  ]);
+}
+
+export function getCreateWithSeedInstructionDataCodec(): Codec<
+  CreateWithSeedInstructionDataArgs,
+  CreateWithSeedInstructionData
+> {
+  return combineCodec(
+    getCreateWithSeedInstructionDataEncoder(),
+    getCreateWithSeedInstructionDataDecoder()
+  );
+}
+
+export type CreateWithSeedInput<
+  TAccountProgram extends string = string,
+  TAccountBase extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Program account to create. */
+  program: Address<TAccountProgram>;
+  /** Base account the program address is derived from. */
+  base: TransactionSigner<TAccountBase>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the program account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram: Address<TAccountSystemProgram>;
+  seed: CreateWithSeedInstructionDataArgs['seed'];
+  size: CreateWithSeedInstructionDataArgs['size'];
+  bumpSeed: CreateWithSeedInstructionDataArgs['bumpSeed'];
+};
+
+export function getCreateWithSeedInstruction<
+  TAccountProgram extends string,
+  TAccountBase extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: CreateWithSeedInput<
+    TAccountProgram,
+    TAccountBase,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): CreateWithSeedInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountBase,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getCreateWithSeedInstructionDataEncoder().encode(
+      args as CreateWithSeedInstructionDataArgs
+    ),
+  } as CreateWithSeedInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountBase,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCreateWithSeedInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to create. */
+    program: TAccountMetas[0];
+    /** Base account the program address is derived from. */
+    base: TAccountMetas[1];
+    /** Program authority. */
+    authority: TAccountMetas[2];
+    /** Payer funding the program account. */
+    payer: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: CreateWithSeedInstructionData;
+};
+
+export function parseCreateWithSeedInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCreateWithSeedInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      base: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getCreateWithSeedInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -10,8 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU32Decoder,
+  getU32Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -32,7 +32,7 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 export const DEPLOY_DISCRIMINATOR = 2;
 
 export function getDeployDiscriminatorBytes() {
-  return getU8Encoder().encode(DEPLOY_DISCRIMINATOR);
+  return getU32Encoder().encode(DEPLOY_DISCRIMINATOR);
 }
 
 export type DeployInstruction<
@@ -65,13 +65,13 @@ export type DeployInstructionDataArgs = {};
 
 export function getDeployInstructionDataEncoder(): Encoder<DeployInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([['discriminator', getU32Encoder()]]),
     (value) => ({ ...value, discriminator: DEPLOY_DISCRIMINATOR })
   );
 }
 
 export function getDeployInstructionDataDecoder(): Decoder<DeployInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
 }
 
 export function getDeployInstructionDataCodec(): Codec<
@@ -123,13 +123,13 @@ export function getDeployInstruction<
     ResolvedAccount
   >;
 
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.program),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.source),
-    ],
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
     programAddress,
     data: getDeployInstructionDataEncoder().encode({}),
   } as DeployInstruction<
@@ -166,7 +166,7 @@ export function parseDeployInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDeployInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -176,11 +176,11 @@ export function parseDeployInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  let optionalAccountsRemaining = instruction.accounts.length - 2;
   const getNextOptionalAccount = () => {
-    const accountMeta = getNextAccount();
-    return accountMeta.address === LOADER_V4_PROGRAM_ADDRESS
-      ? undefined
-      : accountMeta;
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
   };
   return {
     programAddress: instruction.programAddress,
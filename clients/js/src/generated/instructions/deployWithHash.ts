@@ -0,0 +1,216 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const DEPLOY_WITH_HASH_DISCRIMINATOR = 9;
+
+export function getDeployWithHashDiscriminatorBytes() {
+  return getU32Encoder().encode(DEPLOY_WITH_HASH_DISCRIMINATOR);
+}
+
+export type DeployWithHashInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSource extends string
+        ? WritableAccount<TAccountSource>
+        : TAccountSource,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DeployWithHashInstructionData = {
+  discriminator: number;
+  expectedHash: ReadonlyUint8Array;
+};
+
+export type DeployWithHashInstructionDataArgs = {
+  expectedHash: ReadonlyUint8Array;
+};
+
+export function getDeployWithHashInstructionDataEncoder(): Encoder<DeployWithHashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['expectedHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({ ...value, discriminator: DEPLOY_WITH_HASH_DISCRIMINATOR })
+  );
+}
+
+export function getDeployWithHashInstructionDataDecoder(): Decoder<DeployWithHashInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['expectedHash', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getDeployWithHashInstructionDataCodec(): Codec<
+  DeployWithHashInstructionDataArgs,
+  DeployWithHashInstructionData
+> {
+  return combineCodec(
+    getDeployWithHashInstructionDataEncoder(),
+    getDeployWithHashInstructionDataDecoder()
+  );
+}
+
+export type DeployWithHashInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSource extends string = string,
+> = {
+  /** Program account to deploy. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Undeployed source program account to take data and lamports from (optional). */
+  source?: Address<TAccountSource>;
+  expectedHash: DeployWithHashInstructionDataArgs['expectedHash'];
+};
+
+export function getDeployWithHashInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountSource extends string,
+>(
+  input: DeployWithHashInput<TAccountProgram, TAccountAuthority, TAccountSource>
+): DeployWithHashInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountSource
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    source: { value: input.source ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.source),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getDeployWithHashInstructionDataEncoder().encode(
+      args as DeployWithHashInstructionDataArgs
+    ),
+  } as DeployWithHashInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountSource
+  >;
+
+  return instruction;
+}
+
+export type ParsedDeployWithHashInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to deploy. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Undeployed source program account to take data and lamports from (optional). */
+    source?: TAccountMetas[2] | undefined;
+  };
+  data: DeployWithHashInstructionData;
+};
+
+export function parseDeployWithHashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDeployWithHashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 2;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      source: getNextOptionalAccount(),
+    },
+    data: getDeployWithHashInstructionDataDecoder().decode(instruction.data),
+  };
+}
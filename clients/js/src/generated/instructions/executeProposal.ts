@@ -0,0 +1,204 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const EXECUTE_PROPOSAL_DISCRIMINATOR = 24;
+
+export function getExecuteProposalDiscriminatorBytes() {
+  return getU32Encoder().encode(EXECUTE_PROPOSAL_DISCRIMINATOR);
+}
+
+export type ExecuteProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountSource extends string
+        ? WritableAccount<TAccountSource>
+        : TAccountSource,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ExecuteProposalInstructionData = { discriminator: number };
+
+export type ExecuteProposalInstructionDataArgs = {};
+
+export function getExecuteProposalInstructionDataEncoder(): Encoder<ExecuteProposalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: EXECUTE_PROPOSAL_DISCRIMINATOR })
+  );
+}
+
+export function getExecuteProposalInstructionDataDecoder(): Decoder<ExecuteProposalInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getExecuteProposalInstructionDataCodec(): Codec<
+  ExecuteProposalInstructionDataArgs,
+  ExecuteProposalInstructionData
+> {
+  return combineCodec(
+    getExecuteProposalInstructionDataEncoder(),
+    getExecuteProposalInstructionDataDecoder()
+  );
+}
+
+export type ExecuteProposalInput<
+  TAccountProgram extends string = string,
+  TAccountProposal extends string = string,
+  TAccountSource extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Program account to deploy. */
+  program: Address<TAccountProgram>;
+  /** Proposal account. */
+  proposal: Address<TAccountProposal>;
+  /** Source program account of the proposal. */
+  source: Address<TAccountSource>;
+  /** Payer of the proposal account. */
+  payer: Address<TAccountPayer>;
+};
+
+export function getExecuteProposalInstruction<
+  TAccountProgram extends string,
+  TAccountProposal extends string,
+  TAccountSource extends string,
+  TAccountPayer extends string,
+>(
+  input: ExecuteProposalInput<
+    TAccountProgram,
+    TAccountProposal,
+    TAccountSource,
+    TAccountPayer
+  >
+): ExecuteProposalInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountProposal,
+  TAccountSource,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    source: { value: input.source ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.source),
+      getAccountMeta(accounts.payer),
+    ],
+    programAddress,
+    data: getExecuteProposalInstructionDataEncoder().encode({}),
+  } as ExecuteProposalInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountProposal,
+    TAccountSource,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedExecuteProposalInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to deploy. */
+    program: TAccountMetas[0];
+    /** Proposal account. */
+    proposal: TAccountMetas[1];
+    /** Source program account of the proposal. */
+    source: TAccountMetas[2];
+    /** Payer of the proposal account. */
+    payer: TAccountMetas[3];
+  };
+  data: ExecuteProposalInstructionData;
+};
+
+export function parseExecuteProposalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedExecuteProposalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      proposal: getNextAccount(),
+      source: getNextAccount(),
+      payer: getNextAccount(),
+    },
+    data: getExecuteProposalInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,171 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const EXECUTE_SCHEDULED_DEPLOY_DISCRIMINATOR = 15;
+
+export function getExecuteScheduledDeployDiscriminatorBytes() {
+  return getU32Encoder().encode(EXECUTE_SCHEDULED_DEPLOY_DISCRIMINATOR);
+}
+
+export type ExecuteScheduledDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountSource extends string
+        ? WritableAccount<TAccountSource>
+        : TAccountSource,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ExecuteScheduledDeployInstructionData = { discriminator: number };
+
+export type ExecuteScheduledDeployInstructionDataArgs = {};
+
+export function getExecuteScheduledDeployInstructionDataEncoder(): Encoder<ExecuteScheduledDeployInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: EXECUTE_SCHEDULED_DEPLOY_DISCRIMINATOR })
+  );
+}
+
+export function getExecuteScheduledDeployInstructionDataDecoder(): Decoder<ExecuteScheduledDeployInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getExecuteScheduledDeployInstructionDataCodec(): Codec<
+  ExecuteScheduledDeployInstructionDataArgs,
+  ExecuteScheduledDeployInstructionData
+> {
+  return combineCodec(
+    getExecuteScheduledDeployInstructionDataEncoder(),
+    getExecuteScheduledDeployInstructionDataDecoder()
+  );
+}
+
+export type ExecuteScheduledDeployInput<
+  TAccountProgram extends string = string,
+  TAccountSource extends string = string,
+> = {
+  /** Program account to deploy. */
+  program: Address<TAccountProgram>;
+  /** Source program account recorded by the schedule. */
+  source: Address<TAccountSource>;
+};
+
+export function getExecuteScheduledDeployInstruction<
+  TAccountProgram extends string,
+  TAccountSource extends string,
+>(
+  input: ExecuteScheduledDeployInput<TAccountProgram, TAccountSource>
+): ExecuteScheduledDeployInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountSource
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    source: { value: input.source ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.source),
+    ],
+    programAddress,
+    data: getExecuteScheduledDeployInstructionDataEncoder().encode({}),
+  } as ExecuteScheduledDeployInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountSource
+  >;
+
+  return instruction;
+}
+
+export type ParsedExecuteScheduledDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to deploy. */
+    program: TAccountMetas[0];
+    /** Source program account recorded by the schedule. */
+    source: TAccountMetas[1];
+  };
+  data: ExecuteScheduledDeployInstructionData;
+};
+
+export function parseExecuteScheduledDeployInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedExecuteScheduledDeployInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      source: getNextAccount(),
+    },
+    data: getExecuteScheduledDeployInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,163 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const HASH_DISCRIMINATOR = 10;
+
+export function getHashDiscriminatorBytes() {
+  return getU32Encoder().encode(HASH_DISCRIMINATOR);
+}
+
+export type HashInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HashInstructionData = {
+  discriminator: number;
+  offset: number;
+  length: number;
+};
+
+export type HashInstructionDataArgs = { offset: number; length: number };
+
+export function getHashInstructionDataEncoder(): Encoder<HashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['offset', getU32Encoder()],
+      ['length', getU32Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: HASH_DISCRIMINATOR })
+  );
+}
+
+export function getHashInstructionDataDecoder(): Decoder<HashInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['offset', getU32Decoder()],
+    ['length', getU32Decoder()],
+  ]);
+}
+
+export function getHashInstructionDataCodec(): Codec<
+  HashInstructionDataArgs,
+  HashInstructionData
+> {
+  return combineCodec(
+    getHashInstructionDataEncoder(),
+    getHashInstructionDataDecoder()
+  );
+}
+
+export type HashInput<TAccountProgram extends string = string> = {
+  /** Program account to hash. */
+  program: Address<TAccountProgram>;
+  offset: HashInstructionDataArgs['offset'];
+  length: HashInstructionDataArgs['length'];
+};
+
+export function getHashInstruction<TAccountProgram extends string>(
+  input: HashInput<TAccountProgram>
+): HashInstruction<typeof LOADER_V4_PROGRAM_ADDRESS, TAccountProgram> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+    ],
+    programAddress,
+    data: getHashInstructionDataEncoder().encode(
+      args as HashInstructionDataArgs
+    ),
+  } as HashInstruction<typeof LOADER_V4_PROGRAM_ADDRESS, TAccountProgram>;
+
+  return instruction;
+}
+
+export type ParsedHashInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to hash. */
+    program: TAccountMetas[0];
+  };
+  data: HashInstructionData;
+};
+
+export function parseHashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+    },
+    data: getHashInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -6,9 +6,31 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './acceptAuthority';
+export * from './approve';
+export * from './cancelAuthorityProposal';
+export * from './cancelProposal';
+export * from './cancelScheduledDeploy';
+export * from './close';
+export * from './copy';
+export * from './create';
+export * from './createWithSeed';
 export * from './deploy';
+export * from './deployWithHash';
+export * from './executeProposal';
+export * from './executeScheduledDeploy';
 export * from './finalize';
+export * from './hash';
+export * from './migrate';
+export * from './proposeAuthority';
+export * from './proposeDeploy';
+export * from './removeMultisig';
 export * from './retract';
+export * from './revokeWriter';
+export * from './scheduleDeploy';
+export * from './setMultisig';
+export * from './setWriter';
 export * from './transferAuthority';
 export * from './truncate';
 export * from './write';
+export * from './writeBatch';
@@ -0,0 +1,224 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const MIGRATE_DISCRIMINATOR = 6;
+
+export function getMigrateDiscriminatorBytes() {
+  return getU32Encoder().encode(MIGRATE_DISCRIMINATOR);
+}
+
+export type MigrateInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountV3Program extends string | IAccountMeta<string> = string,
+  TAccountV3ProgramData extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableSignerAccount<TAccountProgram> &
+            IAccountSignerMeta<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountV3Program extends string
+        ? ReadonlyAccount<TAccountV3Program>
+        : TAccountV3Program,
+      TAccountV3ProgramData extends string
+        ? ReadonlyAccount<TAccountV3ProgramData>
+        : TAccountV3ProgramData,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateInstructionData = { discriminator: number; deploy: boolean };
+
+export type MigrateInstructionDataArgs = { deploy: boolean };
+
+export function getMigrateInstructionDataEncoder(): Encoder<MigrateInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['deploy', getBooleanEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: MIGRATE_DISCRIMINATOR })
+  );
+}
+
+export function getMigrateInstructionDataDecoder(): Decoder<MigrateInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['deploy', getBooleanDecoder()],
+  ]);
+}
+
+export function getMigrateInstructionDataCodec(): Codec<
+  MigrateInstructionDataArgs,
+  MigrateInstructionData
+> {
+  return combineCodec(
+    getMigrateInstructionDataEncoder(),
+    getMigrateInstructionDataDecoder()
+  );
+}
+
+export type MigrateInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountV3Program extends string = string,
+  TAccountV3ProgramData extends string = string,
+> = {
+  /** Program account to migrate into. */
+  program: TransactionSigner<TAccountProgram>;
+  /** Upgrade authority of the loader-v3 program. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Loader-v3 program account. */
+  v3Program: Address<TAccountV3Program>;
+  /** Loader-v3 program data account. */
+  v3ProgramData: Address<TAccountV3ProgramData>;
+  deploy: MigrateInstructionDataArgs['deploy'];
+};
+
+export function getMigrateInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountV3Program extends string,
+  TAccountV3ProgramData extends string,
+>(
+  input: MigrateInput<
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountV3Program,
+    TAccountV3ProgramData
+  >
+): MigrateInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountV3Program,
+  TAccountV3ProgramData
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    v3Program: { value: input.v3Program ?? null, isWritable: false },
+    v3ProgramData: { value: input.v3ProgramData ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.v3Program),
+      getAccountMeta(accounts.v3ProgramData),
+    ],
+    programAddress,
+    data: getMigrateInstructionDataEncoder().encode(
+      args as MigrateInstructionDataArgs
+    ),
+  } as MigrateInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountV3Program,
+    TAccountV3ProgramData
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to migrate into. */
+    program: TAccountMetas[0];
+    /** Upgrade authority of the loader-v3 program. */
+    authority: TAccountMetas[1];
+    /** Loader-v3 program account. */
+    v3Program: TAccountMetas[2];
+    /** Loader-v3 program data account. */
+    v3ProgramData: TAccountMetas[3];
+  };
+  data: MigrateInstructionData;
+};
+
+export function parseMigrateInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      v3Program: getNextAccount(),
+      v3ProgramData: getNextAccount(),
+    },
+    data: getMigrateInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const PROPOSE_AUTHORITY_DISCRIMINATOR = 11;
+
+export function getProposeAuthorityDiscriminatorBytes() {
+  return getU32Encoder().encode(PROPOSE_AUTHORITY_DISCRIMINATOR);
+}
+
+export type ProposeAuthorityInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrentAuthority extends string | IAccountMeta<string> = string,
+  TAccountProposedAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountCurrentAuthority extends string
+        ? ReadonlySignerAccount<TAccountCurrentAuthority> &
+            IAccountSignerMeta<TAccountCurrentAuthority>
+        : TAccountCurrentAuthority,
+      TAccountProposedAuthority extends string
+        ? ReadonlyAccount<TAccountProposedAuthority>
+        : TAccountProposedAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ProposeAuthorityInstructionData = { discriminator: number };
+
+export type ProposeAuthorityInstructionDataArgs = {};
+
+export function getProposeAuthorityInstructionDataEncoder(): Encoder<ProposeAuthorityInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: PROPOSE_AUTHORITY_DISCRIMINATOR })
+  );
+}
+
+export function getProposeAuthorityInstructionDataDecoder(): Decoder<ProposeAuthorityInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getProposeAuthorityInstructionDataCodec(): Codec<
+  ProposeAuthorityInstructionDataArgs,
+  ProposeAuthorityInstructionData
+> {
+  return combineCodec(
+    getProposeAuthorityInstructionDataEncoder(),
+    getProposeAuthorityInstructionDataDecoder()
+  );
+}
+
+export type ProposeAuthorityInput<
+  TAccountProgram extends string = string,
+  TAccountCurrentAuthority extends string = string,
+  TAccountProposedAuthority extends string = string,
+> = {
+  /** Program account to change the authority of. */
+  program: Address<TAccountProgram>;
+  /** Current program authority. */
+  currentAuthority: TransactionSigner<TAccountCurrentAuthority>;
+  /** Proposed program authority. */
+  proposedAuthority: Address<TAccountProposedAuthority>;
+};
+
+export function getProposeAuthorityInstruction<
+  TAccountProgram extends string,
+  TAccountCurrentAuthority extends string,
+  TAccountProposedAuthority extends string,
+>(
+  input: ProposeAuthorityInput<
+    TAccountProgram,
+    TAccountCurrentAuthority,
+    TAccountProposedAuthority
+  >
+): ProposeAuthorityInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountCurrentAuthority,
+  TAccountProposedAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    currentAuthority: {
+      value: input.currentAuthority ?? null,
+      isWritable: false,
+    },
+    proposedAuthority: {
+      value: input.proposedAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.currentAuthority),
+      getAccountMeta(accounts.proposedAuthority),
+    ],
+    programAddress,
+    data: getProposeAuthorityInstructionDataEncoder().encode({}),
+  } as ProposeAuthorityInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountCurrentAuthority,
+    TAccountProposedAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedProposeAuthorityInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to change the authority of. */
+    program: TAccountMetas[0];
+    /** Current program authority. */
+    currentAuthority: TAccountMetas[1];
+    /** Proposed program authority. */
+    proposedAuthority: TAccountMetas[2];
+  };
+  data: ProposeAuthorityInstructionData;
+};
+
+export function parseProposeAuthorityInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedProposeAuthorityInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      currentAuthority: getNextAccount(),
+      proposedAuthority: getNextAccount(),
+    },
+    data: getProposeAuthorityInstructionDataDecoder().decode(instruction.data),
+  };
+}
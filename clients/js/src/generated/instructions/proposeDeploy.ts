@@ -0,0 +1,243 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const PROPOSE_DEPLOY_DISCRIMINATOR = 22;
+
+export function getProposeDeployDiscriminatorBytes() {
+  return getU32Encoder().encode(PROPOSE_DEPLOY_DISCRIMINATOR);
+}
+
+export type ProposeDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TAccountProposer extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountSource extends string
+        ? ReadonlyAccount<TAccountSource>
+        : TAccountSource,
+      TAccountProposer extends string
+        ? ReadonlySignerAccount<TAccountProposer> &
+            IAccountSignerMeta<TAccountProposer>
+        : TAccountProposer,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ProposeDeployInstructionData = { discriminator: number };
+
+export type ProposeDeployInstructionDataArgs = {};
+
+export function getProposeDeployInstructionDataEncoder(): Encoder<ProposeDeployInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: PROPOSE_DEPLOY_DISCRIMINATOR })
+  );
+}
+
+export function getProposeDeployInstructionDataDecoder(): Decoder<ProposeDeployInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getProposeDeployInstructionDataCodec(): Codec<
+  ProposeDeployInstructionDataArgs,
+  ProposeDeployInstructionData
+> {
+  return combineCodec(
+    getProposeDeployInstructionDataEncoder(),
+    getProposeDeployInstructionDataDecoder()
+  );
+}
+
+export type ProposeDeployInput<
+  TAccountProgram extends string = string,
+  TAccountProposal extends string = string,
+  TAccountSource extends string = string,
+  TAccountProposer extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Program account to propose the deployment of. */
+  program: Address<TAccountProgram>;
+  /** Proposal account to create. */
+  proposal: Address<TAccountProposal>;
+  /** Undeployed source program account to deploy. */
+  source: Address<TAccountSource>;
+  /** Member of the authority proposing the deployment. */
+  proposer: TransactionSigner<TAccountProposer>;
+  /** Payer funding the proposal account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram: Address<TAccountSystemProgram>;
+};
+
+export function getProposeDeployInstruction<
+  TAccountProgram extends string,
+  TAccountProposal extends string,
+  TAccountSource extends string,
+  TAccountProposer extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ProposeDeployInput<
+    TAccountProgram,
+    TAccountProposal,
+    TAccountSource,
+    TAccountProposer,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): ProposeDeployInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountProposal,
+  TAccountSource,
+  TAccountProposer,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    source: { value: input.source ?? null, isWritable: false },
+    proposer: { value: input.proposer ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.source),
+      getAccountMeta(accounts.proposer),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getProposeDeployInstructionDataEncoder().encode({}),
+  } as ProposeDeployInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountProposal,
+    TAccountSource,
+    TAccountProposer,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedProposeDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to propose the deployment of. */
+    program: TAccountMetas[0];
+    /** Proposal account to create. */
+    proposal: TAccountMetas[1];
+    /** Undeployed source program account to deploy. */
+    source: TAccountMetas[2];
+    /** Member of the authority proposing the deployment. */
+    proposer: TAccountMetas[3];
+    /** Payer funding the proposal account. */
+    payer: TAccountMetas[4];
+    /** System program. */
+    systemProgram: TAccountMetas[5];
+  };
+  data: ProposeDeployInstructionData;
+};
+
+export function parseProposeDeployInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedProposeDeployInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      proposal: getNextAccount(),
+      source: getNextAccount(),
+      proposer: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getProposeDeployInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const REMOVE_MULTISIG_DISCRIMINATOR = 21;
+
+export function getRemoveMultisigDiscriminatorBytes() {
+  return getU32Encoder().encode(REMOVE_MULTISIG_DISCRIMINATOR);
+}
+
+export type RemoveMultisigInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RemoveMultisigInstructionData = { discriminator: number };
+
+export type RemoveMultisigInstructionDataArgs = {};
+
+export function getRemoveMultisigInstructionDataEncoder(): Encoder<RemoveMultisigInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: REMOVE_MULTISIG_DISCRIMINATOR })
+  );
+}
+
+export function getRemoveMultisigInstructionDataDecoder(): Decoder<RemoveMultisigInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getRemoveMultisigInstructionDataCodec(): Codec<
+  RemoveMultisigInstructionDataArgs,
+  RemoveMultisigInstructionData
+> {
+  return combineCodec(
+    getRemoveMultisigInstructionDataEncoder(),
+    getRemoveMultisigInstructionDataDecoder()
+  );
+}
+
+export type RemoveMultisigInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Program account to remove the multisig of. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+};
+
+export function getRemoveMultisigInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+>(
+  input: RemoveMultisigInput<TAccountProgram, TAccountAuthority>
+): RemoveMultisigInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getRemoveMultisigInstructionDataEncoder().encode({}),
+  } as RemoveMultisigInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedRemoveMultisigInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to remove the multisig of. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+  };
+  data: RemoveMultisigInstructionData;
+};
+
+export function parseRemoveMultisigInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRemoveMultisigInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getRemoveMultisigInstructionDataDecoder().decode(instruction.data),
+  };
+}
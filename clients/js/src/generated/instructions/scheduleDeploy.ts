@@ -0,0 +1,208 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SCHEDULE_DEPLOY_DISCRIMINATOR = 14;
+
+export function getScheduleDeployDiscriminatorBytes() {
+  return getU32Encoder().encode(SCHEDULE_DEPLOY_DISCRIMINATOR);
+}
+
+export type ScheduleDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSource extends string
+        ? ReadonlyAccount<TAccountSource>
+        : TAccountSource,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ScheduleDeployInstructionData = {
+  discriminator: number;
+  notBeforeSlot: bigint;
+};
+
+export type ScheduleDeployInstructionDataArgs = {
+  notBeforeSlot: number | bigint;
+};
+
+export function getScheduleDeployInstructionDataEncoder(): Encoder<ScheduleDeployInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['notBeforeSlot', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SCHEDULE_DEPLOY_DISCRIMINATOR })
+  );
+}
+
+export function getScheduleDeployInstructionDataDecoder(): Decoder<ScheduleDeployInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['notBeforeSlot', getU64Decoder()],
+  ]);
+}
+
+export function getScheduleDeployInstructionDataCodec(): Codec<
+  ScheduleDeployInstructionDataArgs,
+  ScheduleDeployInstructionData
+> {
+  return combineCodec(
+    getScheduleDeployInstructionDataEncoder(),
+    getScheduleDeployInstructionDataDecoder()
+  );
+}
+
+export type ScheduleDeployInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSource extends string = string,
+> = {
+  /** Program account to deploy. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Source program account to take the program data from. */
+  source: Address<TAccountSource>;
+  notBeforeSlot: ScheduleDeployInstructionDataArgs['notBeforeSlot'];
+};
+
+export function getScheduleDeployInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountSource extends string,
+>(
+  input: ScheduleDeployInput<TAccountProgram, TAccountAuthority, TAccountSource>
+): ScheduleDeployInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountSource
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    source: { value: input.source ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.source),
+    ],
+    programAddress,
+    data: getScheduleDeployInstructionDataEncoder().encode(
+      args as ScheduleDeployInstructionDataArgs
+    ),
+  } as ScheduleDeployInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountSource
+  >;
+
+  return instruction;
+}
+
+export type ParsedScheduleDeployInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to deploy. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Source program account to take the program data from. */
+    source: TAccountMetas[2];
+  };
+  data: ScheduleDeployInstructionData;
+};
+
+export function parseScheduleDeployInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedScheduleDeployInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      source: getNextAccount(),
+    },
+    data: getScheduleDeployInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_MULTISIG_DISCRIMINATOR = 20;
+
+export function getSetMultisigDiscriminatorBytes() {
+  return getU32Encoder().encode(SET_MULTISIG_DISCRIMINATOR);
+}
+
+export type SetMultisigInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetMultisigInstructionData = {
+  discriminator: number;
+  threshold: number;
+  signers: Array<Address>;
+};
+
+export type SetMultisigInstructionDataArgs = {
+  threshold: number;
+  signers: Array<Address>;
+};
+
+export function getSetMultisigInstructionDataEncoder(): Encoder<SetMultisigInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      ['threshold', getU8Encoder()],
+      [
+        'signers',
+        getArrayEncoder(getAddressEncoder(), { size: getU64Encoder() }),
+      ],
+    ]),
+    (value) => ({ ...value, discriminator: SET_MULTISIG_DISCRIMINATOR })
+  );
+}
+
+export function getSetMultisigInstructionDataDecoder(): Decoder<SetMultisigInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    ['threshold', getU8Decoder()],
+    [
+      'signers',
+      getArrayDecoder(getAddressDecoder(), { size: getU64Decoder() }),
+    ],
+  ]);
+}
+
+export function getSetMultisigInstructionDataCodec(): Codec<
+  SetMultisigInstructionDataArgs,
+  SetMultisigInstructionData
+> {
+  return combineCodec(
+    getSetMultisigInstructionDataEncoder(),
+    getSetMultisigInstructionDataDecoder()
+  );
+}
+
+export type SetMultisigInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Program account to set the multisig of. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  threshold: SetMultisigInstructionDataArgs['threshold'];
+  signers: SetMultisigInstructionDataArgs['signers'];
+};
+
+export function getSetMultisigInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+>(
+  input: SetMultisigInput<TAccountProgram, TAccountAuthority>
+): SetMultisigInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getSetMultisigInstructionDataEncoder().encode(
+      args as SetMultisigInstructionDataArgs
+    ),
+  } as SetMultisigInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetMultisigInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to set the multisig of. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+  };
+  data: SetMultisigInstructionData;
+};
+
+export function parseSetMultisigInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetMultisigInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getSetMultisigInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,189 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_WRITER_DISCRIMINATOR = 26;
+
+export function getSetWriterDiscriminatorBytes() {
+  return getU32Encoder().encode(SET_WRITER_DISCRIMINATOR);
+}
+
+export type SetWriterInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountWriter extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountWriter extends string
+        ? ReadonlyAccount<TAccountWriter>
+        : TAccountWriter,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetWriterInstructionData = { discriminator: number };
+
+export type SetWriterInstructionDataArgs = {};
+
+export function getSetWriterInstructionDataEncoder(): Encoder<SetWriterInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU32Encoder()]]),
+    (value) => ({ ...value, discriminator: SET_WRITER_DISCRIMINATOR })
+  );
+}
+
+export function getSetWriterInstructionDataDecoder(): Decoder<SetWriterInstructionData> {
+  return getStructDecoder([['discriminator', getU32Decoder()]]);
+}
+
+export function getSetWriterInstructionDataCodec(): Codec<
+  SetWriterInstructionDataArgs,
+  SetWriterInstructionData
+> {
+  return combineCodec(
+    getSetWriterInstructionDataEncoder(),
+    getSetWriterInstructionDataDecoder()
+  );
+}
+
+export type SetWriterInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountWriter extends string = string,
+> = {
+  /** Program account to set the writer of. */
+  program: Address<TAccountProgram>;
+  /** Program authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Writer. */
+  writer: Address<TAccountWriter>;
+};
+
+export function getSetWriterInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+  TAccountWriter extends string,
+>(
+  input: SetWriterInput<TAccountProgram, TAccountAuthority, TAccountWriter>
+): SetWriterInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority,
+  TAccountWriter
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    writer: { value: input.writer ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.writer),
+    ],
+    programAddress,
+    data: getSetWriterInstructionDataEncoder().encode({}),
+  } as SetWriterInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority,
+    TAccountWriter
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetWriterInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to set the writer of. */
+    program: TAccountMetas[0];
+    /** Program authority. */
+    authority: TAccountMetas[1];
+    /** Writer. */
+    writer: TAccountMetas[2];
+  };
+  data: SetWriterInstructionData;
+};
+
+export function parseSetWriterInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetWriterInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+      writer: getNextAccount(),
+    },
+    data: getSetWriterInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -12,8 +12,6 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -35,7 +33,7 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 export const TRUNCATE_DISCRIMINATOR = 1;
 
 export function getTruncateDiscriminatorBytes() {
-  return getU8Encoder().encode(TRUNCATE_DISCRIMINATOR);
+  return getU32Encoder().encode(TRUNCATE_DISCRIMINATOR);
 }
 
 export type TruncateInstruction<
@@ -73,7 +71,7 @@ export type TruncateInstructionDataArgs = { newSize: number };
 export function getTruncateInstructionDataEncoder(): Encoder<TruncateInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
-      ['discriminator', getU8Encoder()],
+      ['discriminator', getU32Encoder()],
       ['newSize', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: TRUNCATE_DISCRIMINATOR })
@@ -82,7 +80,7 @@ export function getTruncateInstructionDataEncoder(): Encoder<TruncateInstruction
 
 export function getTruncateInstructionDataDecoder(): Decoder<TruncateInstructionData> {
   return getStructDecoder([
-    ['discriminator', getU8Decoder()],
+    ['discriminator', getU32Decoder()],
     ['newSize', getU32Decoder()],
   ]);
 }
@@ -104,7 +102,7 @@ export type TruncateInput<
 > = {
   /** Program account to change the size of. */
   program: TransactionSigner<TAccountProgram>;
-  /** Program authority. */
+  /** Program authority or writer. */
   authority: TransactionSigner<TAccountAuthority>;
   /** Destination account for reclaimed lamports (optional). */
   destination?: Address<TAccountDestination>;
@@ -140,13 +138,13 @@ export function getTruncateInstruction<
   // Original args.
   const args = { ...input };
 
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.program),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.destination),
-    ],
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
     programAddress,
     data: getTruncateInstructionDataEncoder().encode(
       args as TruncateInstructionDataArgs
@@ -169,7 +167,7 @@ export type ParsedTruncateInstruction<
   accounts: {
     /** Program account to change the size of. */
     program: TAccountMetas[0];
-    /** Program authority. */
+    /** Program authority or writer. */
     authority: TAccountMetas[1];
     /** Destination account for reclaimed lamports (optional). */
     destination?: TAccountMetas[2] | undefined;
@@ -185,7 +183,7 @@ export function parseTruncateInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedTruncateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -195,11 +193,11 @@ export function parseTruncateInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  let optionalAccountsRemaining = instruction.accounts.length - 2;
   const getNextOptionalAccount = () => {
-    const accountMeta = getNextAccount();
-    return accountMeta.address === LOADER_V4_PROGRAM_ADDRESS
-      ? undefined
-      : accountMeta;
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
   };
   return {
     programAddress: instruction.programAddress,
@@ -16,8 +16,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -39,7 +39,7 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 export const WRITE_DISCRIMINATOR = 0;
 
 export function getWriteDiscriminatorBytes() {
-  return getU8Encoder().encode(WRITE_DISCRIMINATOR);
+  return getU32Encoder().encode(WRITE_DISCRIMINATOR);
 }
 
 export type WriteInstruction<
@@ -76,9 +76,9 @@ export type WriteInstructionDataArgs = {
 export function getWriteInstructionDataEncoder(): Encoder<WriteInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
-      ['discriminator', getU8Encoder()],
+      ['discriminator', getU32Encoder()],
       ['offset', getU32Encoder()],
-      ['bytes', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+      ['bytes', addEncoderSizePrefix(getBytesEncoder(), getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: WRITE_DISCRIMINATOR })
   );
@@ -86,9 +86,9 @@ export function getWriteInstructionDataEncoder(): Encoder<WriteInstructionDataAr
 
 export function getWriteInstructionDataDecoder(): Decoder<WriteInstructionData> {
   return getStructDecoder([
-    ['discriminator', getU8Decoder()],
+    ['discriminator', getU32Decoder()],
     ['offset', getU32Decoder()],
-    ['bytes', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['bytes', addDecoderSizePrefix(getBytesDecoder(), getU64Decoder())],
   ]);
 }
 
@@ -108,7 +108,7 @@ export type WriteInput<
 > = {
   /** Program account to write to. */
   program: Address<TAccountProgram>;
-  /** Program authority. */
+  /** Program authority or writer. */
   authority: TransactionSigner<TAccountAuthority>;
   offset: WriteInstructionDataArgs['offset'];
   bytes: WriteInstructionDataArgs['bytes'];
@@ -167,7 +167,7 @@ export type ParsedWriteInstruction<
   accounts: {
     /** Program account to write to. */
     program: TAccountMetas[0];
-    /** Program authority. */
+    /** Program authority or writer. */
     authority: TAccountMetas[1];
   };
   data: WriteInstructionData;
@@ -0,0 +1,206 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { LOADER_V4_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getWriteSegmentDecoder,
+  getWriteSegmentEncoder,
+  type WriteSegment,
+  type WriteSegmentArgs,
+} from '../types';
+
+export const WRITE_BATCH_DISCRIMINATOR = 8;
+
+export function getWriteBatchDiscriminatorBytes() {
+  return getU32Encoder().encode(WRITE_BATCH_DISCRIMINATOR);
+}
+
+export type WriteBatchInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgram extends string
+        ? WritableAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type WriteBatchInstructionData = {
+  discriminator: number;
+  segments: Array<WriteSegment>;
+};
+
+export type WriteBatchInstructionDataArgs = {
+  segments: Array<WriteSegmentArgs>;
+};
+
+export function getWriteBatchInstructionDataEncoder(): Encoder<WriteBatchInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU32Encoder()],
+      [
+        'segments',
+        getArrayEncoder(getWriteSegmentEncoder(), { size: getU64Encoder() }),
+      ],
+    ]),
+    (value) => ({ ...value, discriminator: WRITE_BATCH_DISCRIMINATOR })
+  );
+}
+
+export function getWriteBatchInstructionDataDecoder(): Decoder<WriteBatchInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU32Decoder()],
+    [
+      'segments',
+      getArrayDecoder(getWriteSegmentDecoder(), { size: getU64Decoder() }),
+    ],
+  ]);
+}
+
+export function getWriteBatchInstructionDataCodec(): Codec<
+  WriteBatchInstructionDataArgs,
+  WriteBatchInstructionData
+> {
+  return combineCodec(
+    getWriteBatchInstructionDataEncoder(),
+    getWriteBatchInstructionDataDecoder()
+  );
+}
+
+export type WriteBatchInput<
+  TAccountProgram extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Program account to write to. */
+  program: Address<TAccountProgram>;
+  /** Program authority or writer. */
+  authority: TransactionSigner<TAccountAuthority>;
+  segments: WriteBatchInstructionDataArgs['segments'];
+};
+
+export function getWriteBatchInstruction<
+  TAccountProgram extends string,
+  TAccountAuthority extends string,
+>(
+  input: WriteBatchInput<TAccountProgram, TAccountAuthority>
+): WriteBatchInstruction<
+  typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountProgram,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress = LOADER_V4_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    program: { value: input.program ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getWriteBatchInstructionDataEncoder().encode(
+      args as WriteBatchInstructionDataArgs
+    ),
+  } as WriteBatchInstruction<
+    typeof LOADER_V4_PROGRAM_ADDRESS,
+    TAccountProgram,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedWriteBatchInstruction<
+  TProgram extends string = typeof LOADER_V4_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Program account to write to. */
+    program: TAccountMetas[0];
+    /** Program authority or writer. */
+    authority: TAccountMetas[1];
+  };
+  data: WriteBatchInstructionData;
+};
+
+export function parseWriteBatchInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedWriteBatchInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      program: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getWriteBatchInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -8,17 +8,39 @@
 
 import {
   containsBytes,
-  getU8Encoder,
+  getU32Encoder,
   type Address,
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedAcceptAuthorityInstruction,
+  type ParsedApproveInstruction,
+  type ParsedCancelAuthorityProposalInstruction,
+  type ParsedCancelProposalInstruction,
+  type ParsedCancelScheduledDeployInstruction,
+  type ParsedCloseInstruction,
+  type ParsedCopyInstruction,
+  type ParsedCreateInstruction,
+  type ParsedCreateWithSeedInstruction,
   type ParsedDeployInstruction,
+  type ParsedDeployWithHashInstruction,
+  type ParsedExecuteProposalInstruction,
+  type ParsedExecuteScheduledDeployInstruction,
   type ParsedFinalizeInstruction,
+  type ParsedHashInstruction,
+  type ParsedMigrateInstruction,
+  type ParsedProposeAuthorityInstruction,
+  type ParsedProposeDeployInstruction,
+  type ParsedRemoveMultisigInstruction,
   type ParsedRetractInstruction,
+  type ParsedRevokeWriterInstruction,
+  type ParsedScheduleDeployInstruction,
+  type ParsedSetMultisigInstruction,
+  type ParsedSetWriterInstruction,
   type ParsedTransferAuthorityInstruction,
   type ParsedTruncateInstruction,
   type ParsedWriteInstruction,
+  type ParsedWriteBatchInstruction,
 } from '../instructions';
 
 export const LOADER_V4_PROGRAM_ADDRESS =
@@ -31,30 +53,118 @@ export enum LoaderV4Instruction {
   Retract,
   TransferAuthority,
   Finalize,
+  Migrate,
+  Copy,
+  WriteBatch,
+  DeployWithHash,
+  Hash,
+  ProposeAuthority,
+  AcceptAuthority,
+  CancelAuthorityProposal,
+  ScheduleDeploy,
+  ExecuteScheduledDeploy,
+  CancelScheduledDeploy,
+  Close,
+  Create,
+  CreateWithSeed,
+  SetMultisig,
+  RemoveMultisig,
+  ProposeDeploy,
+  Approve,
+  ExecuteProposal,
+  CancelProposal,
+  SetWriter,
+  RevokeWriter,
 }
 
 export function identifyLoaderV4Instruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): LoaderV4Instruction {
   const data = 'data' in instruction ? instruction.data : instruction;
-  if (containsBytes(data, getU8Encoder().encode(0), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(0), 0)) {
     return LoaderV4Instruction.Write;
   }
-  if (containsBytes(data, getU8Encoder().encode(1), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(1), 0)) {
     return LoaderV4Instruction.Truncate;
   }
-  if (containsBytes(data, getU8Encoder().encode(2), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(2), 0)) {
     return LoaderV4Instruction.Deploy;
   }
-  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(3), 0)) {
     return LoaderV4Instruction.Retract;
   }
-  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(4), 0)) {
     return LoaderV4Instruction.TransferAuthority;
   }
-  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+  if (containsBytes(data, getU32Encoder().encode(5), 0)) {
     return LoaderV4Instruction.Finalize;
   }
+  if (containsBytes(data, getU32Encoder().encode(6), 0)) {
+    return LoaderV4Instruction.Migrate;
+  }
+  if (containsBytes(data, getU32Encoder().encode(7), 0)) {
+    return LoaderV4Instruction.Copy;
+  }
+  if (containsBytes(data, getU32Encoder().encode(8), 0)) {
+    return LoaderV4Instruction.WriteBatch;
+  }
+  if (containsBytes(data, getU32Encoder().encode(9), 0)) {
+    return LoaderV4Instruction.DeployWithHash;
+  }
+  if (containsBytes(data, getU32Encoder().encode(10), 0)) {
+    return LoaderV4Instruction.Hash;
+  }
+  if (containsBytes(data, getU32Encoder().encode(11), 0)) {
+    return LoaderV4Instruction.ProposeAuthority;
+  }
+  if (containsBytes(data, getU32Encoder().encode(12), 0)) {
+    return LoaderV4Instruction.AcceptAuthority;
+  }
+  if (containsBytes(data, getU32Encoder().encode(13), 0)) {
+    return LoaderV4Instruction.CancelAuthorityProposal;
+  }
+  if (containsBytes(data, getU32Encoder().encode(14), 0)) {
+    return LoaderV4Instruction.ScheduleDeploy;
+  }
+  if (containsBytes(data, getU32Encoder().encode(15), 0)) {
+    return LoaderV4Instruction.ExecuteScheduledDeploy;
+  }
+  if (containsBytes(data, getU32Encoder().encode(16), 0)) {
+    return LoaderV4Instruction.CancelScheduledDeploy;
+  }
+  if (containsBytes(data, getU32Encoder().encode(17), 0)) {
+    return LoaderV4Instruction.Close;
+  }
+  if (containsBytes(data, getU32Encoder().encode(18), 0)) {
+    return LoaderV4Instruction.Create;
+  }
+  if (containsBytes(data, getU32Encoder().encode(19), 0)) {
+    return LoaderV4Instruction.CreateWithSeed;
+  }
+  if (containsBytes(data, getU32Encoder().encode(20), 0)) {
+    return LoaderV4Instruction.SetMultisig;
+  }
+  if (containsBytes(data, getU32Encoder().encode(21), 0)) {
+    return LoaderV4Instruction.RemoveMultisig;
+  }
+  if (containsBytes(data, getU32Encoder().encode(22), 0)) {
+    return LoaderV4Instruction.ProposeDeploy;
+  }
+  if (containsBytes(data, getU32Encoder().encode(23), 0)) {
+    return LoaderV4Instruction.Approve;
+  }
+  if (containsBytes(data, getU32Encoder().encode(24), 0)) {
+    return LoaderV4Instruction.ExecuteProposal;
+  }
+  if (containsBytes(data, getU32Encoder().encode(25), 0)) {
+    return LoaderV4Instruction.CancelProposal;
+  }
+  if (containsBytes(data, getU32Encoder().encode(26), 0)) {
+    return LoaderV4Instruction.SetWriter;
+  }
+  if (containsBytes(data, getU32Encoder().encode(27), 0)) {
+    return LoaderV4Instruction.RevokeWriter;
+  }
   throw new Error(
     'The provided instruction could not be identified as a loaderV4 instruction.'
   );
@@ -80,4 +190,70 @@ export type ParsedLoaderV4Instruction<
     } & ParsedTransferAuthorityInstruction<TProgram>)
   | ({
       instructionType: LoaderV4Instruction.Finalize;
-    } & ParsedFinalizeInstruction<TProgram>);
+    } & ParsedFinalizeInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Migrate;
+    } & ParsedMigrateInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Copy;
+    } & ParsedCopyInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.WriteBatch;
+    } & ParsedWriteBatchInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.DeployWithHash;
+    } & ParsedDeployWithHashInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Hash;
+    } & ParsedHashInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.ProposeAuthority;
+    } & ParsedProposeAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.AcceptAuthority;
+    } & ParsedAcceptAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.CancelAuthorityProposal;
+    } & ParsedCancelAuthorityProposalInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.ScheduleDeploy;
+    } & ParsedScheduleDeployInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.ExecuteScheduledDeploy;
+    } & ParsedExecuteScheduledDeployInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.CancelScheduledDeploy;
+    } & ParsedCancelScheduledDeployInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Close;
+    } & ParsedCloseInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Create;
+    } & ParsedCreateInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.CreateWithSeed;
+    } & ParsedCreateWithSeedInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.SetMultisig;
+    } & ParsedSetMultisigInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.RemoveMultisig;
+    } & ParsedRemoveMultisigInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.ProposeDeploy;
+    } & ParsedProposeDeployInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.Approve;
+    } & ParsedApproveInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.ExecuteProposal;
+    } & ParsedExecuteProposalInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.CancelProposal;
+    } & ParsedCancelProposalInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.SetWriter;
+    } & ParsedSetWriterInstruction<TProgram>)
+  | ({
+      instructionType: LoaderV4Instruction.RevokeWriter;
+    } & ParsedRevokeWriterInstruction<TProgram>);
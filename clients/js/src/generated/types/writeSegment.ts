@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type WriteSegment = { offset: number; bytes: ReadonlyUint8Array };
+
+export type WriteSegmentArgs = WriteSegment;
+
+export function getWriteSegmentEncoder(): Encoder<WriteSegmentArgs> {
+  return getStructEncoder([
+    ['offset', getU32Encoder()],
+    ['bytes', addEncoderSizePrefix(getBytesEncoder(), getU64Encoder())],
+  ]);
+}
+
+export function getWriteSegmentDecoder(): Decoder<WriteSegment> {
+  return getStructDecoder([
+    ['offset', getU32Decoder()],
+    ['bytes', addDecoderSizePrefix(getBytesDecoder(), getU64Decoder())],
+  ]);
+}
+
+export function getWriteSegmentCodec(): Codec<WriteSegmentArgs, WriteSegment> {
+  return combineCodec(getWriteSegmentEncoder(), getWriteSegmentDecoder());
+}
@@ -0,0 +1,371 @@
+//! Program account state.
+
+use {
+    borsh::maybestd::io::{Error, ErrorKind},
+    solana_program::{account_info::AccountInfo, pubkey::Pubkey},
+};
+
+/// Extension type of the authority proposed by `ProposeAuthority`.
+pub const PENDING_AUTHORITY_EXTENSION: u16 = 1;
+
+/// Extension type of the deployment scheduled by `ScheduleDeploy`.
+pub const SCHEDULED_DEPLOY_EXTENSION: u16 = 2;
+
+/// Extension type of the multisig set by `SetMultisig`.
+pub const MULTISIG_EXTENSION: u16 = 3;
+
+/// Extension type of the writer set by `SetWriter`.
+pub const WRITER_EXTENSION: u16 = 4;
+
+/// Marks the end of the extension trailer of accounts of the extended layout.
+pub const EXTENSIONS_MAGIC: [u8; 8] = *b"LV4EXT\x00\x01";
+
+/// Prefix of the seeds of program accounts created by `CreateWithSeed`.
+pub const PROGRAM_SEED_PREFIX: &[u8] = b"program";
+
+/// Derives the address of the program account created by `CreateWithSeed`
+/// from a base address and a seed, along with its bump seed.
+pub fn find_program_address(base_address: &Pubkey, seed: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROGRAM_SEED_PREFIX, base_address.as_ref(), seed],
+        &crate::ID,
+    )
+}
+
+/// Prefix of the seeds of proposal accounts.
+pub const PROPOSAL_SEED_PREFIX: &[u8] = b"proposal";
+
+/// Derives the address of the proposal account of a program, along with its
+/// bump seed.
+pub fn find_proposal_address(program_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED_PREFIX, program_address.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Deployment status of a program account.
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LoaderV4Status {
+    /// Program is in maintenance.
+    Retracted,
+    /// Program is ready to be executed.
+    Deployed,
+    /// Same as `Deployed`, but can not be retracted anymore.
+    Finalized,
+}
+
+/// An entry of the extension trailer of a program account.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4Extension {
+    /// Type of the extension, kept as is so that unknown types round-trip.
+    pub extension_type: u16,
+    /// Value of the extension.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::base64::Base64>")
+    )]
+    pub value: Vec<u8>,
+}
+
+/// A deployment scheduled by `ScheduleDeploy`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4ScheduledDeploy {
+    /// Program account to deploy the program data of.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub source_address: Pubkey,
+    /// First slot in which the deployment can be executed.
+    pub not_before_slot: u64,
+    /// Hash of the program data of the source program when the deployment
+    /// was scheduled.
+    pub hash: [u8; 32],
+}
+
+/// A multisig set by `SetMultisig`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4Multisig {
+    /// Number of distinct signers required to act as the authority.
+    pub threshold: u8,
+    /// Addresses allowed to sign as part of the multisig.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub signers: Vec<Pubkey>,
+}
+
+/// A program account owned by the loader: its state header, followed by the
+/// program data and its extensions if any.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4ProgramAccount {
+    /// Slot in which the program was last deployed, retracted or initialized.
+    pub slot: u64,
+    /// The authority, or the next version once the program is finalized.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority_address_or_next_version: Pubkey,
+    /// Deployment status.
+    pub status: LoaderV4Status,
+    /// Extensions in the trailer after the program data, `None` for accounts
+    /// of the legacy layout.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub extensions: Option<Vec<LoaderV4Extension>>,
+    /// The program data between the header and the extensions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::base64::Base64>")
+    )]
+    pub program_data: Vec<u8>,
+}
+
+impl LoaderV4ProgramAccount {
+    /// Length of the state header preceding the program data.
+    pub const HEADER_LEN: usize = 48;
+
+    /// Length of the extensions length and magic closing the trailer.
+    const TRAILER_FOOTER_LEN: usize = 4 + EXTENSIONS_MAGIC.len();
+
+    /// Decodes a program account of any layout from its raw data.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() < Self::HEADER_LEN {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "Account data too small",
+            ));
+        }
+        // Proposals have the header of a retracted program.
+        if LoaderV4DeployProposal::from_bytes(data).is_ok() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Proposal account, not a program account",
+            ));
+        }
+        let (header, rest) = data.split_at(Self::HEADER_LEN);
+        let (slot, header_rest) = header.split_at(8);
+        let (authority_address_or_next_version, status) = header_rest.split_at(32);
+        let status = match u64::from_le_bytes(status.try_into().unwrap()) {
+            0 => LoaderV4Status::Retracted,
+            1 => LoaderV4Status::Deployed,
+            2 => LoaderV4Status::Finalized,
+            status => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid program status: {status}"),
+                ))
+            }
+        };
+        let (program_data, extensions) =
+            if rest.len() >= Self::TRAILER_FOOTER_LEN && rest.ends_with(&EXTENSIONS_MAGIC) {
+                let (program_data, extensions) = decode_extensions(rest)?;
+                (program_data, Some(extensions))
+            } else {
+                (rest, None)
+            };
+        Ok(Self {
+            slot: u64::from_le_bytes(slot.try_into().unwrap()),
+            authority_address_or_next_version: Pubkey::new_from_array(
+                authority_address_or_next_version.try_into().unwrap(),
+            ),
+            status,
+            extensions,
+            program_data: program_data.to_vec(),
+        })
+    }
+
+    /// Length of the extension trailer following the program data, zero for
+    /// accounts of the legacy layout.
+    pub fn extensions_len(&self) -> usize {
+        self.extensions.as_ref().map_or(0, |extensions| {
+            extensions
+                .iter()
+                .fold(Self::TRAILER_FOOTER_LEN, |len, extension| {
+                    len + 4 + extension.value.len()
+                })
+        })
+    }
+
+    /// The value of an extension of the account, if it has one.
+    pub fn extension(&self, extension_type: u16) -> Option<&[u8]> {
+        self.extensions
+            .iter()
+            .flatten()
+            .find(|extension| extension.extension_type == extension_type)
+            .map(|extension| extension.value.as_slice())
+    }
+
+    /// The authority allowed to manage the program, unless it is finalized.
+    pub fn authority(&self) -> Option<Pubkey> {
+        (self.status != LoaderV4Status::Finalized).then_some(self.authority_address_or_next_version)
+    }
+
+    /// The next version of the program, once it is finalized.
+    pub fn next_version(&self) -> Option<Pubkey> {
+        (self.status == LoaderV4Status::Finalized).then_some(self.authority_address_or_next_version)
+    }
+
+    /// The authority proposed by `ProposeAuthority`, until it accepts.
+    pub fn pending_authority(&self) -> Option<Pubkey> {
+        self.extension(PENDING_AUTHORITY_EXTENSION)
+            .and_then(|value| value.try_into().ok())
+            .map(Pubkey::new_from_array)
+    }
+
+    /// The deployment scheduled by `ScheduleDeploy`, until it is executed or
+    /// cancelled.
+    pub fn scheduled_deploy(&self) -> Option<LoaderV4ScheduledDeploy> {
+        let value = self.extension(SCHEDULED_DEPLOY_EXTENSION)?;
+        if value.len() != 72 {
+            return None;
+        }
+        Some(LoaderV4ScheduledDeploy {
+            source_address: Pubkey::new_from_array(value[..32].try_into().unwrap()),
+            not_before_slot: u64::from_le_bytes(value[32..40].try_into().unwrap()),
+            hash: value[40..].try_into().unwrap(),
+        })
+    }
+
+    /// The multisig set by `SetMultisig`, required instead of the authority's
+    /// signature while it is set.
+    pub fn multisig(&self) -> Option<LoaderV4Multisig> {
+        let (threshold, signers) = self.extension(MULTISIG_EXTENSION)?.split_first()?;
+        if signers.len() % 32 != 0 {
+            return None;
+        }
+        Some(LoaderV4Multisig {
+            threshold: *threshold,
+            signers: signers
+                .chunks_exact(32)
+                .map(|signer| Pubkey::new_from_array(signer.try_into().unwrap()))
+                .collect(),
+        })
+    }
+
+    /// The writer set by `SetWriter`, which can write to the program while it
+    /// is retracted, until it is revoked.
+    pub fn writer(&self) -> Option<Pubkey> {
+        self.extension(WRITER_EXTENSION)
+            .and_then(|value| value.try_into().ok())
+            .map(Pubkey::new_from_array)
+    }
+}
+
+/// Splits the data following the header of an account of the extended layout
+/// into its program data and extensions.
+fn decode_extensions(data: &[u8]) -> Result<(&[u8], Vec<LoaderV4Extension>), Error> {
+    let invalid = || Error::new(ErrorKind::InvalidData, "Invalid extension trailer");
+    let footer = data.len() - LoaderV4ProgramAccount::TRAILER_FOOTER_LEN;
+    let len = u32::from_le_bytes(data[footer..footer + 4].try_into().unwrap()) as usize;
+    let end = footer.checked_sub(len).ok_or_else(invalid)?;
+    let mut area = &data[end..footer];
+    let mut extensions = Vec::new();
+    while !area.is_empty() {
+        let header = area.get(..4).ok_or_else(invalid)?;
+        let extension_type = u16::from_le_bytes(header[..2].try_into().unwrap());
+        let end = 4 + u16::from_le_bytes(header[2..].try_into().unwrap()) as usize;
+        let value = area.get(4..end).ok_or_else(invalid)?;
+        extensions.push(LoaderV4Extension {
+            extension_type,
+            value: value.to_vec(),
+        });
+        area = &area[end..];
+    }
+    Ok((&data[..end], extensions))
+}
+
+/// A deployment proposed by `ProposeDeploy`, until it is executed or
+/// cancelled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4DeployProposal {
+    /// Slot in which the deployment was proposed.
+    pub slot: u64,
+    /// Program account to deploy.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub program_address: Pubkey,
+    /// Program account to deploy the program data of.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub source_address: Pubkey,
+    /// Hash of the program data of the source program when the deployment
+    /// was proposed.
+    pub hash: [u8; 32],
+    /// Account refunded the lamports of the proposal when it is closed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer_address: Pubkey,
+    /// Addresses which approved the deployment.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub approvals: Vec<Pubkey>,
+}
+
+impl LoaderV4DeployProposal {
+    /// Length of a proposal account.
+    pub const LEN: usize = 529;
+
+    /// Authority in the header of proposal accounts, which makes them valid
+    /// retracted program accounts nobody can sign for.
+    pub const AUTHORITY: Pubkey = Pubkey::new_from_array([0; 32]);
+
+    /// Decodes a proposal account from its raw data.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != Self::LEN
+            || data[0x08..0x28] != Self::AUTHORITY.to_bytes()
+            || data[0x28..0x30] != [0; 8]
+        {
+            return Err(Error::new(ErrorKind::InvalidData, "Not a proposal account"));
+        }
+        let approvals_len = data[0xb0] as usize;
+        if approvals_len > (Self::LEN - 0xb1) / 32 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid number of approvals: {approvals_len}"),
+            ));
+        }
+        Ok(Self {
+            slot: u64::from_le_bytes(data[..0x08].try_into().unwrap()),
+            program_address: Pubkey::new_from_array(data[0x30..0x50].try_into().unwrap()),
+            source_address: Pubkey::new_from_array(data[0x50..0x70].try_into().unwrap()),
+            hash: data[0x70..0x90].try_into().unwrap(),
+            payer_address: Pubkey::new_from_array(data[0x90..0xb0].try_into().unwrap()),
+            approvals: data[0xb1..]
+                .chunks_exact(32)
+                .take(approvals_len)
+                .map(|approval| Pubkey::new_from_array(approval.try_into().unwrap()))
+                .collect(),
+        })
+    }
+}
+
+impl<'a> TryFrom<&AccountInfo<'a>> for LoaderV4ProgramAccount {
+    type Error = Error;
+
+    fn try_from(account_info: &AccountInfo<'a>) -> Result<Self, Self::Error> {
+        let data: &[u8] = &(*account_info.data).borrow();
+        Self::from_bytes(data)
+    }
+}
@@ -0,0 +1,210 @@
+//! Program events, logged with `sol_log_data` by every successful instruction
+//! changing a program account.
+
+use {
+    crate::{
+        accounts::{LoaderV4ProgramAccount, LoaderV4Status},
+        LOADER_V4_ID,
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::maybestd::io::{Error, ErrorKind},
+    solana_program::pubkey::Pubkey,
+};
+
+/// Version of the event layout this client decodes.
+// Keep in sync with `solana_loader_v4_program::event::EVENT_VERSION`.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Instruction which emitted an event.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LoaderV4EventKind {
+    Write,
+    Truncate,
+    Deploy,
+    Retract,
+    TransferAuthority,
+    Finalize,
+    Migrate,
+    Copy,
+    WriteBatch,
+    DeployWithHash,
+    ProposeAuthority = 11,
+    AcceptAuthority,
+    CancelAuthorityProposal,
+    ScheduleDeploy,
+    ExecuteScheduledDeploy,
+    CancelScheduledDeploy,
+    Close,
+    Create,
+    CreateWithSeed,
+    SetMultisig,
+    RemoveMultisig,
+    ProposeDeploy,
+    Approve,
+    ExecuteProposal,
+    CancelProposal,
+    SetWriter,
+    RevokeWriter,
+}
+
+/// State header of a program account, as carried by an event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4EventState {
+    /// Slot in which the program was last deployed, retracted or initialized.
+    pub slot: u64,
+    /// The authority, or the next version once the program is finalized.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority_address_or_next_version: Pubkey,
+    /// Deployment status.
+    pub status: LoaderV4Status,
+}
+
+/// State transition of a program account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoaderV4Event {
+    /// Instruction which emitted the event.
+    pub kind: LoaderV4EventKind,
+    /// Address of the program account.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub program_address: Pubkey,
+    /// State before the instruction, `None` if the account was not
+    /// initialized.
+    pub old_state: Option<LoaderV4EventState>,
+    /// State after the instruction, `None` if the account was closed.
+    pub new_state: Option<LoaderV4EventState>,
+    /// Length of the program data after the instruction.
+    pub data_len: u64,
+    /// Lamports moved out of the program account by `Truncate` and `Close`,
+    /// into it by `Deploy` and `ExecuteScheduledDeploy` from a source program,
+    /// or funded by `Create` and `CreateWithSeed`.
+    pub lamports: u64,
+}
+
+impl LoaderV4Event {
+    /// Length of a packed event.
+    pub const LEN: usize = 148;
+
+    /// Decodes an event from the data logged by the program.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != Self::LEN {
+            return Err(Error::new(ErrorKind::InvalidData, "Invalid event length"));
+        }
+        if data[0] != EVENT_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Unsupported event version: {}", data[0]),
+            ));
+        }
+        let kind = match data[1] {
+            0 => LoaderV4EventKind::Write,
+            1 => LoaderV4EventKind::Truncate,
+            2 => LoaderV4EventKind::Deploy,
+            3 => LoaderV4EventKind::Retract,
+            4 => LoaderV4EventKind::TransferAuthority,
+            5 => LoaderV4EventKind::Finalize,
+            6 => LoaderV4EventKind::Migrate,
+            7 => LoaderV4EventKind::Copy,
+            8 => LoaderV4EventKind::WriteBatch,
+            9 => LoaderV4EventKind::DeployWithHash,
+            11 => LoaderV4EventKind::ProposeAuthority,
+            12 => LoaderV4EventKind::AcceptAuthority,
+            13 => LoaderV4EventKind::CancelAuthorityProposal,
+            14 => LoaderV4EventKind::ScheduleDeploy,
+            15 => LoaderV4EventKind::ExecuteScheduledDeploy,
+            16 => LoaderV4EventKind::CancelScheduledDeploy,
+            17 => LoaderV4EventKind::Close,
+            18 => LoaderV4EventKind::Create,
+            19 => LoaderV4EventKind::CreateWithSeed,
+            20 => LoaderV4EventKind::SetMultisig,
+            21 => LoaderV4EventKind::RemoveMultisig,
+            22 => LoaderV4EventKind::ProposeDeploy,
+            23 => LoaderV4EventKind::Approve,
+            24 => LoaderV4EventKind::ExecuteProposal,
+            25 => LoaderV4EventKind::CancelProposal,
+            26 => LoaderV4EventKind::SetWriter,
+            27 => LoaderV4EventKind::RevokeWriter,
+            kind => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid event kind: {kind}"),
+                ))
+            }
+        };
+        Ok(Self {
+            kind,
+            program_address: Pubkey::new_from_array(data[2..34].try_into().unwrap()),
+            old_state: state_from_bytes(&data[34..83])?,
+            new_state: state_from_bytes(&data[83..132])?,
+            data_len: u64::from_le_bytes(data[132..140].try_into().unwrap()),
+            lamports: u64::from_le_bytes(data[140..148].try_into().unwrap()),
+        })
+    }
+
+    /// Decodes the events logged by the program in the log messages of a
+    /// transaction, in order.
+    ///
+    /// Only data logged while the loader itself is executing is decoded, so
+    /// events of other programs are skipped. The transaction must have
+    /// succeeded, since the log messages of a failed transaction may contain
+    /// events of instructions which were rolled back.
+    pub fn from_log_messages<S: AsRef<str>>(log_messages: &[S]) -> Result<Vec<Self>, Error> {
+        let loader_id = LOADER_V4_ID.to_string();
+        let mut invocations = Vec::new();
+        let mut events = Vec::new();
+        for log_message in log_messages {
+            let log_message = log_message.as_ref();
+            if let Some(fields) = log_message.strip_prefix("Program data: ") {
+                if invocations.last() != Some(&loader_id.as_str()) {
+                    continue;
+                }
+                for field in fields.split(' ') {
+                    let data = STANDARD
+                        .decode(field)
+                        .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+                    events.push(Self::from_bytes(&data)?);
+                }
+            } else if let Some(rest) = log_message.strip_prefix("Program ") {
+                let mut words = rest.split(' ');
+                match (words.next(), words.next()) {
+                    (Some(program_id), Some("invoke")) => invocations.push(program_id),
+                    (Some(_), Some("success")) => {
+                        invocations.pop();
+                    }
+                    (Some(_), Some("failed:")) => {
+                        invocations.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+fn state_from_bytes(data: &[u8]) -> Result<Option<LoaderV4EventState>, Error> {
+    match data[0] {
+        0 => Ok(None),
+        1 => {
+            let account = LoaderV4ProgramAccount::from_bytes(&data[1..])?;
+            Ok(Some(LoaderV4EventState {
+                slot: account.slot,
+                authority_address_or_next_version: account.authority_address_or_next_version,
+                status: account.status,
+            }))
+        }
+        flag => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid state flag: {flag}"),
+        )),
+    }
+}
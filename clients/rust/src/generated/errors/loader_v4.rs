@@ -0,0 +1,102 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use num_derive::FromPrimitive;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum LoaderV4Error {
+    /// 0 (0x0) - Program is not writeable
+    #[error("Program is not writeable")]
+    ProgramNotWritable = 0x0,
+    /// 1 (0x1) - Program is not retracted
+    #[error("Program is not retracted")]
+    ProgramNotRetracted = 0x1,
+    /// 2 (0x2) - Program is not deployed
+    #[error("Program is not deployed")]
+    ProgramNotDeployed = 0x2,
+    /// 3 (0x3) - Program was deployed recently, cooldown still in effect
+    #[error("Program was deployed recently, cooldown still in effect")]
+    DeploymentCooldown = 0x3,
+    /// 4 (0x4) - Source program is not retracted
+    #[error("Source program is not retracted")]
+    SourceProgramNotRetracted = 0x4,
+    /// 5 (0x5) - Recipient is not writeable
+    #[error("Recipient is not writeable")]
+    DestinationNotWritable = 0x5,
+    /// 6 (0x6) - No change
+    #[error("No change")]
+    AuthorityUnchanged = 0x6,
+    /// 7 (0x7) - Program data is not a valid ELF
+    #[error("Program data is not a valid ELF")]
+    InvalidElf = 0x7,
+    /// 8 (0x8) - Program account state is corrupt
+    #[error("Program account state is corrupt")]
+    InvalidProgramState = 0x8,
+    /// 9 (0x9) - Program data does not match the expected hash
+    #[error("Program data does not match the expected hash")]
+    ProgramDataHashMismatch = 0x9,
+    /// 10 (0xA) - No pending authority
+    #[error("No pending authority")]
+    NoPendingAuthority = 0xA,
+    /// 11 (0xB) - Scheduled deployment is not due yet
+    #[error("Scheduled deployment is not due yet")]
+    ScheduledDeployNotDue = 0xB,
+    /// 12 (0xC) - No scheduled deployment
+    #[error("No scheduled deployment")]
+    NoScheduledDeploy = 0xC,
+    /// 13 (0xD) - Source program must differ from the program
+    #[error("Source program must differ from the program")]
+    SourceIsProgram = 0xD,
+    /// 14 (0xE) - Incorrect source program provided
+    #[error("Incorrect source program provided")]
+    IncorrectSourceProgram = 0xE,
+    /// 15 (0xF) - Scheduled deployment must be in a future slot
+    #[error("Scheduled deployment must be in a future slot")]
+    ScheduledDeployNotInFuture = 0xF,
+    /// 16 (0x10) - Program has a scheduled deployment
+    #[error("Program has a scheduled deployment")]
+    DeployScheduled = 0x10,
+    /// 17 (0x11) - Destination must differ from the program
+    #[error("Destination must differ from the program")]
+    DestinationIsProgram = 0x11,
+    /// 18 (0x12) - Program account is too large to create
+    #[error("Program account is too large to create")]
+    CreateSizeTooLarge = 0x12,
+    /// 19 (0x13) - Invalid multisig
+    #[error("Invalid multisig")]
+    InvalidMultisig = 0x13,
+    /// 20 (0x14) - No multisig
+    #[error("No multisig")]
+    NoMultisig = 0x14,
+    /// 21 (0x15) - Proposal is not approved
+    #[error("Proposal is not approved")]
+    ProposalNotApproved = 0x15,
+    /// 22 (0x16) - Already approved
+    #[error("Already approved")]
+    AlreadyApproved = 0x16,
+    /// 23 (0x17) - Proposal is not writeable
+    #[error("Proposal is not writeable")]
+    ProposalNotWritable = 0x17,
+    /// 24 (0x18) - Proposal is for another program
+    #[error("Proposal is for another program")]
+    IncorrectProposal = 0x18,
+    /// 25 (0x19) - Incorrect payer provided
+    #[error("Incorrect payer provided")]
+    IncorrectPayer = 0x19,
+    /// 26 (0x1A) - No writer
+    #[error("No writer")]
+    NoWriter = 0x1A,
+    /// 27 (0x1B) - Writer must differ from the program and its authority
+    #[error("Writer must differ from the program and its authority")]
+    InvalidWriter = 0x1B,
+}
+
+impl solana_program::program_error::PrintProgramError for LoaderV4Error {
+    fn print<E>(&self) {
+        solana_program::msg!(&self.to_string());
+    }
+}
@@ -3,3 +3,7 @@
 //! to add features, then rerun kinobi to update it.
 //!
 //! <https://github.com/kinobi-so/kinobi>
+
+pub(crate) mod r#loader_v4;
+
+pub use self::r#loader_v4::LoaderV4Error;
@@ -0,0 +1,451 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct Copy {
+    /// Program account to copy to.
+    pub program: solana_program::pubkey::Pubkey,
+    /// Program authority or writer.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Source account to copy from.
+    pub source: solana_program::pubkey::Pubkey,
+}
+
+impl Copy {
+    pub fn instruction(
+        &self,
+        args: CopyInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CopyInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.source,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = CopyInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CopyInstructionData {
+    discriminator: u32,
+}
+
+impl CopyInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 7 }
+    }
+}
+
+impl Default for CopyInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CopyInstructionArgs {
+    pub destination_offset: u32,
+    pub source_offset: u32,
+    pub length: u32,
+}
+
+/// Instruction builder for `Copy`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` program
+///   1. `[signer]` authority
+///   2. `[]` source
+#[derive(Clone, Debug, Default)]
+pub struct CopyBuilder {
+    program: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    source: Option<solana_program::pubkey::Pubkey>,
+    destination_offset: Option<u32>,
+    source_offset: Option<u32>,
+    length: Option<u32>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CopyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Program account to copy to.
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Program authority or writer.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Source account to copy from.
+    #[inline(always)]
+    pub fn source(&mut self, source: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.source = Some(source);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_offset(&mut self, destination_offset: u32) -> &mut Self {
+        self.destination_offset = Some(destination_offset);
+        self
+    }
+    #[inline(always)]
+    pub fn source_offset(&mut self, source_offset: u32) -> &mut Self {
+        self.source_offset = Some(source_offset);
+        self
+    }
+    #[inline(always)]
+    pub fn length(&mut self, length: u32) -> &mut Self {
+        self.length = Some(length);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = Copy {
+            program: self.program.expect("program is not set"),
+            authority: self.authority.expect("authority is not set"),
+            source: self.source.expect("source is not set"),
+        };
+        let args = CopyInstructionArgs {
+            destination_offset: self
+                .destination_offset
+                .clone()
+                .expect("destination_offset is not set"),
+            source_offset: self
+                .source_offset
+                .clone()
+                .expect("source_offset is not set"),
+            length: self.length.clone().expect("length is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `copy` CPI accounts.
+pub struct CopyCpiAccounts<'a, 'b> {
+    /// Program account to copy to.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program authority or writer.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source account to copy from.
+    pub source: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `copy` CPI instruction.
+pub struct CopyCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program account to copy to.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program authority or writer.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source account to copy from.
+    pub source: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: CopyInstructionArgs,
+}
+
+impl<'a, 'b> CopyCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CopyCpiAccounts<'a, 'b>,
+        args: CopyInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            program: accounts.program,
+            authority: accounts.authority,
+            source: accounts.source,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.source.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = CopyInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.source.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `Copy` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` program
+///   1. `[signer]` authority
+///   2. `[]` source
+#[derive(Clone, Debug)]
+pub struct CopyCpiBuilder<'a, 'b> {
+    instruction: Box<CopyCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CopyCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CopyCpiBuilderInstruction {
+            __program: program,
+            program: None,
+            authority: None,
+            source: None,
+            destination_offset: None,
+            source_offset: None,
+            length: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Program account to copy to.
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Program authority or writer.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Source account to copy from.
+    #[inline(always)]
+    pub fn source(
+        &mut self,
+        source: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source = Some(source);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_offset(&mut self, destination_offset: u32) -> &mut Self {
+        self.instruction.destination_offset = Some(destination_offset);
+        self
+    }
+    #[inline(always)]
+    pub fn source_offset(&mut self, source_offset: u32) -> &mut Self {
+        self.instruction.source_offset = Some(source_offset);
+        self
+    }
+    #[inline(always)]
+    pub fn length(&mut self, length: u32) -> &mut Self {
+        self.instruction.length = Some(length);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = CopyInstructionArgs {
+            destination_offset: self
+                .instruction
+                .destination_offset
+                .clone()
+                .expect("destination_offset is not set"),
+            source_offset: self
+                .instruction
+                .source_offset
+                .clone()
+                .expect("source_offset is not set"),
+            length: self.instruction.length.clone().expect("length is not set"),
+        };
+        let instruction = CopyCpi {
+            __program: self.instruction.__program,
+
+            program: self.instruction.program.expect("program is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            source: self.instruction.source.expect("source is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CopyCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_offset: Option<u32>,
+    source_offset: Option<u32>,
+    length: Option<u32>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -37,11 +37,6 @@ impl Deploy {
         ));
         if let Some(source) = self.source {
             accounts.push(solana_program::instruction::AccountMeta::new(source, false));
-        } else {
-            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-                crate::LOADER_V4_ID,
-                false,
-            ));
         }
         accounts.extend_from_slice(remaining_accounts);
         let data = DeployInstructionData::new().try_to_vec().unwrap();
@@ -56,7 +51,7 @@ impl Deploy {
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct DeployInstructionData {
-    discriminator: u8,
+    discriminator: u32,
 }
 
 impl DeployInstructionData {
@@ -223,11 +218,6 @@ impl<'a, 'b> DeployCpi<'a, 'b> {
                 *source.key,
                 false,
             ));
-        } else {
-            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-                crate::LOADER_V4_ID,
-                false,
-            ));
         }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
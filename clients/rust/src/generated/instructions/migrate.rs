@@ -0,0 +1,450 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct Migrate {
+    /// Program account to migrate into.
+    pub program: solana_program::pubkey::Pubkey,
+    /// Upgrade authority of the loader-v3 program.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Loader-v3 program account.
+    pub v3_program: solana_program::pubkey::Pubkey,
+    /// Loader-v3 program data account.
+    pub v3_program_data: solana_program::pubkey::Pubkey,
+}
+
+impl Migrate {
+    pub fn instruction(
+        &self,
+        args: MigrateInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigrateInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.program,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.v3_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.v3_program_data,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MigrateInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MigrateInstructionData {
+    discriminator: u32,
+}
+
+impl MigrateInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 6 }
+    }
+}
+
+impl Default for MigrateInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateInstructionArgs {
+    pub deploy: bool,
+}
+
+/// Instruction builder for `Migrate`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` program
+///   1. `[signer]` authority
+///   2. `[]` v3_program
+///   3. `[]` v3_program_data
+#[derive(Clone, Debug, Default)]
+pub struct MigrateBuilder {
+    program: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    v3_program: Option<solana_program::pubkey::Pubkey>,
+    v3_program_data: Option<solana_program::pubkey::Pubkey>,
+    deploy: Option<bool>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigrateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Program account to migrate into.
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Upgrade authority of the loader-v3 program.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Loader-v3 program account.
+    #[inline(always)]
+    pub fn v3_program(&mut self, v3_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.v3_program = Some(v3_program);
+        self
+    }
+    /// Loader-v3 program data account.
+    #[inline(always)]
+    pub fn v3_program_data(
+        &mut self,
+        v3_program_data: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.v3_program_data = Some(v3_program_data);
+        self
+    }
+    #[inline(always)]
+    pub fn deploy(&mut self, deploy: bool) -> &mut Self {
+        self.deploy = Some(deploy);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = Migrate {
+            program: self.program.expect("program is not set"),
+            authority: self.authority.expect("authority is not set"),
+            v3_program: self.v3_program.expect("v3_program is not set"),
+            v3_program_data: self.v3_program_data.expect("v3_program_data is not set"),
+        };
+        let args = MigrateInstructionArgs {
+            deploy: self.deploy.clone().expect("deploy is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate` CPI accounts.
+pub struct MigrateCpiAccounts<'a, 'b> {
+    /// Program account to migrate into.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Upgrade authority of the loader-v3 program.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Loader-v3 program account.
+    pub v3_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Loader-v3 program data account.
+    pub v3_program_data: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `migrate` CPI instruction.
+pub struct MigrateCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program account to migrate into.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Upgrade authority of the loader-v3 program.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Loader-v3 program account.
+    pub v3_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Loader-v3 program data account.
+    pub v3_program_data: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MigrateInstructionArgs,
+}
+
+impl<'a, 'b> MigrateCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigrateCpiAccounts<'a, 'b>,
+        args: MigrateInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            program: accounts.program,
+            authority: accounts.authority,
+            v3_program: accounts.v3_program,
+            v3_program_data: accounts.v3_program_data,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.program.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.v3_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.v3_program_data.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MigrateInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.v3_program.clone());
+        account_infos.push(self.v3_program_data.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `Migrate` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` program
+///   1. `[signer]` authority
+///   2. `[]` v3_program
+///   3. `[]` v3_program_data
+#[derive(Clone, Debug)]
+pub struct MigrateCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateCpiBuilderInstruction {
+            __program: program,
+            program: None,
+            authority: None,
+            v3_program: None,
+            v3_program_data: None,
+            deploy: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Program account to migrate into.
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Upgrade authority of the loader-v3 program.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Loader-v3 program account.
+    #[inline(always)]
+    pub fn v3_program(
+        &mut self,
+        v3_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.v3_program = Some(v3_program);
+        self
+    }
+    /// Loader-v3 program data account.
+    #[inline(always)]
+    pub fn v3_program_data(
+        &mut self,
+        v3_program_data: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.v3_program_data = Some(v3_program_data);
+        self
+    }
+    #[inline(always)]
+    pub fn deploy(&mut self, deploy: bool) -> &mut Self {
+        self.instruction.deploy = Some(deploy);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MigrateInstructionArgs {
+            deploy: self.instruction.deploy.clone().expect("deploy is not set"),
+        };
+        let instruction = MigrateCpi {
+            __program: self.instruction.__program,
+
+            program: self.instruction.program.expect("program is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            v3_program: self.instruction.v3_program.expect("v3_program is not set"),
+
+            v3_program_data: self
+                .instruction
+                .v3_program_data
+                .expect("v3_program_data is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    v3_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    v3_program_data: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deploy: Option<bool>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -4,13 +4,41 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#accept_authority;
+pub(crate) mod r#approve;
+pub(crate) mod r#cancel_authority_proposal;
+pub(crate) mod r#cancel_proposal;
+pub(crate) mod r#cancel_scheduled_deploy;
+pub(crate) mod r#close;
+pub(crate) mod r#copy;
+pub(crate) mod r#create;
+pub(crate) mod r#create_with_seed;
 pub(crate) mod r#deploy;
+pub(crate) mod r#deploy_with_hash;
+pub(crate) mod r#execute_proposal;
+pub(crate) mod r#execute_scheduled_deploy;
 pub(crate) mod r#finalize;
+pub(crate) mod r#hash;
+pub(crate) mod r#migrate;
+pub(crate) mod r#propose_authority;
+pub(crate) mod r#propose_deploy;
+pub(crate) mod r#remove_multisig;
 pub(crate) mod r#retract;
+pub(crate) mod r#revoke_writer;
+pub(crate) mod r#schedule_deploy;
+pub(crate) mod r#set_multisig;
+pub(crate) mod r#set_writer;
 pub(crate) mod r#transfer_authority;
 pub(crate) mod r#truncate;
 pub(crate) mod r#write;
+pub(crate) mod r#write_batch;
 
 pub use self::{
-    r#deploy::*, r#finalize::*, r#retract::*, r#transfer_authority::*, r#truncate::*, r#write::*,
+    r#accept_authority::*, r#approve::*, r#cancel_authority_proposal::*, r#cancel_proposal::*,
+    r#cancel_scheduled_deploy::*, r#close::*, r#copy::*, r#create::*, r#create_with_seed::*,
+    r#deploy::*, r#deploy_with_hash::*, r#execute_proposal::*, r#execute_scheduled_deploy::*,
+    r#finalize::*, r#hash::*, r#migrate::*, r#propose_authority::*, r#propose_deploy::*,
+    r#remove_multisig::*, r#retract::*, r#revoke_writer::*, r#schedule_deploy::*,
+    r#set_multisig::*, r#set_writer::*, r#transfer_authority::*, r#truncate::*, r#write::*,
+    r#write_batch::*,
 };
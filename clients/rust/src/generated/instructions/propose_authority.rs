@@ -0,0 +1,383 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ProposeAuthority {
+    /// Program account to change the authority of.
+    pub program: solana_program::pubkey::Pubkey,
+    /// Current program authority.
+    pub current_authority: solana_program::pubkey::Pubkey,
+    /// Proposed program authority.
+    pub proposed_authority: solana_program::pubkey::Pubkey,
+}
+
+impl ProposeAuthority {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.current_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.proposed_authority,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = ProposeAuthorityInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ProposeAuthorityInstructionData {
+    discriminator: u32,
+}
+
+impl ProposeAuthorityInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 11 }
+    }
+}
+
+impl Default for ProposeAuthorityInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `ProposeAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` program
+///   1. `[signer]` current_authority
+///   2. `[]` proposed_authority
+#[derive(Clone, Debug, Default)]
+pub struct ProposeAuthorityBuilder {
+    program: Option<solana_program::pubkey::Pubkey>,
+    current_authority: Option<solana_program::pubkey::Pubkey>,
+    proposed_authority: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ProposeAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Program account to change the authority of.
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Current program authority.
+    #[inline(always)]
+    pub fn current_authority(
+        &mut self,
+        current_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.current_authority = Some(current_authority);
+        self
+    }
+    /// Proposed program authority.
+    #[inline(always)]
+    pub fn proposed_authority(
+        &mut self,
+        proposed_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.proposed_authority = Some(proposed_authority);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ProposeAuthority {
+            program: self.program.expect("program is not set"),
+            current_authority: self
+                .current_authority
+                .expect("current_authority is not set"),
+            proposed_authority: self
+                .proposed_authority
+                .expect("proposed_authority is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `propose_authority` CPI accounts.
+pub struct ProposeAuthorityCpiAccounts<'a, 'b> {
+    /// Program account to change the authority of.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Current program authority.
+    pub current_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Proposed program authority.
+    pub proposed_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `propose_authority` CPI instruction.
+pub struct ProposeAuthorityCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program account to change the authority of.
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Current program authority.
+    pub current_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Proposed program authority.
+    pub proposed_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> ProposeAuthorityCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ProposeAuthorityCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            program: accounts.program,
+            current_authority: accounts.current_authority,
+            proposed_authority: accounts.proposed_authority,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.current_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.proposed_authority.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = ProposeAuthorityInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::LOADER_V4_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.program.clone());
+        account_infos.push(self.current_authority.clone());
+        account_infos.push(self.proposed_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ProposeAuthority` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` program
+///   1. `[signer]` current_authority
+///   2. `[]` proposed_authority
+#[derive(Clone, Debug)]
+pub struct ProposeAuthorityCpiBuilder<'a, 'b> {
+    instruction: Box<ProposeAuthorityCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ProposeAuthorityCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ProposeAuthorityCpiBuilderInstruction {
+            __program: program,
+            program: None,
+            current_authority: None,
+            proposed_authority: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Program account to change the authority of.
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Current program authority.
+    #[inline(always)]
+    pub fn current_authority(
+        &mut self,
+        current_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.current_authority = Some(current_authority);
+        self
+    }
+    /// Proposed program authority.
+    #[inline(always)]
+    pub fn proposed_authority(
+        &mut self,
+        proposed_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.proposed_authority = Some(proposed_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = ProposeAuthorityCpi {
+            __program: self.instruction.__program,
+
+            program: self.instruction.program.expect("program is not set"),
+
+            current_authority: self
+                .instruction
+                .current_authority
+                .expect("current_authority is not set"),
+
+            proposed_authority: self
+                .instruction
+                .proposed_authority
+                .expect("proposed_authority is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ProposeAuthorityCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    current_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    proposed_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
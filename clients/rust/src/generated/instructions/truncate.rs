@@ -10,7 +10,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct Truncate {
     /// Program account to change the size of.
     pub program: solana_program::pubkey::Pubkey,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: solana_program::pubkey::Pubkey,
     /// Destination account for reclaimed lamports (optional).
     pub destination: Option<solana_program::pubkey::Pubkey>,
@@ -43,11 +43,6 @@ impl Truncate {
                 destination,
                 false,
             ));
-        } else {
-            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-                crate::LOADER_V4_ID,
-                false,
-            ));
         }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = TruncateInstructionData::new().try_to_vec().unwrap();
@@ -64,7 +59,7 @@ impl Truncate {
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct TruncateInstructionData {
-    discriminator: u8,
+    discriminator: u32,
 }
 
 impl TruncateInstructionData {
@@ -111,7 +106,7 @@ impl TruncateBuilder {
         self.program = Some(program);
         self
     }
-    /// Program authority.
+    /// Program authority or writer.
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -169,7 +164,7 @@ impl TruncateBuilder {
 pub struct TruncateCpiAccounts<'a, 'b> {
     /// Program account to change the size of.
     pub program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Destination account for reclaimed lamports (optional).
     pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -181,7 +176,7 @@ pub struct TruncateCpi<'a, 'b> {
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Program account to change the size of.
     pub program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Destination account for reclaimed lamports (optional).
     pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -250,11 +245,6 @@ impl<'a, 'b> TruncateCpi<'a, 'b> {
                 *destination.key,
                 false,
             ));
-        } else {
-            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-                crate::LOADER_V4_ID,
-                false,
-            ));
         }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
@@ -324,7 +314,7 @@ impl<'a, 'b> TruncateCpiBuilder<'a, 'b> {
         self.instruction.program = Some(program);
         self
     }
-    /// Program authority.
+    /// Program authority or writer.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -4,13 +4,14 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+use crate::hooked::U64PrefixBytes;
 use borsh::{BorshDeserialize, BorshSerialize};
 
 /// Accounts.
 pub struct Write {
     /// Program account to write to.
     pub program: solana_program::pubkey::Pubkey,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: solana_program::pubkey::Pubkey,
 }
 
@@ -51,7 +52,7 @@ impl Write {
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct WriteInstructionData {
-    discriminator: u8,
+    discriminator: u32,
 }
 
 impl WriteInstructionData {
@@ -70,7 +71,7 @@ impl Default for WriteInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WriteInstructionArgs {
     pub offset: u32,
-    pub bytes: Vec<u8>,
+    pub bytes: U64PrefixBytes,
 }
 
 /// Instruction builder for `Write`.
@@ -84,7 +85,7 @@ pub struct WriteBuilder {
     program: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     offset: Option<u32>,
-    bytes: Option<Vec<u8>>,
+    bytes: Option<U64PrefixBytes>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -98,7 +99,7 @@ impl WriteBuilder {
         self.program = Some(program);
         self
     }
-    /// Program authority.
+    /// Program authority or writer.
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -110,7 +111,7 @@ impl WriteBuilder {
         self
     }
     #[inline(always)]
-    pub fn bytes(&mut self, bytes: Vec<u8>) -> &mut Self {
+    pub fn bytes(&mut self, bytes: U64PrefixBytes) -> &mut Self {
         self.bytes = Some(bytes);
         self
     }
@@ -151,7 +152,7 @@ impl WriteBuilder {
 pub struct WriteCpiAccounts<'a, 'b> {
     /// Program account to write to.
     pub program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -161,7 +162,7 @@ pub struct WriteCpi<'a, 'b> {
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Program account to write to.
     pub program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Program authority.
+    /// Program authority or writer.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: WriteInstructionArgs,
@@ -286,7 +287,7 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
         self.instruction.program = Some(program);
         self
     }
-    /// Program authority.
+    /// Program authority or writer.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -301,7 +302,7 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn bytes(&mut self, bytes: Vec<u8>) -> &mut Self {
+    pub fn bytes(&mut self, bytes: U64PrefixBytes) -> &mut Self {
         self.instruction.bytes = Some(bytes);
         self
     }
@@ -372,7 +373,7 @@ struct WriteCpiBuilderInstruction<'a, 'b> {
     program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     offset: Option<u32>,
-    bytes: Option<Vec<u8>>,
+    bytes: Option<U64PrefixBytes>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
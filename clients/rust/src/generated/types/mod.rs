@@ -0,0 +1,9 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+pub(crate) mod r#write_segment;
+
+pub use self::r#write_segment::*;
@@ -0,0 +1,116 @@
+//! Hand-written types referenced by the generated client.
+
+use {
+    crate::generated::types::WriteSegment,
+    borsh::{
+        maybestd::io::{Read, Result, Write},
+        BorshDeserialize, BorshSerialize,
+    },
+    solana_program::pubkey::Pubkey,
+    std::ops::{Deref, DerefMut},
+};
+
+/// A byte vector prefixed with its length as a `u64`.
+///
+/// The loader decodes instruction data with bincode, which encodes sequence
+/// lengths as `u64` rather than the `u32` used by borsh.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct U64PrefixBytes(pub Vec<u8>);
+
+impl Deref for U64PrefixBytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for U64PrefixBytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for U64PrefixBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<U64PrefixBytes> for Vec<u8> {
+    fn from(bytes: U64PrefixBytes) -> Self {
+        bytes.0
+    }
+}
+
+impl BorshSerialize for U64PrefixBytes {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.0.len() as u64).serialize(writer)?;
+        writer.write_all(&self.0)
+    }
+}
+
+impl BorshDeserialize for U64PrefixBytes {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = u64::deserialize_reader(reader)?;
+        let mut bytes = Vec::new();
+        reader.take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(borsh::maybestd::io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(Self(bytes))
+    }
+}
+
+/// A vector prefixed with its length as a `u64`.
+///
+/// See [`U64PrefixBytes`] for why the prefix differs from borsh's.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct U64PrefixVec<T>(pub Vec<T>);
+
+/// The segments of a `WriteBatch` instruction.
+pub type WriteSegments = U64PrefixVec<WriteSegment>;
+
+/// The signers of a `SetMultisig` instruction.
+pub type MultisigSigners = U64PrefixVec<Pubkey>;
+
+impl<T> Deref for U64PrefixVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for U64PrefixVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for U64PrefixVec<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self(items)
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for U64PrefixVec<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.0.len() as u64).serialize(writer)?;
+        self.0.iter().try_for_each(|item| item.serialize(writer))
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for U64PrefixVec<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = u64::deserialize_reader(reader)?;
+        // Not preallocated, since the length is untrusted.
+        let mut items = Vec::new();
+        for _ in 0..len {
+            items.push(T::deserialize_reader(reader)?);
+        }
+        Ok(Self(items))
+    }
+}
@@ -1,3 +1,10 @@
+pub mod accounts;
+pub mod events;
 mod generated;
+mod hooked;
+pub mod plan;
 
-pub use generated::{programs::LOADER_V4_ID as ID, *};
+pub use {
+    generated::{programs::LOADER_V4_ID as ID, *},
+    hooked::*,
+};
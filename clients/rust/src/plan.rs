@@ -0,0 +1,580 @@
+//! Transaction plans for multi-step program management flows.
+
+use {
+    crate::{
+        accounts::{LoaderV4ProgramAccount, LoaderV4Status},
+        instructions::{
+            DeployWithHashBuilder, MigrateBuilder, RetractBuilder, TruncateBuilder,
+            WriteBatchBuilder, WriteBuilder,
+        },
+        types::WriteSegment,
+        LOADER_V4_ID,
+    },
+    solana_program::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        clock::Slot,
+        entrypoint::MAX_PERMITTED_DATA_INCREASE,
+        hash::hash,
+        instruction::Instruction,
+        message::Message,
+        pubkey::Pubkey,
+        rent::Rent,
+        system_instruction::{self, MAX_PERMITTED_DATA_LENGTH},
+    },
+    thiserror::Error,
+};
+
+/// Maximum size of a serialized transaction.
+// See `solana_sdk::packet::PACKET_DATA_SIZE`.
+pub const PACKET_DATA_SIZE: usize = 1232;
+
+/// Cooldown before a program can be un-/redeployed again.
+// Keep in sync with `solana_loader_v4_program::state::DEPLOYMENT_COOLDOWN_IN_SLOTS`.
+pub const DEPLOYMENT_COOLDOWN_IN_SLOTS: u64 = 750;
+
+/// Size of the program account state header preceding the program data.
+const PROGRAM_DATA_OFFSET: usize = LoaderV4ProgramAccount::HEADER_LEN;
+
+/// Serialized size of a `WriteSegment` besides its bytes: a `u32` offset and
+/// a `u64` length.
+const SEGMENT_HEADER_LEN: usize = 12;
+
+/// Errors that may be returned when planning a deployment.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum PlanError {
+    /// The program data is empty.
+    #[error("Program data is empty")]
+    EmptyProgram,
+    /// The program data does not fit in an account.
+    #[error("Program data is too large")]
+    ProgramTooLarge,
+    /// A `Write` instruction can not fit in a transaction.
+    #[error("No room for program data in a transaction")]
+    TransactionTooSmall,
+    /// The program is finalized and can not be upgraded.
+    #[error("Program is finalized")]
+    ProgramFinalized,
+    /// The given authority does not match the program's.
+    #[error("Incorrect authority provided")]
+    IncorrectAuthority,
+}
+
+/// An ordered list of transactions deploying a program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployPlan {
+    /// Lamports the payer moves into the program account to keep it
+    /// rent-exempt.
+    pub lamports: u64,
+    /// Instructions of each transaction, in execution order. Each transaction
+    /// fits in a packet when paid for by the planned payer.
+    pub transactions: Vec<Vec<Instruction>>,
+}
+
+/// An ordered list of transactions upgrading a program through a buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpgradePlan {
+    /// Lamports the payer moves into the buffer account to keep it
+    /// rent-exempt.
+    pub buffer_lamports: u64,
+    /// Lamports `Deploy` moves from the buffer into the program.
+    pub transfer_lamports: u64,
+    /// First slot in which the last transaction passes the deployment
+    /// cooldown.
+    pub earliest_slot: Slot,
+    /// Instructions of each transaction, in execution order. Each transaction
+    /// fits in a packet when paid for by the planned payer.
+    pub transactions: Vec<Vec<Instruction>>,
+}
+
+/// Plans the deployment of `elf` to a new program account.
+///
+/// The first transaction creates the program account, which must sign along
+/// with the payer and the authority, and sizes it for the program data. As
+/// `Truncate` grows a program by at most `MAX_PERMITTED_DATA_INCREASE` bytes,
+/// larger programs are grown further in the following transactions. They are
+/// followed by as many `Write` transactions as needed and a final
+/// `DeployWithHash`, which only succeeds if the program data matches the hash
+/// of `elf`.
+pub fn plan_deploy(
+    elf: &[u8],
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    rent: &Rent,
+) -> Result<DeployPlan, PlanError> {
+    let new_size = program_size(elf.len())?;
+    let lamports = rent.minimum_balance(PROGRAM_DATA_OFFSET.saturating_add(elf.len()));
+
+    let mut transactions = create(program, authority, payer, new_size, rent);
+    transactions.extend(plan_writes(elf, program, authority, payer)?);
+    transactions.push(vec![DeployWithHashBuilder::new()
+        .program(*program)
+        .authority(*authority)
+        .expected_hash(hash(elf).to_bytes())
+        .instruction()]);
+
+    Ok(DeployPlan {
+        lamports,
+        transactions,
+    })
+}
+
+/// Plans the upgrade of a live program to `elf` through a new `buffer`
+/// account.
+///
+/// The buffer is created and sized like the program account by
+/// `plan_deploy`, and the ELF is written to it first, so that the program is
+/// only retracted for the duration of the last transactions. They retract
+/// the program, resize it and deploy it from the buffer, which is emptied in
+/// the process. `DeployWithHash` checks the buffer against the hash of
+/// `elf`. A program shrinking or growing by at most
+/// `MAX_PERMITTED_DATA_INCREASE` bytes is resized in a single transaction;
+/// one growing further is grown like a new program first, which may take
+/// more. The emptied buffer is then closed, returning its lamports to the
+/// payer, so the buffer must sign the last transaction too.
+///
+/// `program_account` and `program_lamports` describe the program account as
+/// it is on chain.
+#[allow(clippy::too_many_arguments)]
+pub fn plan_upgrade(
+    elf: &[u8],
+    program: &Pubkey,
+    program_account: &LoaderV4ProgramAccount,
+    program_lamports: u64,
+    buffer: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    rent: &Rent,
+) -> Result<UpgradePlan, PlanError> {
+    let new_size = program_size(elf.len())?;
+    let buffer_len = PROGRAM_DATA_OFFSET.saturating_add(elf.len());
+    // The program keeps its extensions, in the trailer after the program data.
+    let overhead = PROGRAM_DATA_OFFSET.saturating_add(program_account.extensions_len());
+    let new_len = overhead.saturating_add(elf.len());
+    let program_len = overhead.saturating_add(program_account.program_data.len());
+
+    if program_account.status == LoaderV4Status::Finalized {
+        return Err(PlanError::ProgramFinalized);
+    }
+    if program_account.authority_address_or_next_version != *authority {
+        return Err(PlanError::IncorrectAuthority);
+    }
+
+    // `Retract` enforces the cooldown unconditionally, `Deploy` only once the
+    // program has been deployed before.
+    let retract = program_account.status == LoaderV4Status::Deployed;
+    let earliest_slot = if retract || program_account.slot != 0 {
+        program_account
+            .slot
+            .saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS)
+    } else {
+        0
+    };
+
+    let buffer_lamports = rent.minimum_balance(buffer_len);
+    let mut transactions = create(buffer, authority, payer, new_size, rent);
+    transactions.extend(plan_writes(elf, buffer, authority, payer)?);
+
+    let mut upgrade = vec![Vec::new()];
+    if retract {
+        pack(
+            &mut upgrade,
+            &[RetractBuilder::new()
+                .program(*program)
+                .authority(*authority)
+                .instruction()],
+            payer,
+        );
+    }
+    // Shrink the program beforehand, since `Deploy` keeps the lamports the
+    // smaller program no longer needs. Truncating returns them to the payer
+    // and leaves exactly the rent-exempt minimum in the program.
+    let program_lamports = if program_len > new_len {
+        let mut shrink = truncate(program, authority, payer, new_size);
+        // Only the initialization of a program requires its signature.
+        shrink.accounts[0].is_signer = false;
+        pack(&mut upgrade, &[shrink], payer);
+        rent.minimum_balance(new_len)
+    } else if new_len.saturating_sub(program_len) > MAX_PERMITTED_DATA_INCREASE {
+        // `Deploy` can not grow the program by that much itself.
+        for step in grow(
+            program,
+            authority,
+            payer,
+            overhead,
+            program_account.program_data.len(),
+            elf.len(),
+            program_lamports,
+            rent,
+        ) {
+            pack(&mut upgrade, &step, payer);
+        }
+        rent.minimum_balance(new_len)
+    } else {
+        program_lamports
+    };
+    pack(
+        &mut upgrade,
+        &[DeployWithHashBuilder::new()
+            .program(*program)
+            .authority(*authority)
+            .source(Some(*buffer))
+            .expected_hash(hash(elf).to_bytes())
+            .instruction()],
+        payer,
+    );
+
+    // Same as `Deploy`: the program is topped up to the rent-exempt minimum of
+    // its new size, and the buffer keeps the rest of its lamports.
+    let transfer_lamports = rent
+        .minimum_balance(new_len)
+        .saturating_sub(program_lamports);
+    let remaining_lamports = buffer_lamports.saturating_sub(transfer_lamports);
+
+    // Reinitialize the emptied buffer, then close it to reclaim its lamports.
+    // The payer lends what the reinitialization lacks, returned on closing.
+    let mut close = Vec::new();
+    let reinit_lamports = rent.minimum_balance(PROGRAM_DATA_OFFSET.saturating_add(1));
+    if remaining_lamports < reinit_lamports {
+        close.push(system_instruction::transfer(
+            payer,
+            buffer,
+            reinit_lamports.saturating_sub(remaining_lamports),
+        ));
+    }
+    close.push(truncate(buffer, authority, payer, 1));
+    close.push(truncate(buffer, authority, payer, 0));
+    pack(&mut upgrade, &close, payer);
+    transactions.extend(upgrade);
+
+    Ok(UpgradePlan {
+        buffer_lamports,
+        transfer_lamports,
+        earliest_slot,
+        transactions,
+    })
+}
+
+/// Plans the migration of a loader-v3 program to a new program account, in a
+/// single transaction.
+///
+/// `v3_program_data_len` is the data length of the loader-v3 program data
+/// account as it is on chain. The program account is allocated to its full
+/// size by the system program, since `Migrate` could not grow it beyond
+/// `MAX_PERMITTED_DATA_INCREASE`. It must sign along with the payer and the
+/// loader-v3 upgrade authority, which becomes the program's authority.
+pub fn plan_migrate(
+    v3_program: &Pubkey,
+    v3_program_data_len: usize,
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    deploy: bool,
+    rent: &Rent,
+) -> Result<Vec<Instruction>, PlanError> {
+    let elf_len =
+        v3_program_data_len.saturating_sub(UpgradeableLoaderState::size_of_programdata_metadata());
+    program_size(elf_len)?;
+    let space = PROGRAM_DATA_OFFSET.saturating_add(elf_len);
+
+    Ok(vec![
+        system_instruction::create_account(
+            payer,
+            program,
+            rent.minimum_balance(space),
+            space as u64,
+            &LOADER_V4_ID,
+        ),
+        MigrateBuilder::new()
+            .program(*program)
+            .authority(*authority)
+            .v3_program(*v3_program)
+            .v3_program_data(bpf_loader_upgradeable::get_program_data_address(v3_program))
+            .deploy(deploy)
+            .instruction(),
+    ])
+}
+
+/// Splits `elf` into `Write` instructions, one per transaction, each carrying
+/// as many bytes as fit in a packet.
+pub fn plan_writes(
+    elf: &[u8],
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> Result<Vec<Vec<Instruction>>, PlanError> {
+    let chunk_len = max_write_len(program, authority, payer)?;
+    Ok(elf
+        .chunks(chunk_len)
+        .zip((0..).step_by(chunk_len))
+        .map(|(bytes, offset)| vec![write(program, authority, offset, bytes)])
+        .collect())
+}
+
+/// Returns the segments of `new` that differ from `old`.
+///
+/// Bytes past the end of `old` are compared against zeros, which is what
+/// `Truncate` fills a growing program with. Differences separated by fewer
+/// bytes than a segment header are merged into one segment.
+pub fn diff(old: &[u8], new: &[u8]) -> Vec<WriteSegment> {
+    let differs = |i: usize| new[i] != old.get(i).copied().unwrap_or(0);
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+    while i < new.len() {
+        if !differs(i) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < new.len() && differs(i) {
+            i += 1;
+        }
+        match ranges.last_mut() {
+            Some((_, end)) if start - *end <= SEGMENT_HEADER_LEN => *end = i,
+            _ => ranges.push((start, i)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| WriteSegment {
+            offset: start as u32,
+            bytes: new[start..end].to_vec().into(),
+        })
+        .collect()
+}
+
+/// Plans the `WriteBatch` transactions patching a retracted program from
+/// `old` to `new`, one instruction per transaction.
+///
+/// The program must already have the size of `new`. Segments are packed into
+/// as few transactions as possible and split when they do not fit in one.
+pub fn plan_write_batches(
+    old: &[u8],
+    new: &[u8],
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> Result<Vec<Vec<Instruction>>, PlanError> {
+    let size = |segments: &[WriteSegment]| {
+        transaction_size(&[write_batch(program, authority, segments)], payer)
+    };
+
+    let mut transactions = Vec::new();
+    let mut batch = Vec::new();
+    for mut segment in diff(old, new) {
+        loop {
+            batch.push(segment);
+            if size(&batch) <= PACKET_DATA_SIZE {
+                break;
+            }
+            segment = batch.pop().unwrap();
+
+            // Fill the rest of the packet with the head of the segment.
+            let empty = WriteSegment {
+                offset: segment.offset,
+                bytes: Vec::new().into(),
+            };
+            batch.push(empty);
+            let mut len = PACKET_DATA_SIZE
+                .saturating_sub(size(&batch))
+                .min(segment.bytes.len());
+            batch.pop();
+            while len > 0 {
+                let head = WriteSegment {
+                    offset: segment.offset,
+                    bytes: segment.bytes[..len].to_vec().into(),
+                };
+                batch.push(head);
+                if size(&batch) <= PACKET_DATA_SIZE {
+                    break;
+                }
+                batch.pop();
+                len -= 1;
+            }
+
+            if batch.is_empty() {
+                return Err(PlanError::TransactionTooSmall);
+            }
+            transactions.push(vec![write_batch(program, authority, &batch)]);
+            batch.clear();
+            segment = WriteSegment {
+                offset: segment.offset + len as u32,
+                bytes: segment.bytes[len..].to_vec().into(),
+            };
+        }
+    }
+    if !batch.is_empty() {
+        transactions.push(vec![write_batch(program, authority, &batch)]);
+    }
+    Ok(transactions)
+}
+
+/// Returns the largest number of bytes a single `Write` can carry in a
+/// transaction paid for by `payer`.
+pub fn max_write_len(
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> Result<usize, PlanError> {
+    let overhead = transaction_size(&[write(program, authority, 0, &[])], payer);
+    let mut len = PACKET_DATA_SIZE
+        .checked_sub(overhead)
+        .filter(|len| *len > 0)
+        .ok_or(PlanError::TransactionTooSmall)?;
+    // The instruction data length prefix grows with the data.
+    while transaction_size(&[write(program, authority, 0, &vec![0; len])], payer) > PACKET_DATA_SIZE
+    {
+        len -= 1;
+    }
+    Ok(len)
+}
+
+/// Returns the size of a signed legacy transaction holding `instructions`.
+pub fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(instructions, Some(payer));
+    let signatures = message.header.num_required_signatures as usize;
+    short_vec_len(signatures) + signatures * 64 + message.serialize().len()
+}
+
+fn program_size(len: usize) -> Result<u32, PlanError> {
+    if len == 0 {
+        return Err(PlanError::EmptyProgram);
+    }
+    if PROGRAM_DATA_OFFSET.saturating_add(len) > MAX_PERMITTED_DATA_LENGTH as usize {
+        return Err(PlanError::ProgramTooLarge);
+    }
+    Ok(len as u32)
+}
+
+/// Creates `program` and grows it to `new_size` bytes of program data, by at
+/// most `MAX_PERMITTED_DATA_INCREASE` per `Truncate`, each preceded by the
+/// lamports the larger program needs to remain rent-exempt. The instructions
+/// are packed into as few transactions as fit in a packet, of which only the
+/// first requires the signature of the program.
+fn create(
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    new_size: u32,
+    rent: &Rent,
+) -> Vec<Vec<Instruction>> {
+    let lamports = |size: usize| rent.minimum_balance(PROGRAM_DATA_OFFSET.saturating_add(size));
+    let new_size = new_size as usize;
+
+    // The initial `Truncate` grows the empty account by the header too.
+    let size = new_size.min(MAX_PERMITTED_DATA_INCREASE.saturating_sub(PROGRAM_DATA_OFFSET));
+    let mut transactions = vec![vec![
+        system_instruction::create_account(payer, program, lamports(size), 0, &LOADER_V4_ID),
+        TruncateBuilder::new()
+            .program(*program)
+            .authority(*authority)
+            .new_size(size as u32)
+            .instruction(),
+    ]];
+
+    for step in grow(
+        program,
+        authority,
+        payer,
+        PROGRAM_DATA_OFFSET,
+        size,
+        new_size,
+        lamports(size),
+        rent,
+    ) {
+        pack(&mut transactions, &step, payer);
+    }
+    transactions
+}
+
+/// Grows the retracted `program` from `size` to `new_size` bytes of program
+/// data, by at most `MAX_PERMITTED_DATA_INCREASE` per `Truncate`, each
+/// preceded by the lamports the larger program needs to remain rent-exempt.
+///
+/// `overhead` is the size of the header and the extension trailer of the
+/// program, and `lamports` are those it holds.
+#[allow(clippy::too_many_arguments)]
+fn grow(
+    program: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    overhead: usize,
+    mut size: usize,
+    new_size: usize,
+    mut lamports: u64,
+    rent: &Rent,
+) -> Vec<[Instruction; 2]> {
+    let mut steps = Vec::new();
+    while size < new_size {
+        let next_size = size
+            .saturating_add(MAX_PERMITTED_DATA_INCREASE)
+            .min(new_size);
+        let next_lamports = rent.minimum_balance(overhead.saturating_add(next_size));
+        let mut grow = TruncateBuilder::new()
+            .program(*program)
+            .authority(*authority)
+            .new_size(next_size as u32)
+            .instruction();
+        // Only the initialization of a program requires its signature.
+        grow.accounts[0].is_signer = false;
+        steps.push([
+            system_instruction::transfer(payer, program, next_lamports.saturating_sub(lamports)),
+            grow,
+        ]);
+        size = next_size;
+        lamports = lamports.max(next_lamports);
+    }
+    steps
+}
+
+/// Appends `step` to the last of `transactions`, or to a new transaction if
+/// it would no longer fit in a packet.
+fn pack(transactions: &mut Vec<Vec<Instruction>>, step: &[Instruction], payer: &Pubkey) {
+    let transaction = transactions.last_mut().unwrap();
+    transaction.extend_from_slice(step);
+    if transaction_size(transaction, payer) > PACKET_DATA_SIZE && transaction.len() > step.len() {
+        transaction.truncate(transaction.len() - step.len());
+        transactions.push(step.to_vec());
+    }
+}
+
+fn truncate(
+    program: &Pubkey,
+    authority: &Pubkey,
+    destination: &Pubkey,
+    new_size: u32,
+) -> Instruction {
+    TruncateBuilder::new()
+        .program(*program)
+        .authority(*authority)
+        .destination(Some(*destination))
+        .new_size(new_size)
+        .instruction()
+}
+
+fn write(program: &Pubkey, authority: &Pubkey, offset: u32, bytes: &[u8]) -> Instruction {
+    WriteBuilder::new()
+        .program(*program)
+        .authority(*authority)
+        .offset(offset)
+        .bytes(bytes.to_vec().into())
+        .instruction()
+}
+
+fn write_batch(program: &Pubkey, authority: &Pubkey, segments: &[WriteSegment]) -> Instruction {
+    WriteBatchBuilder::new()
+        .program(*program)
+        .authority(*authority)
+        .segments(segments.to_vec().into())
+        .instruction()
+}
+
+fn short_vec_len(len: usize) -> usize {
+    match len {
+        0..=0x7f => 1,
+        0x80..=0x3fff => 2,
+        _ => 3,
+    }
+}
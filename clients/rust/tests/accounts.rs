@@ -0,0 +1,321 @@
+//! The client must decode program accounts written by the program.
+
+use {
+    solana_loader_v4_program::{
+        extension::{self, ExtensionType, LayoutVersion, Multisig, ScheduledDeploy},
+        proposal::{self, DeployProposal},
+        state::{self, LoaderV4State, LoaderV4Status as ProgramStatus},
+    },
+    solana_loader_v4_program_client::accounts::{
+        find_program_address, find_proposal_address, LoaderV4DeployProposal, LoaderV4Extension,
+        LoaderV4Multisig, LoaderV4ProgramAccount, LoaderV4ScheduledDeploy, LoaderV4Status,
+        EXTENSIONS_MAGIC, MULTISIG_EXTENSION, PENDING_AUTHORITY_EXTENSION,
+        SCHEDULED_DEPLOY_EXTENSION, WRITER_EXTENSION,
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+fn program_account_data(status: ProgramStatus, program_data: &[u8]) -> (LoaderV4State, Vec<u8>) {
+    let state = LoaderV4State {
+        slot: 42,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status,
+    };
+    let mut data = vec![0; LoaderV4State::program_data_offset()];
+    state.pack(&mut data).unwrap();
+    data.extend_from_slice(program_data);
+    (state, data)
+}
+
+#[test]
+fn decode() {
+    for (status, expected_status) in [
+        (ProgramStatus::Retracted, LoaderV4Status::Retracted),
+        (ProgramStatus::Deployed, LoaderV4Status::Deployed),
+        (ProgramStatus::Finalized, LoaderV4Status::Finalized),
+    ] {
+        let (state, data) = program_account_data(status, &[7; 100]);
+
+        let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+        assert_eq!(account.slot, 42);
+        assert_eq!(
+            account.authority_address_or_next_version,
+            state.authority_address_or_next_version
+        );
+        assert_eq!(account.status, expected_status);
+        assert_eq!(account.program_data, [7; 100]);
+
+        if expected_status == LoaderV4Status::Finalized {
+            assert_eq!(account.authority(), None);
+            assert_eq!(
+                account.next_version(),
+                Some(state.authority_address_or_next_version)
+            );
+        } else {
+            assert_eq!(
+                account.authority(),
+                Some(state.authority_address_or_next_version)
+            );
+            assert_eq!(account.next_version(), None);
+        }
+    }
+}
+
+#[test]
+fn decode_header_only() {
+    let (_, data) = program_account_data(ProgramStatus::Retracted, &[]);
+    assert_eq!(data.len(), LoaderV4ProgramAccount::HEADER_LEN);
+
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert!(account.program_data.is_empty());
+}
+
+/// Switches an account of the legacy layout to the extended one, with the
+/// given extension entries.
+fn extend(data: &mut Vec<u8>, entries: &[(u16, &[u8])]) {
+    let mut area = Vec::new();
+    for (extension_type, value) in entries {
+        area.extend_from_slice(&extension_type.to_le_bytes());
+        area.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        area.extend_from_slice(value);
+    }
+    data.extend_from_slice(&area);
+    data.extend_from_slice(&(area.len() as u32).to_le_bytes());
+    data.extend_from_slice(&extension::EXTENSIONS_MAGIC);
+}
+
+#[test]
+fn decode_extended_layout() {
+    let pending_authority = Pubkey::new_unique();
+    let (state, mut data) = program_account_data(ProgramStatus::Deployed, &[7; 100]);
+    extend(
+        &mut data,
+        &[(0xffff, &[1, 2, 3]), (1, pending_authority.as_ref())],
+    );
+
+    // Same program data and extensions as the program sees.
+    assert_eq!(
+        ExtensionType::PendingAuthority as u16,
+        PENDING_AUTHORITY_EXTENSION
+    );
+    assert_eq!(EXTENSIONS_MAGIC, extension::EXTENSIONS_MAGIC);
+    assert_eq!(extension::layout_version(&data), LayoutVersion::Extended);
+    assert_eq!(LoaderV4State::unpack(&data), Ok(state));
+    assert_eq!(
+        extension::get_extension(&data, ExtensionType::PendingAuthority),
+        Ok(Some(pending_authority.as_ref()))
+    );
+
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(account.status, LoaderV4Status::Deployed);
+    assert_eq!(
+        account.extensions,
+        Some(vec![
+            LoaderV4Extension {
+                extension_type: 0xffff,
+                value: vec![1, 2, 3],
+            },
+            LoaderV4Extension {
+                extension_type: 1,
+                value: pending_authority.to_bytes().to_vec(),
+            },
+        ])
+    );
+    assert_eq!(account.pending_authority(), Some(pending_authority));
+    assert_eq!(
+        account.program_data,
+        data[extension::program_data_range(&data).unwrap()]
+    );
+    assert_eq!(account.program_data, [7; 100]);
+    assert_eq!(account.extensions_len(), data.len() - 48 - 100);
+
+    // An empty trailer keeps the extended layout.
+    let (_, mut data) = program_account_data(ProgramStatus::Deployed, &[7; 100]);
+    extend(&mut data, &[]);
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(account.extensions, Some(Vec::new()));
+    assert_eq!(account.program_data, [7; 100]);
+    assert_eq!(account.extensions_len(), 4 + 8);
+
+    // Accounts of the legacy layout have no extensions.
+    let (_, data) = program_account_data(ProgramStatus::Deployed, &[7; 100]);
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(account.extensions, None);
+    assert_eq!(account.pending_authority(), None);
+    assert_eq!(account.extensions_len(), 0);
+}
+
+#[test]
+fn decode_scheduled_deploy() {
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: Pubkey::new_unique(),
+        not_before_slot: 1_000,
+        hash: [7; 32],
+    };
+    let (_, mut data) = program_account_data(ProgramStatus::Deployed, &[7; 100]);
+    extend(&mut data, &[(2, &scheduled_deploy.pack())]);
+
+    assert_eq!(
+        ExtensionType::ScheduledDeploy as u16,
+        SCHEDULED_DEPLOY_EXTENSION
+    );
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(
+        account.scheduled_deploy(),
+        Some(LoaderV4ScheduledDeploy {
+            source_address: scheduled_deploy.source_address,
+            not_before_slot: 1_000,
+            hash: [7; 32],
+        })
+    );
+    assert_eq!(account.pending_authority(), None);
+}
+
+#[test]
+fn decode_multisig() {
+    let multisig = Multisig {
+        threshold: 2,
+        signers: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+    };
+    let (_, mut data) = program_account_data(ProgramStatus::Deployed, &[7; 100]);
+    extend(&mut data, &[(3, &multisig.pack())]);
+
+    assert_eq!(ExtensionType::Multisig as u16, MULTISIG_EXTENSION);
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(
+        account.multisig(),
+        Some(LoaderV4Multisig {
+            threshold: 2,
+            signers: multisig.signers,
+        })
+    );
+    assert_eq!(account.scheduled_deploy(), None);
+}
+
+#[test]
+fn decode_writer() {
+    let writer = Pubkey::new_unique();
+    let (_, mut data) = program_account_data(ProgramStatus::Retracted, &[7; 100]);
+    extend(&mut data, &[(4, writer.as_ref())]);
+
+    assert_eq!(ExtensionType::Writer as u16, WRITER_EXTENSION);
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+    assert_eq!(account.writer(), Some(writer));
+    assert_eq!(account.pending_authority(), None);
+}
+
+#[test]
+fn fail_invalid_extension_area() {
+    let (_, data) = program_account_data(ProgramStatus::Retracted, &[]);
+
+    // The header has no room for a layout version.
+    let mut upper_status = data.clone();
+    upper_status[0x2c] = 1;
+    assert!(LoaderV4ProgramAccount::from_bytes(&upper_status).is_err());
+
+    let mut too_long = data.clone();
+    extend(&mut too_long, &[]);
+    too_long[48..52].copy_from_slice(&1u32.to_le_bytes());
+    assert!(LoaderV4ProgramAccount::from_bytes(&too_long).is_err());
+
+    let mut truncated_entry = data;
+    extend(&mut truncated_entry, &[(1, &[0; 32])]);
+    truncated_entry[50..52].copy_from_slice(&33u16.to_le_bytes());
+    assert!(LoaderV4ProgramAccount::from_bytes(&truncated_entry).is_err());
+}
+
+#[test]
+fn fail_too_small() {
+    let (_, data) = program_account_data(ProgramStatus::Retracted, &[]);
+
+    assert!(
+        LoaderV4ProgramAccount::from_bytes(&data[..LoaderV4ProgramAccount::HEADER_LEN - 1])
+            .is_err()
+    );
+}
+
+#[test]
+fn fail_invalid_status() {
+    let (_, mut data) = program_account_data(ProgramStatus::Retracted, &[]);
+    data[0x28..0x30].copy_from_slice(&3u64.to_le_bytes());
+
+    assert!(LoaderV4ProgramAccount::from_bytes(&data).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json() {
+    let (state, data) = program_account_data(ProgramStatus::Deployed, &[1, 2, 3]);
+    let account = LoaderV4ProgramAccount::from_bytes(&data).unwrap();
+
+    let json = serde_json::to_value(&account).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "slot": 42,
+            "authority_address_or_next_version": state.authority_address_or_next_version.to_string(),
+            "status": "Deployed",
+            "program_data": "AQID",
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<LoaderV4ProgramAccount>(json).unwrap(),
+        account
+    );
+}
+
+#[test]
+fn derive_program_address() {
+    let base = Pubkey::new_unique();
+
+    for seed in [&b""[..], b"my-program", &[7; 32]] {
+        assert_eq!(
+            find_program_address(&base, seed),
+            state::find_program_address(&base, seed)
+        );
+    }
+    assert_ne!(
+        find_program_address(&base, b"a").0,
+        find_program_address(&Pubkey::new_unique(), b"a").0
+    );
+}
+
+#[test]
+fn decode_deploy_proposal() {
+    let proposal = DeployProposal {
+        slot: 42,
+        program_address: Pubkey::new_unique(),
+        source_address: Pubkey::new_unique(),
+        hash: [7; 32],
+        payer_address: Pubkey::new_unique(),
+        approvals: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+    };
+    let mut data = vec![0; DeployProposal::LEN];
+    proposal.pack(&mut data).unwrap();
+
+    assert_eq!(LoaderV4DeployProposal::LEN, DeployProposal::LEN);
+    assert_eq!(
+        LoaderV4DeployProposal::from_bytes(&data).unwrap(),
+        LoaderV4DeployProposal {
+            slot: 42,
+            program_address: proposal.program_address,
+            source_address: proposal.source_address,
+            hash: [7; 32],
+            payer_address: proposal.payer_address,
+            approvals: proposal.approvals,
+        }
+    );
+    assert_eq!(
+        find_proposal_address(&proposal.program_address),
+        proposal::find_proposal_address(&proposal.program_address)
+    );
+
+    // Program accounts and proposals are told apart.
+    assert!(LoaderV4ProgramAccount::from_bytes(&data).is_err());
+    let (_, program_data) = program_account_data(ProgramStatus::Deployed, &[0; 449]);
+    assert!(LoaderV4DeployProposal::from_bytes(&program_data).is_err());
+}
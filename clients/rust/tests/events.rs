@@ -0,0 +1,127 @@
+//! The client must decode events logged by the program.
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    solana_loader_v4_program::{
+        event::{LoaderV4Event as ProgramEvent, LoaderV4EventKind as ProgramEventKind},
+        state::{LoaderV4State, LoaderV4Status as ProgramStatus},
+    },
+    solana_loader_v4_program_client::{
+        accounts::LoaderV4Status,
+        events::{LoaderV4Event, LoaderV4EventKind, LoaderV4EventState},
+        ID,
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+fn deploy_event() -> ProgramEvent {
+    let authority = Pubkey::new_unique();
+    ProgramEvent {
+        kind: ProgramEventKind::Deploy,
+        program_address: Pubkey::new_unique(),
+        old_state: Some(LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: ProgramStatus::Retracted,
+        }),
+        new_state: Some(LoaderV4State {
+            slot: 800,
+            authority_address_or_next_version: authority,
+            status: ProgramStatus::Deployed,
+        }),
+        data_len: 1_500,
+        lamports: 42,
+    }
+}
+
+fn data_log(event: &ProgramEvent) -> String {
+    format!("Program data: {}", STANDARD.encode(event.pack()))
+}
+
+#[test]
+fn decode() {
+    let event = deploy_event();
+    let authority = event.old_state.unwrap().authority_address_or_next_version;
+
+    assert_eq!(
+        LoaderV4Event::from_bytes(&event.pack()).unwrap(),
+        LoaderV4Event {
+            kind: LoaderV4EventKind::Deploy,
+            program_address: event.program_address,
+            old_state: Some(LoaderV4EventState {
+                slot: 0,
+                authority_address_or_next_version: authority,
+                status: LoaderV4Status::Retracted,
+            }),
+            new_state: Some(LoaderV4EventState {
+                slot: 800,
+                authority_address_or_next_version: authority,
+                status: LoaderV4Status::Deployed,
+            }),
+            data_len: 1_500,
+            lamports: 42,
+        }
+    );
+
+    // Closing a program leaves no state.
+    let event = ProgramEvent {
+        kind: ProgramEventKind::Truncate,
+        new_state: None,
+        ..event
+    };
+    let decoded = LoaderV4Event::from_bytes(&event.pack()).unwrap();
+    assert_eq!(decoded.kind, LoaderV4EventKind::Truncate);
+    assert_eq!(decoded.new_state, None);
+}
+
+#[test]
+fn fail_decode() {
+    let data = deploy_event().pack();
+
+    assert!(LoaderV4Event::from_bytes(&data[..ProgramEvent::LEN - 1]).is_err());
+
+    let mut unknown_version = data;
+    unknown_version[0] += 1;
+    assert!(LoaderV4Event::from_bytes(&unknown_version).is_err());
+
+    let mut unknown_kind = data;
+    unknown_kind[1] = u8::MAX;
+    assert!(LoaderV4Event::from_bytes(&unknown_kind).is_err());
+}
+
+#[test]
+fn decode_log_messages() {
+    let loader = ID.to_string();
+    let other = Pubkey::new_unique().to_string();
+    let retract = ProgramEvent {
+        kind: ProgramEventKind::Retract,
+        ..deploy_event()
+    };
+    let deploy = deploy_event();
+
+    let log_messages = [
+        format!("Program {loader} invoke [1]"),
+        "Program log: Instruction: Retract".to_string(),
+        data_log(&retract),
+        format!("Program {loader} consumed 2000 of 200000 compute units"),
+        format!("Program {loader} success"),
+        // Another program logging the same data is ignored, even when it
+        // invokes the loader.
+        format!("Program {other} invoke [1]"),
+        data_log(&retract),
+        format!("Program {loader} invoke [2]"),
+        data_log(&deploy),
+        format!("Program {loader} success"),
+        data_log(&retract),
+        format!("Program {other} success"),
+    ];
+
+    let events = LoaderV4Event::from_log_messages(&log_messages).unwrap();
+    assert_eq!(
+        events,
+        [
+            LoaderV4Event::from_bytes(&retract.pack()).unwrap(),
+            LoaderV4Event::from_bytes(&deploy.pack()).unwrap(),
+        ]
+    );
+}
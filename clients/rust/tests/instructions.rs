@@ -0,0 +1,540 @@
+//! The client must produce the exact instructions the program decodes.
+
+use {
+    solana_loader_v4_program::instruction as program_instruction,
+    solana_loader_v4_program_client::{
+        accounts::{find_program_address, find_proposal_address},
+        instructions::{
+            AcceptAuthorityBuilder, ApproveBuilder, CancelAuthorityProposalBuilder,
+            CancelProposalBuilder, CancelScheduledDeployBuilder, CloseBuilder, CopyBuilder,
+            CreateBuilder, CreateWithSeedBuilder, DeployBuilder, DeployWithHashBuilder,
+            ExecuteProposalBuilder, ExecuteScheduledDeployBuilder, FinalizeBuilder, HashBuilder,
+            MigrateBuilder, ProposeAuthorityBuilder, ProposeDeployBuilder, RemoveMultisigBuilder,
+            RetractBuilder, RevokeWriterBuilder, ScheduleDeployBuilder, SetMultisigBuilder,
+            SetWriterBuilder, TransferAuthorityBuilder, TruncateBuilder, WriteBatchBuilder,
+            WriteBuilder,
+        },
+        types::WriteSegment,
+    },
+    solana_program::{pubkey::Pubkey, system_program},
+};
+
+#[test]
+fn write() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let bytes = vec![7; 300];
+
+    let instruction = WriteBuilder::new()
+        .program(program)
+        .authority(authority)
+        .offset(42)
+        .bytes(bytes.clone().into())
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::write(&program, &authority, 42, bytes)
+    );
+}
+
+#[test]
+fn truncate() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let instruction = TruncateBuilder::new()
+        .program(program)
+        .authority(authority)
+        .new_size(1_000)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::truncate(&program, &authority, None, 1_000)
+    );
+
+    let instruction = TruncateBuilder::new()
+        .program(program)
+        .authority(authority)
+        .destination(Some(destination))
+        .new_size(0)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::truncate(&program, &authority, Some(&destination), 0)
+    );
+}
+
+#[test]
+fn deploy() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let instruction = DeployBuilder::new()
+        .program(program)
+        .authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::deploy(&program, &authority, None)
+    );
+
+    let instruction = DeployBuilder::new()
+        .program(program)
+        .authority(authority)
+        .source(Some(source))
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::deploy(&program, &authority, Some(&source))
+    );
+
+    let instruction = DeployWithHashBuilder::new()
+        .program(program)
+        .authority(authority)
+        .expected_hash([7; 32])
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::deploy_with_hash(&program, &authority, None, [7; 32])
+    );
+}
+
+#[test]
+fn retract() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = RetractBuilder::new()
+        .program(program)
+        .authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::retract(&program, &authority)
+    );
+}
+
+#[test]
+fn transfer_authority() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+
+    let instruction = TransferAuthorityBuilder::new()
+        .program(program)
+        .current_authority(authority)
+        .new_authority(new_authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::transfer_authority(&program, &authority, &new_authority)
+    );
+}
+
+#[test]
+fn finalize() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let next_version = Pubkey::new_unique();
+
+    let instruction = FinalizeBuilder::new()
+        .program(program)
+        .authority(authority)
+        .next_version(next_version)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::finalize(&program, &authority, &next_version)
+    );
+}
+
+#[test]
+fn migrate() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+
+    for deploy in [false, true] {
+        let instruction = MigrateBuilder::new()
+            .program(program)
+            .authority(authority)
+            .v3_program(v3_program)
+            .v3_program_data(v3_program_data)
+            .deploy(deploy)
+            .instruction();
+        assert_eq!(
+            instruction,
+            program_instruction::migrate(
+                &program,
+                &authority,
+                &v3_program,
+                &v3_program_data,
+                deploy
+            )
+        );
+    }
+}
+
+#[test]
+fn copy() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let instruction = CopyBuilder::new()
+        .program(program)
+        .authority(authority)
+        .source(source)
+        .destination_offset(12)
+        .source_offset(34)
+        .length(56)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::copy(&program, &authority, &source, 12, 34, 56)
+    );
+}
+
+#[test]
+fn write_batch() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let segments = vec![(0, vec![7; 300]), (1_000, vec![]), (42, vec![1, 2, 3])];
+
+    let instruction = WriteBatchBuilder::new()
+        .program(program)
+        .authority(authority)
+        .segments(
+            segments
+                .iter()
+                .map(|(offset, bytes)| WriteSegment {
+                    offset: *offset,
+                    bytes: bytes.clone().into(),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        )
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::write_batch(
+            &program,
+            &authority,
+            segments
+                .into_iter()
+                .map(|(offset, bytes)| program_instruction::WriteSegment { offset, bytes })
+                .collect(),
+        )
+    );
+}
+
+#[test]
+fn hash() {
+    let program = Pubkey::new_unique();
+
+    let instruction = HashBuilder::new()
+        .program(program)
+        .offset(16)
+        .length(1_000)
+        .instruction();
+    assert_eq!(instruction, program_instruction::hash(&program, 16, 1_000));
+}
+
+#[test]
+fn propose_authority() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let instruction = ProposeAuthorityBuilder::new()
+        .program(program)
+        .current_authority(authority)
+        .proposed_authority(proposed_authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::propose_authority(&program, &authority, &proposed_authority)
+    );
+}
+
+#[test]
+fn accept_authority() {
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let instruction = AcceptAuthorityBuilder::new()
+        .program(program)
+        .proposed_authority(proposed_authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::accept_authority(&program, &proposed_authority)
+    );
+}
+
+#[test]
+fn cancel_authority_proposal() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = CancelAuthorityProposalBuilder::new()
+        .program(program)
+        .current_authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::cancel_authority_proposal(&program, &authority)
+    );
+}
+
+#[test]
+fn schedule_deploy() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let instruction = ScheduleDeployBuilder::new()
+        .program(program)
+        .authority(authority)
+        .source(source)
+        .not_before_slot(1_000)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::schedule_deploy(&program, &authority, &source, 1_000)
+    );
+}
+
+#[test]
+fn execute_scheduled_deploy() {
+    let program = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let instruction = ExecuteScheduledDeployBuilder::new()
+        .program(program)
+        .source(source)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::execute_scheduled_deploy(&program, &source)
+    );
+}
+
+#[test]
+fn cancel_scheduled_deploy() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = CancelScheduledDeployBuilder::new()
+        .program(program)
+        .authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::cancel_scheduled_deploy(&program, &authority)
+    );
+}
+
+#[test]
+fn close() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let instruction = CloseBuilder::new()
+        .program(program)
+        .authority(authority)
+        .destination(destination)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::close(&program, &authority, &destination)
+    );
+}
+
+#[test]
+fn create() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let instruction = CreateBuilder::new()
+        .program(program)
+        .authority(authority)
+        .payer(payer)
+        .system_program(system_program::id())
+        .size(1_500)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::create(&program, &authority, &payer, 1_500)
+    );
+}
+
+#[test]
+fn create_with_seed() {
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, bump_seed) = find_program_address(&base, b"my-program");
+
+    let instruction = CreateWithSeedBuilder::new()
+        .program(program)
+        .base(base)
+        .authority(authority)
+        .payer(payer)
+        .system_program(system_program::id())
+        .seed(b"my-program".to_vec().into())
+        .size(1_500)
+        .bump_seed(bump_seed)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::create_with_seed(&base, b"my-program", &authority, &payer, 1_500)
+    );
+}
+
+#[test]
+fn set_multisig() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction = SetMultisigBuilder::new()
+        .program(program)
+        .authority(authority)
+        .threshold(2)
+        .signers(signers.clone().into())
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::set_multisig(&program, &authority, 2, &signers)
+    );
+}
+
+#[test]
+fn remove_multisig() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = RemoveMultisigBuilder::new()
+        .program(program)
+        .authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::remove_multisig(&program, &authority)
+    );
+}
+
+#[test]
+fn propose_deploy() {
+    let program = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let instruction = ProposeDeployBuilder::new()
+        .program(program)
+        .proposal(proposal)
+        .source(source)
+        .proposer(proposer)
+        .payer(payer)
+        .system_program(system_program::id())
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::propose_deploy(&program, &source, &proposer, &payer)
+    );
+}
+
+#[test]
+fn approve() {
+    let program = Pubkey::new_unique();
+    let approver = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let instruction = ApproveBuilder::new()
+        .program(program)
+        .proposal(proposal)
+        .approver(approver)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::approve(&program, &approver)
+    );
+}
+
+#[test]
+fn execute_proposal() {
+    let program = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let instruction = ExecuteProposalBuilder::new()
+        .program(program)
+        .proposal(proposal)
+        .source(source)
+        .payer(payer)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::execute_proposal(&program, &source, &payer)
+    );
+}
+
+#[test]
+fn cancel_proposal() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let instruction = CancelProposalBuilder::new()
+        .program(program)
+        .authority(authority)
+        .proposal(proposal)
+        .payer(payer)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::cancel_proposal(&program, &authority, &payer)
+    );
+}
+
+#[test]
+fn set_writer() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let instruction = SetWriterBuilder::new()
+        .program(program)
+        .authority(authority)
+        .writer(writer)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::set_writer(&program, &authority, &writer)
+    );
+}
+
+#[test]
+fn revoke_writer() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = RevokeWriterBuilder::new()
+        .program(program)
+        .authority(authority)
+        .instruction();
+    assert_eq!(
+        instruction,
+        program_instruction::revoke_writer(&program, &authority)
+    );
+}
@@ -0,0 +1,655 @@
+//! Plans must fit in packets and reproduce the ELF on chain.
+
+use {
+    solana_loader_v4_program::instruction::LoaderV4Instruction,
+    solana_loader_v4_program_client::{
+        accounts::{
+            LoaderV4Extension, LoaderV4ProgramAccount, LoaderV4Status, PENDING_AUTHORITY_EXTENSION,
+        },
+        plan::{
+            diff, max_write_len, plan_deploy, plan_migrate, plan_upgrade, plan_write_batches,
+            transaction_size, PlanError, DEPLOYMENT_COOLDOWN_IN_SLOTS, PACKET_DATA_SIZE,
+        },
+        ID,
+    },
+    solana_program::{
+        bpf_loader_upgradeable, entrypoint::MAX_PERMITTED_DATA_INCREASE, hash::hash,
+        instruction::Instruction, program_utils::limited_deserialize, pubkey::Pubkey, rent::Rent,
+        system_instruction::SystemInstruction, system_program,
+    },
+};
+
+fn decode(data: &[u8]) -> LoaderV4Instruction {
+    limited_deserialize(data, PACKET_DATA_SIZE as u64).unwrap()
+}
+
+#[test]
+fn deploy_plan() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = (0..10_000).map(|i| i as u8).collect::<Vec<_>>();
+
+    let plan = plan_deploy(&elf, &program, &authority, &payer, &Rent::default()).unwrap();
+    assert_eq!(
+        plan.lamports,
+        Rent::default().minimum_balance(48 + elf.len())
+    );
+
+    for transaction in &plan.transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+    }
+
+    let (first, rest) = plan.transactions.split_first().unwrap();
+    let (last, writes) = rest.split_last().unwrap();
+
+    // Create and initialize.
+    assert_eq!(first.len(), 2);
+    assert_eq!(first[0].program_id, system_program::id());
+    assert_eq!(
+        decode(&first[1].data),
+        LoaderV4Instruction::Truncate {
+            new_size: elf.len() as u32
+        }
+    );
+
+    // Write the whole ELF, in order.
+    let mut written = Vec::new();
+    for transaction in writes {
+        assert_eq!(transaction.len(), 1);
+        assert_eq!(transaction[0].program_id, ID);
+        match decode(&transaction[0].data) {
+            LoaderV4Instruction::Write { offset, bytes } => {
+                assert_eq!(offset as usize, written.len());
+                written.extend(bytes);
+            }
+            instruction => panic!("Unexpected instruction: {instruction:?}"),
+        }
+    }
+    assert_eq!(written, elf);
+
+    // Deploy.
+    assert_eq!(last.len(), 1);
+    assert_eq!(
+        decode(&last[0].data),
+        LoaderV4Instruction::DeployWithHash {
+            expected_hash: hash(&elf).to_bytes()
+        }
+    );
+}
+
+/// Replays the transactions creating `account`, up to the first `Write`,
+/// and returns the size it is grown to. Checks that each `Truncate` stays
+/// within the growth limit of an instruction and finds the account exactly
+/// rent-exempt.
+fn replay_create(transactions: &[Vec<Instruction>], account: &Pubkey) -> usize {
+    let rent = Rent::default();
+    let mut lamports = 0;
+    let mut len = 0;
+    for (i, transaction) in transactions.iter().enumerate() {
+        // Only the initialization requires the signature of the account.
+        if i > 0 {
+            assert!(transaction
+                .iter()
+                .flat_map(|instruction| &instruction.accounts)
+                .all(|meta| meta.pubkey != *account || !meta.is_signer));
+        }
+        for instruction in transaction {
+            if instruction.program_id == system_program::id() {
+                assert_eq!(instruction.accounts[1].pubkey, *account);
+                lamports += match limited_deserialize(&instruction.data, 1_232).unwrap() {
+                    SystemInstruction::CreateAccount { lamports, .. }
+                    | SystemInstruction::Transfer { lamports } => lamports,
+                    instruction => panic!("Unexpected instruction: {instruction:?}"),
+                };
+                continue;
+            }
+            match decode(&instruction.data) {
+                LoaderV4Instruction::Truncate { new_size } => {
+                    let new_len = 48 + new_size as usize;
+                    assert!(new_len - len <= MAX_PERMITTED_DATA_INCREASE);
+                    assert_eq!(lamports, rent.minimum_balance(new_len));
+                    len = new_len;
+                }
+                LoaderV4Instruction::Write { .. } => return len - 48,
+                instruction => panic!("Unexpected instruction: {instruction:?}"),
+            }
+        }
+    }
+    len - 48
+}
+
+#[test]
+fn deploy_plan_large_program() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = vec![1; 1_000_000];
+
+    let plan = plan_deploy(&elf, &program, &authority, &payer, &Rent::default()).unwrap();
+    for transaction in &plan.transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+    }
+
+    // Grown in more steps than fit in the first transaction.
+    assert_eq!(replay_create(&plan.transactions, &program), elf.len());
+    assert!(plan.transactions[0].len() > 2);
+    assert_eq!(plan.transactions[1][0].program_id, system_program::id());
+}
+
+#[test]
+fn max_write_len_fills_packet() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let len = max_write_len(&program, &authority, &payer).unwrap();
+    let plan = plan_deploy(
+        &vec![1; len + 1],
+        &program,
+        &authority,
+        &payer,
+        &Rent::default(),
+    )
+    .unwrap();
+    let writes = &plan.transactions[1..plan.transactions.len() - 1];
+    assert_eq!(writes.len(), 2);
+    assert_eq!(transaction_size(&writes[0], &payer), PACKET_DATA_SIZE);
+
+    // A payer that is also the authority saves a signature and a key.
+    assert_eq!(
+        max_write_len(&program, &authority, &authority).unwrap(),
+        len + 64 + 32
+    );
+}
+
+#[test]
+fn fail_empty_program() {
+    assert_eq!(
+        plan_deploy(
+            &[],
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Rent::default()
+        ),
+        Err(PlanError::EmptyProgram)
+    );
+}
+
+#[test]
+fn fail_program_too_large() {
+    assert_eq!(
+        plan_deploy(
+            &vec![0; 10 * 1024 * 1024],
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Rent::default()
+        ),
+        Err(PlanError::ProgramTooLarge)
+    );
+}
+
+fn live_program(authority: &Pubkey, slot: u64, len: usize) -> (LoaderV4ProgramAccount, u64) {
+    let account = LoaderV4ProgramAccount {
+        slot,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Deployed,
+        extensions: None,
+        program_data: vec![0; len],
+    };
+    (account, Rent::default().minimum_balance(48 + len))
+}
+
+#[test]
+fn upgrade_plan() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = vec![5; 3_000];
+    let (account, lamports) = live_program(&authority, 100, 2_000);
+    let rent = Rent::default();
+
+    let plan = plan_upgrade(
+        &elf, &program, &account, lamports, &buffer, &authority, &payer, &rent,
+    )
+    .unwrap();
+    assert_eq!(
+        DEPLOYMENT_COOLDOWN_IN_SLOTS,
+        solana_loader_v4_program::state::DEPLOYMENT_COOLDOWN_IN_SLOTS
+    );
+    assert_eq!(plan.earliest_slot, 100 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+    assert_eq!(plan.buffer_lamports, rent.minimum_balance(48 + elf.len()));
+    assert_eq!(plan.transfer_lamports, plan.buffer_lamports - lamports);
+
+    for transaction in &plan.transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+    }
+
+    // The buffer is created and written, the program is left untouched.
+    let (upgrade, staging) = plan.transactions.split_last().unwrap();
+    assert_eq!(staging[0][0].program_id, system_program::id());
+    assert_eq!(staging[0][1].accounts[0].pubkey, buffer);
+    for transaction in &staging[1..] {
+        assert_eq!(transaction[0].accounts[0].pubkey, buffer);
+        assert!(matches!(
+            decode(&transaction[0].data),
+            LoaderV4Instruction::Write { .. }
+        ));
+    }
+
+    // Retract, deploy from the buffer, then reclaim what is left in it.
+    let instructions = upgrade
+        .iter()
+        .map(|instruction| (instruction.accounts[0].pubkey, decode(&instruction.data)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        instructions,
+        [
+            (program, LoaderV4Instruction::Retract),
+            (
+                program,
+                LoaderV4Instruction::DeployWithHash {
+                    expected_hash: hash(&elf).to_bytes()
+                }
+            ),
+            (buffer, LoaderV4Instruction::Truncate { new_size: 1 }),
+            (buffer, LoaderV4Instruction::Truncate { new_size: 0 }),
+        ]
+    );
+    assert_eq!(upgrade[1].accounts[2].pubkey, buffer);
+}
+
+#[test]
+fn upgrade_plan_large_program() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = vec![5; 45_000];
+    let (account, lamports) = live_program(&authority, 100, 40_000);
+
+    let plan = plan_upgrade(
+        &elf,
+        &program,
+        &account,
+        lamports,
+        &buffer,
+        &authority,
+        &payer,
+        &Rent::default(),
+    )
+    .unwrap();
+    for transaction in &plan.transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+    }
+
+    assert_eq!(replay_create(&plan.transactions, &buffer), elf.len());
+}
+
+#[test]
+fn upgrade_plan_shrinks_program() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (account, lamports) = live_program(&authority, 100, 5_000);
+
+    let plan = plan_upgrade(
+        &[5; 3_000],
+        &program,
+        &account,
+        lamports,
+        &buffer,
+        &authority,
+        &payer,
+        &Rent::default(),
+    )
+    .unwrap();
+    assert_eq!(plan.transfer_lamports, 0);
+
+    let upgrade = plan.transactions.last().unwrap();
+    assert_eq!(
+        decode(&upgrade[1].data),
+        LoaderV4Instruction::Truncate { new_size: 3_000 }
+    );
+    assert_eq!(upgrade[1].accounts[0].pubkey, program);
+    assert!(!upgrade[1].accounts[0].is_signer);
+    assert_eq!(upgrade[1].accounts[2].pubkey, payer);
+    assert_eq!(
+        decode(&upgrade[2].data),
+        LoaderV4Instruction::DeployWithHash {
+            expected_hash: hash(&[5; 3_000]).to_bytes()
+        }
+    );
+}
+
+#[test]
+fn upgrade_plan_grows_program() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = vec![5; 500_000];
+    let (account, lamports) = live_program(&authority, 100, 2_000);
+    let rent = Rent::default();
+
+    let plan = plan_upgrade(
+        &elf, &program, &account, lamports, &buffer, &authority, &payer, &rent,
+    )
+    .unwrap();
+    // The payer funds the growth, `Deploy` moves nothing.
+    assert_eq!(plan.transfer_lamports, 0);
+
+    // Retracted, then grown in as many transactions as needed before the
+    // deployment.
+    let upgrade = plan
+        .transactions
+        .iter()
+        .skip_while(|transaction| {
+            !transaction.iter().any(|instruction| {
+                instruction.program_id == ID
+                    && decode(&instruction.data) == LoaderV4Instruction::Retract
+            })
+        })
+        .collect::<Vec<_>>();
+    assert!(upgrade.len() > 1);
+    let instructions = upgrade.iter().flat_map(|transaction| transaction.iter());
+    let mut program_lamports = lamports;
+    let mut len = 48 + 2_000;
+    let mut deployed = false;
+    for instruction in instructions {
+        if instruction.program_id == system_program::id() {
+            if instruction.accounts[1].pubkey == program {
+                program_lamports += match limited_deserialize(&instruction.data, 1_232).unwrap() {
+                    SystemInstruction::Transfer { lamports } => lamports,
+                    instruction => panic!("Unexpected instruction: {instruction:?}"),
+                };
+            }
+            continue;
+        }
+        match (instruction.accounts[0].pubkey, decode(&instruction.data)) {
+            (pubkey, LoaderV4Instruction::Truncate { new_size }) if pubkey == program => {
+                assert!(!deployed);
+                let new_len = 48 + new_size as usize;
+                assert!(new_len - len <= MAX_PERMITTED_DATA_INCREASE);
+                assert_eq!(program_lamports, rent.minimum_balance(new_len));
+                len = new_len;
+            }
+            (pubkey, LoaderV4Instruction::DeployWithHash { .. }) if pubkey == program => {
+                assert_eq!(len, 48 + elf.len());
+                deployed = true;
+            }
+            _ => {}
+        }
+    }
+    assert!(deployed);
+    for transaction in &plan.transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+    }
+}
+
+#[test]
+fn upgrade_plan_closes_buffer() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let elf = vec![5; 3_000];
+    let (account, _) = live_program(&authority, 100, 2_000);
+    let rent = Rent::default();
+
+    // `Deploy` takes almost everything from the buffer to fund a program
+    // which lacks lamports, leaving too few to reinitialize it.
+    let plan = plan_upgrade(
+        &elf, &program, &account, 1, &buffer, &authority, &payer, &rent,
+    )
+    .unwrap();
+    assert_eq!(plan.transfer_lamports, plan.buffer_lamports - 1);
+
+    let upgrade = plan.transactions.last().unwrap();
+    let close = &upgrade[upgrade.len() - 3..];
+    assert_eq!(close[0].program_id, system_program::id());
+    assert_eq!(close[0].accounts[1].pubkey, buffer);
+    assert_eq!(
+        limited_deserialize::<SystemInstruction>(&close[0].data, 1_232).unwrap(),
+        SystemInstruction::Transfer {
+            lamports: rent.minimum_balance(48 + 1) - 1
+        }
+    );
+    let truncates = close[1..]
+        .iter()
+        .map(|instruction| (instruction.accounts[0].pubkey, decode(&instruction.data)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        truncates,
+        [
+            (buffer, LoaderV4Instruction::Truncate { new_size: 1 }),
+            (buffer, LoaderV4Instruction::Truncate { new_size: 0 }),
+        ]
+    );
+}
+
+#[test]
+fn upgrade_plan_program_with_extensions() {
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (mut account, _) = live_program(&authority, 100, 2_000);
+    account.extensions = Some(vec![LoaderV4Extension {
+        extension_type: PENDING_AUTHORITY_EXTENSION,
+        value: Pubkey::new_unique().to_bytes().to_vec(),
+    }]);
+    let overhead = 48 + 4 + 32 + 4 + 8;
+    let lamports = Rent::default().minimum_balance(overhead + 2_000);
+    let rent = Rent::default();
+
+    let plan = plan_upgrade(
+        &[5; 3_000],
+        &program,
+        &account,
+        lamports,
+        &buffer,
+        &authority,
+        &payer,
+        &rent,
+    )
+    .unwrap();
+
+    // The buffer has no extensions, the program keeps its own.
+    assert_eq!(plan.buffer_lamports, rent.minimum_balance(48 + 3_000));
+    assert_eq!(
+        plan.transfer_lamports,
+        rent.minimum_balance(overhead + 3_000) - lamports
+    );
+}
+
+#[test]
+fn upgrade_plan_retracted_program() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let (mut account, lamports) = live_program(&authority, 0, 2_000);
+    account.status = LoaderV4Status::Retracted;
+
+    let plan = plan_upgrade(
+        &[5; 2_000],
+        &program,
+        &account,
+        lamports,
+        &Pubkey::new_unique(),
+        &authority,
+        &Pubkey::new_unique(),
+        &Rent::default(),
+    )
+    .unwrap();
+    assert_eq!(plan.earliest_slot, 0);
+    let upgrade = plan.transactions.last().unwrap();
+    assert_eq!(
+        decode(&upgrade[0].data),
+        LoaderV4Instruction::DeployWithHash {
+            expected_hash: hash(&[5; 2_000]).to_bytes()
+        }
+    );
+}
+
+#[test]
+fn fail_upgrade() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let rent = Rent::default();
+    let plan = |account: &LoaderV4ProgramAccount, authority: &Pubkey, len: usize| {
+        plan_upgrade(
+            &vec![5; len],
+            &program,
+            account,
+            0,
+            &Pubkey::new_unique(),
+            authority,
+            &Pubkey::new_unique(),
+            &rent,
+        )
+    };
+
+    let (account, _) = live_program(&authority, 0, 2_000);
+    assert_eq!(
+        plan(&account, &Pubkey::new_unique(), 2_000),
+        Err(PlanError::IncorrectAuthority)
+    );
+
+    let mut finalized = account.clone();
+    finalized.status = LoaderV4Status::Finalized;
+    assert_eq!(
+        plan(&finalized, &authority, 2_000),
+        Err(PlanError::ProgramFinalized)
+    );
+}
+
+#[test]
+fn migrate_plan() {
+    let v3_program = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    // Larger than `Migrate` could grow the program account by itself.
+    let instructions = plan_migrate(
+        &v3_program,
+        45 + 100_000,
+        &program,
+        &authority,
+        &payer,
+        true,
+        &Rent::default(),
+    )
+    .unwrap();
+    assert!(transaction_size(&instructions, &payer) <= PACKET_DATA_SIZE);
+
+    let [create, migrate] = &instructions[..] else {
+        panic!("Expected two instructions");
+    };
+    assert_eq!(create.program_id, system_program::id());
+    assert_eq!(create.accounts[1].pubkey, program);
+    assert_eq!(
+        create.data[4..],
+        [
+            &Rent::default().minimum_balance(48 + 100_000).to_le_bytes()[..],
+            &(48u64 + 100_000).to_le_bytes(),
+            ID.as_ref(),
+        ]
+        .concat()
+    );
+
+    assert_eq!(migrate.program_id, ID);
+    assert_eq!(
+        decode(&migrate.data),
+        LoaderV4Instruction::Migrate { deploy: true }
+    );
+    assert_eq!(
+        migrate.accounts[3].pubkey,
+        bpf_loader_upgradeable::get_program_data_address(&v3_program)
+    );
+}
+
+#[test]
+fn fail_migrate_empty_program() {
+    assert_eq!(
+        plan_migrate(
+            &Pubkey::new_unique(),
+            45,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            false,
+            &Rent::default(),
+        ),
+        Err(PlanError::EmptyProgram)
+    );
+}
+
+#[test]
+fn diff_merges_close_changes() {
+    let old = vec![0; 100];
+    let mut new = old.clone();
+    new[10] = 1;
+    new[20] = 2; // Within a segment header of the previous change.
+    new[60] = 3;
+    new[61] = 4;
+
+    let segments = diff(&old, &new)
+        .into_iter()
+        .map(|segment| (segment.offset, segment.bytes.0))
+        .collect::<Vec<_>>();
+    assert_eq!(segments, vec![(10, new[10..21].to_vec()), (60, vec![3, 4])]);
+
+    // Bytes past the end of the old program are zeros.
+    let segments = diff(&[1, 2], &[1, 2, 0, 5]);
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].offset, 3);
+    assert_eq!(segments[0].bytes.0, vec![5]);
+
+    assert!(diff(&old, &old).is_empty());
+}
+
+#[test]
+fn write_batches_plan() {
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let old = (0..10_000).map(|i| i as u8).collect::<Vec<_>>();
+
+    // Many small patches and one that spans several packets.
+    let mut new = old.clone();
+    for i in (0..5_000).step_by(50) {
+        new[i] = !new[i];
+    }
+    for byte in &mut new[6_000..9_000] {
+        *byte = !*byte;
+    }
+
+    let transactions = plan_write_batches(&old, &new, &program, &authority, &payer).unwrap();
+    assert!(transactions.len() < 10);
+
+    let mut patched = old.clone();
+    for transaction in &transactions {
+        assert!(transaction_size(transaction, &payer) <= PACKET_DATA_SIZE);
+        assert_eq!(transaction.len(), 1);
+        assert_eq!(transaction[0].program_id, ID);
+        match decode(&transaction[0].data) {
+            LoaderV4Instruction::WriteBatch { segments } => {
+                for segment in segments {
+                    let offset = segment.offset as usize;
+                    patched[offset..offset + segment.bytes.len()].copy_from_slice(&segment.bytes);
+                }
+            }
+            instruction => panic!("Unexpected instruction: {instruction:?}"),
+        }
+    }
+    assert_eq!(patched, new);
+
+    assert!(plan_write_batches(&old, &old, &program, &authority, &payer)
+        .unwrap()
+        .is_empty());
+}
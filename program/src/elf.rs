@@ -0,0 +1,403 @@
+//! ELF verification for program data.
+//!
+//! [CORE BPF]: This is not a replacement for the runtime's loader, which
+//! still performs the full verification when the program is loaded into the
+//! program cache. It only makes sure that Deploy refuses data which could
+//! never be loaded, so garbage is never marked executable.
+
+use thiserror::Error;
+
+const ELF_MAGIC: [u8; 4] = *b"\x7fELF";
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+const EV_CURRENT: u8 = 1;
+const ELFOSABI_NONE: u8 = 0;
+const ET_DYN: u16 = 3;
+const EM_BPF: u16 = 247;
+const EM_SBPF: u16 = 263;
+const EF_SBPF_V2: u32 = 0x20;
+
+const EHDR_SIZE: usize = 64;
+const PHDR_SIZE: usize = 56;
+const SHDR_SIZE: usize = 64;
+const REL_SIZE: usize = 16;
+const SYM_SIZE: usize = 24;
+const INSN_SIZE: u64 = 8;
+
+const PT_LOAD: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_REL: u32 = 9;
+const SHT_NOBITS: u32 = 8;
+const SHT_DYNSYM: u32 = 11;
+const SHF_EXECINSTR: u64 = 0x4;
+
+const R_BPF_NONE: u32 = 0;
+const R_BPF_64_64: u32 = 1;
+const R_BPF_64_RELATIVE: u32 = 8;
+const R_BPF_64_32: u32 = 10;
+
+/// Reasons for which program data is rejected as an ELF.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ElfError {
+    /// The data does not start with the ELF magic.
+    #[error("Program data is not an ELF")]
+    InvalidMagic,
+    /// The identification bytes are not those of a 64-bit little-endian ELF.
+    #[error("ELF is not a 64-bit little-endian object")]
+    InvalidIdent,
+    /// The header is truncated or its sizes are inconsistent.
+    #[error("ELF header is malformed")]
+    InvalidHeader,
+    /// The ELF is not a shared object.
+    #[error("ELF is not a shared object")]
+    InvalidType,
+    /// The machine type is neither BPF nor SBPF.
+    #[error("ELF machine type is not BPF")]
+    InvalidMachine,
+    /// The flags do not designate a supported SBPF version.
+    #[error("ELF has an unsupported SBPF version")]
+    UnsupportedSbpfVersion,
+    /// The program header table or a segment lies outside of the data.
+    #[error("ELF program header is out of bounds")]
+    ProgramHeaderOutOfBounds,
+    /// The section header table or a section lies outside of the data.
+    #[error("ELF section header is out of bounds")]
+    SectionHeaderOutOfBounds,
+    /// The entrypoint is not an aligned address in an executable section.
+    #[error("ELF entrypoint is invalid")]
+    InvalidEntrypoint,
+    /// A relocation section is malformed or references a missing symbol.
+    #[error("ELF relocation table is malformed")]
+    InvalidRelocationTable,
+    /// A relocation has an unknown type or patches outside of the data.
+    #[error("ELF relocation is invalid")]
+    InvalidRelocation,
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    read(data, offset).map(u16::from_le_bytes)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    read(data, offset).map(u32::from_le_bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    read(data, offset).map(u64::from_le_bytes)
+}
+
+/// Returns the `(offset, size)` range if it lies within `len` bytes.
+fn checked_range(offset: u64, size: u64, len: usize) -> Option<(usize, usize)> {
+    let offset = usize::try_from(offset).ok()?;
+    let size = usize::try_from(size).ok()?;
+    (offset.checked_add(size)? <= len).then_some((offset, size))
+}
+
+/// Returns the byte range of a table of `count` entries of `entry_size`.
+fn table(data: &[u8], offset: u64, count: u16, entry_size: usize) -> Option<&[u8]> {
+    let size = (count as usize).checked_mul(entry_size)?;
+    let (offset, size) = checked_range(offset, size as u64, data.len())?;
+    data.get(offset..offset.checked_add(size)?)
+}
+
+struct SectionHeader {
+    sh_type: u32,
+    sh_flags: u64,
+    sh_addr: u64,
+    sh_offset: u64,
+    sh_size: u64,
+    sh_link: u32,
+    sh_entsize: u64,
+}
+
+impl SectionHeader {
+    fn parse(entry: &[u8]) -> Option<Self> {
+        Some(Self {
+            sh_type: read_u32(entry, 4)?,
+            sh_flags: read_u64(entry, 8)?,
+            sh_addr: read_u64(entry, 16)?,
+            sh_offset: read_u64(entry, 24)?,
+            sh_size: read_u64(entry, 32)?,
+            sh_link: read_u32(entry, 40)?,
+            sh_entsize: read_u64(entry, 56)?,
+        })
+    }
+}
+
+/// Verifies that `data` is a well-formed SBPF ELF.
+pub fn verify(data: &[u8]) -> Result<(), ElfError> {
+    if data.get(0..4) != Some(&ELF_MAGIC[..]) {
+        return Err(ElfError::InvalidMagic);
+    }
+    if data.len() < EHDR_SIZE {
+        return Err(ElfError::InvalidHeader);
+    }
+    if data[4] != ELFCLASS64
+        || data[5] != ELFDATA2LSB
+        || data[6] != EV_CURRENT
+        || data[7] != ELFOSABI_NONE
+    {
+        return Err(ElfError::InvalidIdent);
+    }
+
+    // The header length was checked above, so these reads can't fail.
+    let header_u16 = |offset| read_u16(data, offset).ok_or(ElfError::InvalidHeader);
+    let header_u32 = |offset| read_u32(data, offset).ok_or(ElfError::InvalidHeader);
+    let header_u64 = |offset| read_u64(data, offset).ok_or(ElfError::InvalidHeader);
+
+    if header_u16(16)? != ET_DYN {
+        return Err(ElfError::InvalidType);
+    }
+    if !matches!(header_u16(18)?, EM_BPF | EM_SBPF) {
+        return Err(ElfError::InvalidMachine);
+    }
+    if header_u32(20)? != EV_CURRENT as u32 {
+        return Err(ElfError::InvalidHeader);
+    }
+    let e_entry = header_u64(24)?;
+    let e_phoff = header_u64(32)?;
+    let e_shoff = header_u64(40)?;
+    if !matches!(header_u32(48)?, 0 | EF_SBPF_V2) {
+        return Err(ElfError::UnsupportedSbpfVersion);
+    }
+    let e_phnum = header_u16(56)?;
+    let e_shnum = header_u16(60)?;
+    let e_shstrndx = header_u16(62)?;
+    if header_u16(52)? as usize != EHDR_SIZE
+        || (e_phnum > 0 && header_u16(54)? as usize != PHDR_SIZE)
+        || header_u16(58)? as usize != SHDR_SIZE
+        || e_shstrndx >= e_shnum
+    {
+        return Err(ElfError::InvalidHeader);
+    }
+
+    let program_headers =
+        table(data, e_phoff, e_phnum, PHDR_SIZE).ok_or(ElfError::ProgramHeaderOutOfBounds)?;
+    for entry in program_headers.chunks_exact(PHDR_SIZE) {
+        let p_type = read_u32(entry, 0).ok_or(ElfError::ProgramHeaderOutOfBounds)?;
+        let p_offset = read_u64(entry, 8).ok_or(ElfError::ProgramHeaderOutOfBounds)?;
+        let p_filesz = read_u64(entry, 32).ok_or(ElfError::ProgramHeaderOutOfBounds)?;
+        let p_memsz = read_u64(entry, 40).ok_or(ElfError::ProgramHeaderOutOfBounds)?;
+        if p_type == PT_LOAD
+            && (p_filesz > p_memsz || checked_range(p_offset, p_filesz, data.len()).is_none())
+        {
+            return Err(ElfError::ProgramHeaderOutOfBounds);
+        }
+    }
+
+    let section_headers = table(data, e_shoff, e_shnum, SHDR_SIZE)
+        .ok_or(ElfError::SectionHeaderOutOfBounds)?
+        .chunks_exact(SHDR_SIZE)
+        .map(SectionHeader::parse)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(ElfError::SectionHeaderOutOfBounds)?;
+    for section in section_headers.iter() {
+        if section.sh_type != SHT_NOBITS
+            && checked_range(section.sh_offset, section.sh_size, data.len()).is_none()
+        {
+            return Err(ElfError::SectionHeaderOutOfBounds);
+        }
+    }
+
+    let entrypoint_is_valid = section_headers.iter().any(|section| {
+        section.sh_flags & SHF_EXECINSTR != 0
+            && e_entry >= section.sh_addr
+            && e_entry - section.sh_addr < section.sh_size
+            && (e_entry - section.sh_addr).checked_rem(INSN_SIZE) == Some(0)
+    });
+    if !entrypoint_is_valid {
+        return Err(ElfError::InvalidEntrypoint);
+    }
+
+    for section in section_headers.iter().filter(|s| s.sh_type == SHT_REL) {
+        verify_relocations(data, &section_headers, section)?;
+    }
+
+    Ok(())
+}
+
+fn verify_relocations(
+    data: &[u8],
+    section_headers: &[SectionHeader],
+    section: &SectionHeader,
+) -> Result<(), ElfError> {
+    if section.sh_entsize as usize != REL_SIZE
+        || section.sh_size.checked_rem(REL_SIZE as u64) != Some(0)
+    {
+        return Err(ElfError::InvalidRelocationTable);
+    }
+    let symbol_count = match section.sh_link {
+        0 => 0,
+        link => {
+            let symbols = section_headers
+                .get(link as usize)
+                .filter(|s| matches!(s.sh_type, SHT_SYMTAB | SHT_DYNSYM))
+                .filter(|s| s.sh_entsize as usize == SYM_SIZE)
+                .ok_or(ElfError::InvalidRelocationTable)?;
+            symbols.sh_size / SYM_SIZE as u64
+        }
+    };
+
+    // Section bounds were checked by the caller.
+    let (offset, size) = checked_range(section.sh_offset, section.sh_size, data.len())
+        .ok_or(ElfError::SectionHeaderOutOfBounds)?;
+    for entry in data[offset..offset + size].chunks_exact(REL_SIZE) {
+        let r_offset = read_u64(entry, 0).ok_or(ElfError::InvalidRelocationTable)?;
+        let r_info = read_u64(entry, 8).ok_or(ElfError::InvalidRelocationTable)?;
+        let r_type = (r_info & 0xffff_ffff) as u32;
+        let r_sym = r_info >> 32;
+        let patch_size = match r_type {
+            R_BPF_NONE => 0,
+            R_BPF_64_64 => 2 * INSN_SIZE,
+            R_BPF_64_RELATIVE | R_BPF_64_32 => INSN_SIZE,
+            _ => return Err(ElfError::InvalidRelocation),
+        };
+        if checked_range(r_offset, patch_size, data.len()).is_none() {
+            return Err(ElfError::InvalidRelocation);
+        }
+        if r_sym != 0 && r_sym >= symbol_count {
+            return Err(ElfError::InvalidRelocationTable);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOOP: &[u8] = include_bytes!("../tests/fixtures/noop.elf");
+
+    // Offsets into the fixture, see `tests/fixtures/noop.elf`.
+    const TEXT_SHDR: usize = 192 + SHDR_SIZE;
+    const REL_SHDR: usize = 192 + 2 * SHDR_SIZE;
+    const REL: usize = 144;
+
+    fn patch(offset: usize, bytes: &[u8]) -> Vec<u8> {
+        let mut elf = NOOP.to_vec();
+        elf[offset..offset + bytes.len()].copy_from_slice(bytes);
+        elf
+    }
+
+    #[test]
+    fn test_verify_fixture() {
+        assert_eq!(verify(NOOP), Ok(()));
+
+        // Trailing bytes after the ELF are ignored.
+        let mut elf = NOOP.to_vec();
+        elf.extend_from_slice(&[4; 1_024]);
+        assert_eq!(verify(&elf), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_garbage() {
+        assert_eq!(verify(&[]), Err(ElfError::InvalidMagic));
+        assert_eq!(verify(&[4; 1_500]), Err(ElfError::InvalidMagic));
+        assert_eq!(verify(&NOOP[..32]), Err(ElfError::InvalidHeader));
+    }
+
+    #[test]
+    fn test_verify_header() {
+        assert_eq!(verify(&patch(4, &[1])), Err(ElfError::InvalidIdent));
+        assert_eq!(verify(&patch(5, &[2])), Err(ElfError::InvalidIdent));
+        assert_eq!(verify(&patch(7, &[3])), Err(ElfError::InvalidIdent));
+        assert_eq!(
+            verify(&patch(16, &2u16.to_le_bytes())),
+            Err(ElfError::InvalidType)
+        );
+        assert_eq!(
+            verify(&patch(18, &62u16.to_le_bytes())),
+            Err(ElfError::InvalidMachine)
+        );
+        assert_eq!(verify(&patch(18, &EM_SBPF.to_le_bytes())), Ok(()));
+        assert_eq!(
+            verify(&patch(48, &1u32.to_le_bytes())),
+            Err(ElfError::UnsupportedSbpfVersion)
+        );
+        assert_eq!(verify(&patch(48, &EF_SBPF_V2.to_le_bytes())), Ok(()));
+        assert_eq!(
+            verify(&patch(58, &40u16.to_le_bytes())),
+            Err(ElfError::InvalidHeader)
+        );
+        assert_eq!(
+            verify(&patch(62, &4u16.to_le_bytes())),
+            Err(ElfError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn test_verify_bounds() {
+        // Program header table past the end.
+        assert_eq!(
+            verify(&patch(32, &440u64.to_le_bytes())),
+            Err(ElfError::ProgramHeaderOutOfBounds)
+        );
+        // Segment file size past the end.
+        assert_eq!(
+            verify(&patch(64 + 32, &u64::MAX.to_le_bytes())),
+            Err(ElfError::ProgramHeaderOutOfBounds)
+        );
+        // Section header table past the end.
+        assert_eq!(
+            verify(&patch(60, &5u16.to_le_bytes())),
+            Err(ElfError::SectionHeaderOutOfBounds)
+        );
+        // Section size past the end.
+        assert_eq!(
+            verify(&patch(TEXT_SHDR + 32, &4_096u64.to_le_bytes())),
+            Err(ElfError::SectionHeaderOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_verify_entrypoint() {
+        // Misaligned.
+        assert_eq!(
+            verify(&patch(24, &121u64.to_le_bytes())),
+            Err(ElfError::InvalidEntrypoint)
+        );
+        // Past the end of `.text`.
+        assert_eq!(
+            verify(&patch(24, &144u64.to_le_bytes())),
+            Err(ElfError::InvalidEntrypoint)
+        );
+        // `.text` is not executable.
+        assert_eq!(
+            verify(&patch(TEXT_SHDR + 8, &2u64.to_le_bytes())),
+            Err(ElfError::InvalidEntrypoint)
+        );
+    }
+
+    #[test]
+    fn test_verify_relocations() {
+        // Entry size.
+        assert_eq!(
+            verify(&patch(REL_SHDR + 56, &8u64.to_le_bytes())),
+            Err(ElfError::InvalidRelocationTable)
+        );
+        // Unknown relocation type.
+        assert_eq!(
+            verify(&patch(REL + 8, &3u64.to_le_bytes())),
+            Err(ElfError::InvalidRelocation)
+        );
+        // Relocation patches past the end.
+        assert_eq!(
+            verify(&patch(REL, &444u64.to_le_bytes())),
+            Err(ElfError::InvalidRelocation)
+        );
+        // Symbol without a symbol table.
+        assert_eq!(
+            verify(&patch(REL + 8, &((1u64 << 32) | 10).to_le_bytes())),
+            Err(ElfError::InvalidRelocationTable)
+        );
+        // Linked section is not a symbol table.
+        assert_eq!(
+            verify(&patch(REL_SHDR + 40, &1u32.to_le_bytes())),
+            Err(ElfError::InvalidRelocationTable)
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Program error types.
+
+use {num_derive::FromPrimitive, solana_program::program_error::ProgramError, thiserror::Error};
+
+/// Errors that may be returned by the Solana BPF Loader v4 program.
+///
+/// The discriminants are surfaced as `ProgramError::Custom` codes, so they
+/// must never be reordered or reused.
+#[derive(Clone, Copy, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum LoaderV4Error {
+    /// The program account is not writable.
+    #[error("Program is not writeable")]
+    ProgramNotWritable = 0,
+    /// The program account must be retracted for this operation.
+    #[error("Program is not retracted")]
+    ProgramNotRetracted = 1,
+    /// The program account must be deployed for this operation.
+    #[error("Program is not deployed")]
+    ProgramNotDeployed = 2,
+    /// The program was deployed too recently to be redeployed or retracted.
+    #[error("Program was deployed recently, cooldown still in effect")]
+    DeploymentCooldown = 3,
+    /// The source program account of a deployment is not retracted.
+    #[error("Source program is not retracted")]
+    SourceProgramNotRetracted = 4,
+    /// The destination account for reclaimed lamports is not writable.
+    #[error("Recipient is not writeable")]
+    DestinationNotWritable = 5,
+    /// The new authority is the same as the current one.
+    #[error("No change")]
+    AuthorityUnchanged = 6,
+    /// The program data is not a valid ELF.
+    #[error("Program data is not a valid ELF")]
+    InvalidElf = 7,
+    /// The program account state is corrupt.
+    #[error("Program account state is corrupt")]
+    InvalidProgramState = 8,
+    /// The program data does not match the expected hash.
+    #[error("Program data does not match the expected hash")]
+    ProgramDataHashMismatch = 9,
+    /// The program account has no pending authority.
+    #[error("No pending authority")]
+    NoPendingAuthority = 10,
+    /// The scheduled deployment can not be executed yet.
+    #[error("Scheduled deployment is not due yet")]
+    ScheduledDeployNotDue = 11,
+    /// The program account has no scheduled deployment.
+    #[error("No scheduled deployment")]
+    NoScheduledDeploy = 12,
+    /// The source program of a deployment is the program itself.
+    #[error("Source program must differ from the program")]
+    SourceIsProgram = 13,
+    /// The source program is not the one of the scheduled deployment.
+    #[error("Incorrect source program provided")]
+    IncorrectSourceProgram = 14,
+    /// The scheduled deployment is not after the current slot.
+    #[error("Scheduled deployment must be in a future slot")]
+    ScheduledDeployNotInFuture = 15,
+    /// The program has a scheduled deployment, which only
+    /// `ExecuteScheduledDeploy` can perform.
+    #[error("Program has a scheduled deployment")]
+    DeployScheduled = 16,
+    /// The destination of a closed program is the program itself.
+    #[error("Destination must differ from the program")]
+    DestinationIsProgram = 17,
+    /// A created program account would grow by more than a cross-program
+    /// invocation allows.
+    #[error("Program account is too large to create")]
+    CreateSizeTooLarge = 18,
+    /// The threshold or the signers of a multisig are invalid.
+    #[error("Invalid multisig")]
+    InvalidMultisig = 19,
+    /// The program account has no multisig.
+    #[error("No multisig")]
+    NoMultisig = 20,
+    /// The proposed deployment lacks approvals of the authority.
+    #[error("Proposal is not approved")]
+    ProposalNotApproved = 21,
+    /// The proposed deployment was already approved by this member.
+    #[error("Already approved")]
+    AlreadyApproved = 22,
+    /// The proposal account is not writable.
+    #[error("Proposal is not writeable")]
+    ProposalNotWritable = 23,
+    /// The proposal account is for another program.
+    #[error("Proposal is for another program")]
+    IncorrectProposal = 24,
+    /// The payer is not the one who funded the proposal account.
+    #[error("Incorrect payer provided")]
+    IncorrectPayer = 25,
+    /// The program account has no writer.
+    #[error("No writer")]
+    NoWriter = 26,
+    /// The writer is the program or its authority.
+    #[error("Writer must differ from the program and its authority")]
+    InvalidWriter = 27,
+}
+
+impl From<LoaderV4Error> for ProgramError {
+    fn from(e: LoaderV4Error) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -0,0 +1,257 @@
+//! Program events.
+//!
+//! Every successful instruction changing a program account logs a
+//! `LoaderV4Event` with `sol_log_data`, so that indexers can follow the
+//! lifecycle of programs without parsing log messages.
+
+use {
+    crate::{error::LoaderV4Error, state::LoaderV4State},
+    solana_program::{log::sol_log_data, program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Version of the event layout, stored in the first byte of every event.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Instruction which emitted an event. The values match the discriminants of
+/// the corresponding `LoaderV4Instruction` variants.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LoaderV4EventKind {
+    Write = 0,
+    Truncate = 1,
+    Deploy = 2,
+    Retract = 3,
+    TransferAuthority = 4,
+    Finalize = 5,
+    Migrate = 6,
+    Copy = 7,
+    WriteBatch = 8,
+    DeployWithHash = 9,
+    ProposeAuthority = 11,
+    AcceptAuthority = 12,
+    CancelAuthorityProposal = 13,
+    ScheduleDeploy = 14,
+    ExecuteScheduledDeploy = 15,
+    CancelScheduledDeploy = 16,
+    Close = 17,
+    Create = 18,
+    CreateWithSeed = 19,
+    SetMultisig = 20,
+    RemoveMultisig = 21,
+    ProposeDeploy = 22,
+    Approve = 23,
+    ExecuteProposal = 24,
+    CancelProposal = 25,
+    SetWriter = 26,
+    RevokeWriter = 27,
+}
+
+impl TryFrom<u8> for LoaderV4EventKind {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Write),
+            1 => Ok(Self::Truncate),
+            2 => Ok(Self::Deploy),
+            3 => Ok(Self::Retract),
+            4 => Ok(Self::TransferAuthority),
+            5 => Ok(Self::Finalize),
+            6 => Ok(Self::Migrate),
+            7 => Ok(Self::Copy),
+            8 => Ok(Self::WriteBatch),
+            9 => Ok(Self::DeployWithHash),
+            11 => Ok(Self::ProposeAuthority),
+            12 => Ok(Self::AcceptAuthority),
+            13 => Ok(Self::CancelAuthorityProposal),
+            14 => Ok(Self::ScheduleDeploy),
+            15 => Ok(Self::ExecuteScheduledDeploy),
+            16 => Ok(Self::CancelScheduledDeploy),
+            17 => Ok(Self::Close),
+            18 => Ok(Self::Create),
+            19 => Ok(Self::CreateWithSeed),
+            20 => Ok(Self::SetMultisig),
+            21 => Ok(Self::RemoveMultisig),
+            22 => Ok(Self::ProposeDeploy),
+            23 => Ok(Self::Approve),
+            24 => Ok(Self::ExecuteProposal),
+            25 => Ok(Self::CancelProposal),
+            26 => Ok(Self::SetWriter),
+            27 => Ok(Self::RevokeWriter),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// State transition of a program account.
+///
+/// Events are packed as:
+///
+/// - `u8` version, see `EVENT_VERSION`.
+/// - `u8` kind.
+/// - 32 bytes program address.
+/// - Old state: `u8` presence flag followed by the 48 bytes packed state,
+///   zeroed if absent.
+/// - New state: same as the old state.
+/// - `u64` program data length after the instruction.
+/// - `u64` lamports moved by the instruction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LoaderV4Event {
+    /// Instruction which emitted the event.
+    pub kind: LoaderV4EventKind,
+    /// Address of the program account.
+    pub program_address: Pubkey,
+    /// State before the instruction, `None` if the account was not
+    /// initialized.
+    pub old_state: Option<LoaderV4State>,
+    /// State after the instruction, `None` if the account was closed.
+    pub new_state: Option<LoaderV4State>,
+    /// Length of the program data, after the state header, after the
+    /// instruction.
+    pub data_len: u64,
+    /// Lamports moved out of the program account by `Truncate` and `Close`,
+    /// into it by `Deploy` and `ExecuteScheduledDeploy` from a source program,
+    /// or funded by `Create` and `CreateWithSeed`.
+    pub lamports: u64,
+}
+
+impl LoaderV4Event {
+    /// Size of a packed event.
+    pub const LEN: usize = 2 + 32 + 2 * (1 + LoaderV4State::program_data_offset()) + 8 + 8;
+
+    /// Packs the event into a byte buffer.
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0; Self::LEN];
+        data[0] = EVENT_VERSION;
+        data[1] = self.kind as u8;
+        data[2..34].copy_from_slice(self.program_address.as_ref());
+        let (old_state, new_state) = data[34..132].split_at_mut(49);
+        for (state, data) in [(self.old_state, old_state), (self.new_state, new_state)] {
+            if let Some(state) = state {
+                data[0] = 1;
+                // The slice is exactly as long as the state.
+                state.pack(&mut data[1..]).unwrap();
+            }
+        }
+        data[132..140].copy_from_slice(&self.data_len.to_le_bytes());
+        data[140..148].copy_from_slice(&self.lamports.to_le_bytes());
+        data
+    }
+
+    /// Unpacks a byte buffer into a LoaderV4Event.
+    ///
+    /// Fails with `InvalidAccountData` if the buffer is not an event of the
+    /// current version.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN || data[0] != EVENT_VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let unpack_state = |data: &[u8]| match data[0] {
+            0 => Ok(None),
+            1 => LoaderV4State::unpack(&data[1..]).map(Some),
+            _ => Err(ProgramError::from(LoaderV4Error::InvalidProgramState)),
+        };
+        Ok(Self {
+            kind: LoaderV4EventKind::try_from(data[1])?,
+            program_address: Pubkey::new_from_array(data[2..34].try_into().unwrap()),
+            old_state: unpack_state(&data[34..83])?,
+            new_state: unpack_state(&data[83..132])?,
+            data_len: u64::from_le_bytes(data[132..140].try_into().unwrap()),
+            lamports: u64::from_le_bytes(data[140..148].try_into().unwrap()),
+        })
+    }
+
+    /// Logs the packed event as program data.
+    pub fn emit(&self) {
+        sol_log_data(&[&self.pack()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::LoaderV4Status};
+
+    #[test]
+    fn pack_unpack() {
+        let event = LoaderV4Event {
+            kind: LoaderV4EventKind::Deploy,
+            program_address: Pubkey::new_unique(),
+            old_state: Some(LoaderV4State {
+                slot: 3,
+                authority_address_or_next_version: Pubkey::new_unique(),
+                status: LoaderV4Status::Retracted,
+            }),
+            new_state: Some(LoaderV4State {
+                slot: 800,
+                authority_address_or_next_version: Pubkey::new_unique(),
+                status: LoaderV4Status::Deployed,
+            }),
+            data_len: 1_500,
+            lamports: 42,
+        };
+        let data = event.pack();
+        assert_eq!(data[0], EVENT_VERSION);
+        assert_eq!(data[1], 2);
+        assert_eq!(LoaderV4Event::unpack(&data), Ok(event));
+
+        let event = LoaderV4Event {
+            old_state: None,
+            new_state: None,
+            ..event
+        };
+        let data = event.pack();
+        assert_eq!(&data[34..132], &[0; 98]);
+        assert_eq!(LoaderV4Event::unpack(&data), Ok(event));
+
+        let event = LoaderV4Event {
+            kind: LoaderV4EventKind::DeployWithHash,
+            ..event
+        };
+        let data = event.pack();
+        assert_eq!(data[1], 9);
+        assert_eq!(LoaderV4Event::unpack(&data), Ok(event));
+    }
+
+    #[test]
+    fn unpack_invalid() {
+        let data = LoaderV4Event {
+            kind: LoaderV4EventKind::Write,
+            program_address: Pubkey::new_unique(),
+            old_state: None,
+            new_state: None,
+            data_len: 0,
+            lamports: 0,
+        }
+        .pack();
+
+        // Truncated.
+        assert_eq!(
+            LoaderV4Event::unpack(&data[..LoaderV4Event::LEN - 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Unknown version.
+        let mut unknown_version = data;
+        unknown_version[0] = EVENT_VERSION + 1;
+        assert_eq!(
+            LoaderV4Event::unpack(&unknown_version),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Unknown kind.
+        let mut unknown_kind = data;
+        unknown_kind[1] = u8::MAX;
+        assert_eq!(
+            LoaderV4Event::unpack(&unknown_kind),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Invalid presence flag.
+        let mut invalid_flag = data;
+        invalid_flag[34] = 2;
+        assert_eq!(
+            LoaderV4Event::unpack(&invalid_flag),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+    }
+}
@@ -0,0 +1,512 @@
+//! Program account layouts and extensions.
+//!
+//! Program accounts of the legacy layout have their program data right after
+//! the `LoaderV4State` header, up to the end of the account. Accounts of the
+//! extended layout have an extension trailer after the program data:
+//!
+//! - Entries, each a `u16` extension type, a `u16` length and the value.
+//! - `u32` length of the entries.
+//! - `EXTENSIONS_MAGIC`.
+//!
+//! The header is the same in both layouts, so the runtime and readers unaware
+//! of extensions read the state as before, and see the trailer as padding
+//! after the ELF. An account keeps the (possibly empty) trailer once it has
+//! had an extension, so that program data written in the meantime can never
+//! be mistaken for extensions. Entries of unknown types are preserved.
+//!
+//! The extensions live in a trailer rather than in an area between the
+//! header and the program data. The runtime reads the ELF at the fixed
+//! `LoaderV4State::program_data_offset()`, which an area there would shift,
+//! and it ignores the bytes after the ELF. Since the layout is detected from
+//! the end of the account, program data written to an account of the legacy
+//! layout must never end with `EXTENSIONS_MAGIC`.
+
+use {
+    crate::{error::LoaderV4Error, state::LoaderV4State},
+    solana_program::{
+        account_info::AccountInfo, clock::Slot, program_error::ProgramError, pubkey::Pubkey,
+    },
+    std::ops::Range,
+};
+
+/// Marks the end of the extension trailer of accounts of the extended layout.
+pub const EXTENSIONS_MAGIC: [u8; 8] = *b"LV4EXT\x00\x01";
+
+/// Size of the length and magic closing the extension trailer.
+const TRAILER_FOOTER_LEN: usize = 4 + EXTENSIONS_MAGIC.len();
+
+/// Size of the type and length preceding the value of an extension.
+const ENTRY_HEADER_LEN: usize = 4;
+
+/// Layout versions of program accounts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LayoutVersion {
+    /// Program data up to the end of the account.
+    Legacy,
+    /// Extension trailer after the program data.
+    Extended,
+}
+
+/// Types of extensions.
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExtensionType {
+    /// Authority proposed by `ProposeAuthority`, until it is accepted or the
+    /// proposal is cancelled.
+    PendingAuthority = 1,
+    /// Deployment scheduled by `ScheduleDeploy`, until it is executed or
+    /// cancelled.
+    ScheduledDeploy = 2,
+    /// Signers set by `SetMultisig`, required instead of the authority's
+    /// signature until the multisig is removed or the authority changes.
+    Multisig = 3,
+    /// Address set by `SetWriter`, which can sign instead of the authority
+    /// to modify the program data of a retracted program, until it is
+    /// revoked or the authority changes.
+    Writer = 4,
+}
+
+/// Value of the `ScheduledDeploy` extension.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ScheduledDeploy {
+    /// Program account to deploy the program data of.
+    pub source_address: Pubkey,
+    /// First slot in which the deployment can be executed.
+    pub not_before_slot: Slot,
+    /// Hash of the program data of the source program when the deployment
+    /// was scheduled.
+    pub hash: [u8; 32],
+}
+
+impl ScheduledDeploy {
+    /// Size of a packed scheduled deployment.
+    pub const LEN: usize = 32 + 8 + 32;
+
+    /// Unpacks the value of a `ScheduledDeploy` extension.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(LoaderV4Error::InvalidProgramState.into());
+        }
+        Ok(Self {
+            source_address: Pubkey::new_from_array(data[..32].try_into().unwrap()),
+            not_before_slot: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+            hash: data[40..].try_into().unwrap(),
+        })
+    }
+
+    /// Packs the value of a `ScheduledDeploy` extension.
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0; Self::LEN];
+        data[..32].copy_from_slice(self.source_address.as_ref());
+        data[32..40].copy_from_slice(&self.not_before_slot.to_le_bytes());
+        data[40..].copy_from_slice(&self.hash);
+        data
+    }
+}
+
+/// Maximum number of signers of a `Multisig` extension.
+pub const MAX_MULTISIG_SIGNERS: usize = 11;
+
+/// Value of the `Multisig` extension.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Multisig {
+    /// Number of distinct signers required to act as the authority.
+    pub threshold: u8,
+    /// Addresses allowed to sign as part of the multisig.
+    pub signers: Vec<Pubkey>,
+}
+
+impl Multisig {
+    /// Checks that the threshold is reachable and the signers are distinct.
+    ///
+    /// Fails with `LoaderV4Error::InvalidMultisig` otherwise.
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let has_duplicates = self
+            .signers
+            .iter()
+            .enumerate()
+            .any(|(i, signer)| self.signers[..i].contains(signer));
+        if self.threshold == 0
+            || self.threshold as usize > self.signers.len()
+            || self.signers.len() > MAX_MULTISIG_SIGNERS
+            || has_duplicates
+        {
+            return Err(LoaderV4Error::InvalidMultisig.into());
+        }
+        Ok(())
+    }
+
+    /// Unpacks and validates the value of a `Multisig` extension.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let (threshold, signers) = data
+            .split_first()
+            .ok_or(LoaderV4Error::InvalidProgramState)?;
+        if signers.len() % 32 != 0 {
+            return Err(LoaderV4Error::InvalidProgramState.into());
+        }
+        let multisig = Self {
+            threshold: *threshold,
+            signers: signers
+                .chunks_exact(32)
+                .map(|signer| Pubkey::new_from_array(signer.try_into().unwrap()))
+                .collect(),
+        };
+        multisig
+            .validate()
+            .map_err(|_| LoaderV4Error::InvalidProgramState)?;
+        Ok(multisig)
+    }
+
+    /// Packs the value of a `Multisig` extension.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(1 + 32 * self.signers.len());
+        data.push(self.threshold);
+        for signer in &self.signers {
+            data.extend_from_slice(signer.as_ref());
+        }
+        data
+    }
+
+    /// Counts the signers of the multisig that signed among the given
+    /// accounts, each at most once.
+    pub fn count_signed(&self, accounts: &[AccountInfo]) -> usize {
+        self.signers
+            .iter()
+            .filter(|signer| {
+                accounts
+                    .iter()
+                    .any(|account| account.is_signer && account.key == *signer)
+            })
+            .count()
+    }
+}
+
+/// Returns the extension area of a program account, without its length and
+/// magic, along with the range of its program data.
+fn split_extensions(data: &[u8]) -> Result<(Range<usize>, &[u8]), ProgramError> {
+    let start = LoaderV4State::program_data_offset();
+    if data.len() < start {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if layout_version(data) == LayoutVersion::Legacy {
+        return Ok((start..data.len(), &[]));
+    }
+    let footer = data.len().saturating_sub(TRAILER_FOOTER_LEN);
+    let len = u32::from_le_bytes(data[footer..footer.saturating_add(4)].try_into().unwrap());
+    let end = footer
+        .checked_sub(len as usize)
+        .filter(|end| *end >= start)
+        .ok_or(LoaderV4Error::InvalidProgramState)?;
+    Ok((start..end, &data[end..footer]))
+}
+
+/// Splits an extension area into its entries.
+fn entries(mut area: &[u8]) -> Result<Vec<(u16, &[u8])>, ProgramError> {
+    let mut entries = Vec::new();
+    while !area.is_empty() {
+        let header = area
+            .get(..ENTRY_HEADER_LEN)
+            .ok_or(LoaderV4Error::InvalidProgramState)?;
+        let extension_type = u16::from_le_bytes(header[..2].try_into().unwrap());
+        let end = ENTRY_HEADER_LEN
+            .saturating_add(u16::from_le_bytes(header[2..].try_into().unwrap()) as usize);
+        let value = area
+            .get(ENTRY_HEADER_LEN..end)
+            .ok_or(LoaderV4Error::InvalidProgramState)?;
+        entries.push((extension_type, value));
+        area = &area[end..];
+    }
+    Ok(entries)
+}
+
+/// Returns the layout version of a program account.
+pub fn layout_version(data: &[u8]) -> LayoutVersion {
+    if data.len() >= LoaderV4State::program_data_offset().saturating_add(TRAILER_FOOTER_LEN)
+        && data.ends_with(&EXTENSIONS_MAGIC)
+    {
+        LayoutVersion::Extended
+    } else {
+        LayoutVersion::Legacy
+    }
+}
+
+/// Range of the program data in a program account of any layout.
+pub fn program_data_range(data: &[u8]) -> Result<Range<usize>, ProgramError> {
+    split_extensions(data).map(|(range, _)| range)
+}
+
+/// Returns the value of an extension of a program account, if it has one.
+pub fn get_extension(
+    data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<Option<&[u8]>, ProgramError> {
+    Ok(entries(split_extensions(data)?.1)?
+        .into_iter()
+        .find(|(entry_type, _)| *entry_type == extension_type as u16)
+        .map(|(_, value)| value))
+}
+
+/// Sets the value of an extension of a program account, resizing the account
+/// as needed.
+///
+/// The caller is responsible for keeping the account rent-exempt.
+pub fn set_extension(
+    program_info: &AccountInfo,
+    extension_type: ExtensionType,
+    value: &[u8],
+) -> Result<(), ProgramError> {
+    if value.len() > u16::MAX as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut area = Vec::new();
+    for (entry_type, entry_value) in entries(split_extensions(&program_info.try_borrow_data()?)?.1)?
+    {
+        if entry_type != extension_type as u16 {
+            push_entry(&mut area, entry_type, entry_value);
+        }
+    }
+    push_entry(&mut area, extension_type as u16, value);
+    write_extension_area(program_info, &area)
+}
+
+/// Removes an extension of a program account, resizing the account as
+/// needed. The account keeps the extended layout.
+///
+/// Returns whether the account had the extension.
+pub fn remove_extension(
+    program_info: &AccountInfo,
+    extension_type: ExtensionType,
+) -> Result<bool, ProgramError> {
+    let (area, removed) = {
+        let data = program_info.try_borrow_data()?;
+        let mut area = Vec::new();
+        let mut removed = false;
+        for (entry_type, entry_value) in entries(split_extensions(&data)?.1)? {
+            if entry_type == extension_type as u16 {
+                removed = true;
+            } else {
+                push_entry(&mut area, entry_type, entry_value);
+            }
+        }
+        (area, removed)
+    };
+    if removed {
+        write_extension_area(program_info, &area)?;
+    }
+    Ok(removed)
+}
+
+/// Resizes the program data of a program account, moving its extension
+/// trailer if it has one. Bytes added to the program data are zeroed.
+///
+/// The caller is responsible for keeping the account rent-exempt.
+pub fn resize_program_data(program_info: &AccountInfo, new_len: usize) -> Result<(), ProgramError> {
+    let old_len = program_info.data_len();
+    let range = program_data_range(&program_info.try_borrow_data()?)?;
+    let new_end = range.start.saturating_add(new_len);
+    let new_account_len = new_end.saturating_add(old_len.saturating_sub(range.end));
+
+    if new_account_len > old_len {
+        program_info.realloc(new_account_len, true)?;
+    }
+    {
+        let mut data = program_info.try_borrow_mut_data()?;
+        data.copy_within(range.end..old_len, new_end);
+        if new_end > range.end {
+            let zeroed_end = new_end.min(old_len);
+            data[range.end..zeroed_end].fill(0);
+        }
+    }
+    if new_account_len < old_len {
+        program_info.realloc(new_account_len, true)?;
+    }
+    Ok(())
+}
+
+fn push_entry(area: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+    area.extend_from_slice(&extension_type.to_le_bytes());
+    area.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    area.extend_from_slice(value);
+}
+
+/// Replaces the extension area of a program account, switching it to the
+/// extended layout.
+fn write_extension_area(program_info: &AccountInfo, area: &[u8]) -> Result<(), ProgramError> {
+    let program_data_end = program_data_range(&program_info.try_borrow_data()?)?.end;
+    let new_len = program_data_end
+        .saturating_add(area.len())
+        .saturating_add(TRAILER_FOOTER_LEN);
+
+    program_info.realloc(new_len, true)?;
+    let mut data = program_info.try_borrow_mut_data()?;
+    let footer = new_len.saturating_sub(TRAILER_FOOTER_LEN);
+    data[program_data_end..footer].copy_from_slice(area);
+    data[footer..footer.saturating_add(4)].copy_from_slice(&(area.len() as u32).to_le_bytes());
+    data[footer.saturating_add(4)..].copy_from_slice(&EXTENSIONS_MAGIC);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::LoaderV4Status, solana_program::pubkey::Pubkey};
+
+    fn account_data(
+        extensions: Option<&[(u16, &[u8])]>,
+        program_data: &[u8],
+    ) -> (LoaderV4State, Vec<u8>) {
+        let state = LoaderV4State {
+            slot: 42,
+            authority_address_or_next_version: Pubkey::new_unique(),
+            status: LoaderV4Status::Deployed,
+        };
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        state.pack(&mut data).unwrap();
+        data.extend_from_slice(program_data);
+        if let Some(extensions) = extensions {
+            let mut area = Vec::new();
+            for (extension_type, value) in extensions {
+                push_entry(&mut area, *extension_type, value);
+            }
+            data.extend_from_slice(&area);
+            data.extend_from_slice(&(area.len() as u32).to_le_bytes());
+            data.extend_from_slice(&EXTENSIONS_MAGIC);
+        }
+        (state, data)
+    }
+
+    #[test]
+    fn test_legacy_layout() {
+        let (state, data) = account_data(None, &[7; 10]);
+        assert_eq!(layout_version(&data), LayoutVersion::Legacy);
+        assert_eq!(LoaderV4State::unpack(&data), Ok(state));
+        assert_eq!(program_data_range(&data), Ok(48..58));
+        assert_eq!(
+            get_extension(&data, ExtensionType::PendingAuthority),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_extended_layout() {
+        let pending_authority = Pubkey::new_unique();
+        let (state, data) = account_data(
+            Some(&[(0xffff, &[1, 2, 3]), (1, pending_authority.as_ref())]),
+            &[7; 10],
+        );
+
+        assert_eq!(layout_version(&data), LayoutVersion::Extended);
+
+        // The header is the same as in the legacy layout.
+        assert_eq!(LoaderV4State::unpack(&data), Ok(state));
+
+        let range = program_data_range(&data).unwrap();
+        assert_eq!(range, 48..58);
+        assert_eq!(data[range], [7; 10]);
+        assert_eq!(data.len(), 58 + (4 + 3) + (4 + 32) + 4 + 8);
+        assert_eq!(
+            get_extension(&data, ExtensionType::PendingAuthority),
+            Ok(Some(pending_authority.as_ref()))
+        );
+
+        // An empty trailer keeps the layout.
+        let (_, data) = account_data(Some(&[]), &[7; 10]);
+        assert_eq!(layout_version(&data), LayoutVersion::Extended);
+        assert_eq!(program_data_range(&data), Ok(48..58));
+        assert_eq!(
+            get_extension(&data, ExtensionType::PendingAuthority),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_scheduled_deploy() {
+        let scheduled_deploy = ScheduledDeploy {
+            source_address: Pubkey::new_unique(),
+            not_before_slot: 0x0102_0304_0506_0708,
+            hash: [7; 32],
+        };
+        let data = scheduled_deploy.pack();
+        assert_eq!(data[..32], scheduled_deploy.source_address.to_bytes());
+        assert_eq!(data[32..40], scheduled_deploy.not_before_slot.to_le_bytes());
+        assert_eq!(ScheduledDeploy::unpack(&data), Ok(scheduled_deploy));
+        assert_eq!(
+            ScheduledDeploy::unpack(&data[1..]),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+    }
+
+    #[test]
+    fn test_multisig() {
+        let multisig = Multisig {
+            threshold: 2,
+            signers: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        let data = multisig.pack();
+        assert_eq!(data.len(), 1 + 2 * 32);
+        assert_eq!(data[0], 2);
+        assert_eq!(data[1..33], multisig.signers[0].to_bytes());
+        assert_eq!(Multisig::unpack(&data), Ok(multisig.clone()));
+        assert_eq!(
+            Multisig::unpack(&data[..data.len() - 1]),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+        assert_eq!(
+            Multisig::unpack(&[]),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+
+        let check_invalid = |threshold: u8, signers: Vec<Pubkey>| {
+            let multisig = Multisig { threshold, signers };
+            assert_eq!(
+                multisig.validate(),
+                Err(LoaderV4Error::InvalidMultisig.into())
+            );
+            assert_eq!(
+                Multisig::unpack(&multisig.pack()),
+                Err(LoaderV4Error::InvalidProgramState.into())
+            );
+        };
+        check_invalid(0, multisig.signers.clone());
+        check_invalid(3, multisig.signers.clone());
+        check_invalid(1, vec![multisig.signers[0]; 2]);
+        check_invalid(1, vec![]);
+        check_invalid(
+            1,
+            (0..=MAX_MULTISIG_SIGNERS)
+                .map(|_| Pubkey::new_unique())
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_extension_area() {
+        let (_, mut data) = account_data(None, &[]);
+        assert_eq!(
+            program_data_range(&data[..47]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+
+        // Too short to hold a trailer.
+        data.extend_from_slice(&EXTENSIONS_MAGIC);
+        assert_eq!(layout_version(&data), LayoutVersion::Legacy);
+        assert_eq!(program_data_range(&data), Ok(48..56));
+
+        // Area longer than the program data.
+        let (_, mut data) = account_data(Some(&[]), &[0; 4]);
+        let footer = data.len() - TRAILER_FOOTER_LEN;
+        data[footer..footer + 4].copy_from_slice(&5u32.to_le_bytes());
+        assert_eq!(
+            program_data_range(&data),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+
+        // Truncated entry.
+        data[footer..footer + 4].copy_from_slice(&4u32.to_le_bytes());
+        data[48..52].copy_from_slice(&[1, 0, 8, 0]);
+        assert_eq!(program_data_range(&data), Ok(48..48));
+        assert_eq!(
+            get_extension(&data, ExtensionType::PendingAuthority),
+            Err(LoaderV4Error::InvalidProgramState.into())
+        );
+    }
+}
@@ -2,13 +2,25 @@
 
 use {
     serde::{Deserialize, Serialize},
-    shank::ShankInstruction,
+    shank::{ShankInstruction, ShankType},
     solana_program::{
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
+        system_program,
     },
 };
 
+/// A range of program data written by a
+/// [WriteBatch](enum.LoaderV4Instruction.html)
+/// instruction.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ShankType)]
+pub struct WriteSegment {
+    /// Offset at which to write the given bytes.
+    pub offset: u32,
+    /// Serialized program data.
+    pub bytes: Vec<u8>,
+}
+
 /// Instructions supported by the Solana BPF Loader v4 program.
 #[rustfmt::skip]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ShankInstruction)]
@@ -18,7 +30,7 @@ pub enum LoaderV4Instruction {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Program account to write to.
-    /// 1. `[s]` Program authority.
+    /// 1. `[s]` Program authority or writer.
     #[account(
         0,
         writable,
@@ -29,7 +41,7 @@ pub enum LoaderV4Instruction {
         1,
         signer,
         name = "authority",
-        desc = "Program authority."
+        desc = "Program authority or writer."
     )]
     Write {
         /// Offset at which to write the given bytes.
@@ -53,7 +65,7 @@ pub enum LoaderV4Instruction {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w, s]` Program account to change the size of.
-    /// 1. `[s]` Program authority.
+    /// 1. `[s]` Program authority or writer.
     /// 2. `[w]` Destination account for reclaimed lamports (optional).
     #[account(
         0,
@@ -66,7 +78,7 @@ pub enum LoaderV4Instruction {
         1,
         signer,
         name = "authority",
-        desc = "Program authority."
+        desc = "Program authority or writer."
     )]
     #[account(
         2,
@@ -90,6 +102,8 @@ pub enum LoaderV4Instruction {
     /// necessary for rent exemption are transferred, in case that the source
     /// was bigger than the program.
     ///
+    /// Fails while the program has a deployment scheduled by `ScheduleDeploy`.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Program account to deploy.
@@ -142,6 +156,8 @@ pub enum LoaderV4Instruction {
 
     /// Transfers the authority over a program account.
     ///
+    /// Cancels any pending authority proposal.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Program account to change the authority of.
@@ -192,112 +208,1310 @@ pub enum LoaderV4Instruction {
         desc = "The next version of the program (can be itself)."
     )]
     Finalize,
-}
 
-/// Creates a
-/// [Write](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn write(
-    program_address: &Pubkey,
-    authority_address: &Pubkey,
-    offset: u32,
-    bytes: Vec<u8>,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*program_address, false),
-        AccountMeta::new_readonly(*authority_address, true),
-    ];
-    Instruction::new_with_bincode(
-        crate::id(),
-        &LoaderV4Instruction::Write { offset, bytes },
-        accounts,
-    )
-}
+    /// Migrates a program of the BPF Upgradeable Loader (loader-v3) into an
+    /// uninitialized program account.
+    ///
+    /// The ELF following the loader-v3 program data header is copied into the
+    /// program account, which is initialized with the loader-v3 upgrade
+    /// authority as its authority. The loader-v3 accounts are left untouched.
+    /// The program account must either be empty or already be allocated with
+    /// zeroed data, which allows migrating programs larger than the data
+    /// increase permitted in a single instruction.
+    /// Providing lamports upfront is necessary to reach rent exemption.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Program account to migrate into.
+    /// 1. `[s]` Upgrade authority of the loader-v3 program.
+    /// 2. `[ ]` Loader-v3 program account.
+    /// 3. `[ ]` Loader-v3 program data account.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "program",
+        desc = "Program account to migrate into."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Upgrade authority of the loader-v3 program."
+    )]
+    #[account(
+        2,
+        name = "v3_program",
+        desc = "Loader-v3 program account."
+    )]
+    #[account(
+        3,
+        name = "v3_program_data",
+        desc = "Loader-v3 program data account."
+    )]
+    Migrate {
+        /// Whether to deploy the program, rather than leaving it retracted.
+        deploy: bool,
+    },
 
-/// Creates a
-/// [Truncate](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn truncate(
-    program_address: &Pubkey,
-    authority_address: &Pubkey,
-    destination_address: Option<&Pubkey>,
-    new_size: u32,
-) -> Instruction {
-    let mut accounts = vec![
-        AccountMeta::new(*program_address, true),
-        AccountMeta::new_readonly(*authority_address, true),
-    ];
-    if let Some(destination_address) = destination_address {
-        accounts.push(AccountMeta::new(*destination_address, false));
-    }
-    Instruction::new_with_bincode(
-        crate::id(),
-        &LoaderV4Instruction::Truncate { new_size },
-        accounts,
-    )
-}
+    /// Copy data from another account into an undeployed program account.
+    ///
+    /// The source offset is relative to the start of the program data of the
+    /// source account: after the state header of a loader-v4 program, after
+    /// the metadata of a loader-v3 buffer or program data account, and from
+    /// the start of the data of any other account.
+    /// The source may be the program account itself.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to copy to.
+    /// 1. `[s]` Program authority or writer.
+    /// 2. `[ ]` Source account to copy from.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to copy to."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority or writer."
+    )]
+    #[account(
+        2,
+        name = "source",
+        desc = "Source account to copy from."
+    )]
+    Copy {
+        /// Offset at which to write the copied bytes.
+        destination_offset: u32,
+        /// Offset in the source program data at which to start reading.
+        source_offset: u32,
+        /// Number of bytes to copy.
+        length: u32,
+    },
 
-/// Creates a
-/// [Deploy](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn deploy(
-    program_address: &Pubkey,
-    authority_address: &Pubkey,
-    source_address: Option<&Pubkey>,
-) -> Instruction {
-    let mut accounts = vec![
-        AccountMeta::new(*program_address, false),
-        AccountMeta::new_readonly(*authority_address, true),
-    ];
-    if let Some(source_address) = source_address {
-        accounts.push(AccountMeta::new(*source_address, false));
-    }
-    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Deploy, accounts)
-}
+    /// Write several ranges of ELF data into an undeployed program account.
+    ///
+    /// Every segment is bounds-checked before any byte is written, so either
+    /// all segments are written or none. Overlapping segments are written in
+    /// order.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to write to.
+    /// 1. `[s]` Program authority or writer.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to write to."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority or writer."
+    )]
+    WriteBatch {
+        /// Segments to write.
+        segments: Vec<WriteSegment>,
+    },
 
-/// Creates a
-/// [Retract](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn retract(program_address: &Pubkey, authority_address: &Pubkey) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*program_address, false),
-        AccountMeta::new_readonly(*authority_address, true),
-    ];
-    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Retract, accounts)
-}
+    /// Same as `Deploy`, but fails unless the SHA-256 hash of the program
+    /// data to be deployed matches the expected hash.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to deploy.
+    /// 1. `[s]` Program authority.
+    /// 2. `[w]` Undeployed source program account to take data and lamports
+    ///    from (optional).
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to deploy."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        writable,
+        optional,
+        name = "source",
+        desc = "Undeployed source program account to take data and lamports from (optional)."
+    )]
+    DeployWithHash {
+        /// SHA-256 hash of the program data that is expected to be deployed.
+        expected_hash: [u8; 32],
+    },
 
-/// Creates a
-/// [TransferAuthority](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn transfer_authority(
-    program_address: &Pubkey,
-    current_authority_address: &Pubkey,
-    new_authority_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*program_address, false),
-        AccountMeta::new_readonly(*current_authority_address, true),
-        AccountMeta::new_readonly(*new_authority_address, true),
-    ];
-    Instruction::new_with_bincode(
-        crate::id(),
-        &LoaderV4Instruction::TransferAuthority,
-        accounts,
-    )
-}
+    /// Compute the SHA-256 hash of a range of the program data of a program
+    /// account, in any state, and return it as return data.
+    ///
+    /// The offset is relative to the start of the program data, after the
+    /// state header.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Program account to hash.
+    #[account(
+        0,
+        name = "program",
+        desc = "Program account to hash."
+    )]
+    Hash {
+        /// Offset at which the hashed range starts.
+        offset: u32,
+        /// Length of the hashed range.
+        length: u32,
+    },
 
-/// Creates a
-/// [Finalize](enum.LoaderV4Instruction.html)
-/// instruction.
-pub fn finalize(
-    program_address: &Pubkey,
-    authority_address: &Pubkey,
-    next_version_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*program_address, false),
-        AccountMeta::new_readonly(*authority_address, true),
-        AccountMeta::new_readonly(*next_version_address, false),
-    ];
-    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Finalize, accounts)
+    /// Proposes a new authority for a program account, which takes over once
+    /// it accepts with `AcceptAuthority`.
+    ///
+    /// The proposed authority is stored in an extension of the program
+    /// account, replacing any previous proposal. The program account must
+    /// hold enough lamports to stay rent-exempt as it grows.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to change the authority of.
+    /// 1. `[s]` Current program authority.
+    /// 2. `[ ]` Proposed program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to change the authority of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "current_authority",
+        desc = "Current program authority."
+    )]
+    #[account(
+        2,
+        name = "proposed_authority",
+        desc = "Proposed program authority."
+    )]
+    ProposeAuthority,
+
+    /// Accepts the authority over a program account proposed by
+    /// `ProposeAuthority`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to change the authority of.
+    /// 1. `[s]` Proposed program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to change the authority of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "proposed_authority",
+        desc = "Proposed program authority."
+    )]
+    AcceptAuthority,
+
+    /// Cancels the pending authority proposal of a program account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account with a pending authority proposal.
+    /// 1. `[s]` Current program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account with a pending authority proposal."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "current_authority",
+        desc = "Current program authority."
+    )]
+    CancelAuthorityProposal,
+
+    /// Schedules the deployment of the program data of a source program,
+    /// which anyone can execute with `ExecuteScheduledDeploy` from
+    /// `not_before_slot` on.
+    ///
+    /// The hash of the source program data is recorded, so that the
+    /// deployment fails if the source program changes in the meantime. The
+    /// schedule is stored in an extension of the program account, replacing
+    /// any previous one. The program account must hold enough lamports to stay
+    /// rent-exempt as it grows.
+    ///
+    /// `not_before_slot` must be after the current slot. While the schedule
+    /// is pending, `Deploy`, `DeployWithHash` and `ExecuteProposal` fail, so
+    /// the program can only be deployed early by visibly cancelling the
+    /// schedule with `CancelScheduledDeploy` first. Changing the authority
+    /// cancels the schedule.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to deploy.
+    /// 1. `[s]` Program authority, which must also be the authority of the
+    ///    source program.
+    /// 2. `[ ]` Source program account to take the program data from.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to deploy."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        name = "source",
+        desc = "Source program account to take the program data from."
+    )]
+    ScheduleDeploy {
+        /// First slot in which the deployment can be executed.
+        not_before_slot: u64,
+    },
+
+    /// Executes the deployment scheduled by `ScheduleDeploy`, once due.
+    ///
+    /// Works like `Deploy` from the source program, retracting the program
+    /// first if it is deployed. The cooldown applies the same way. The
+    /// authority of the source program must still be the program authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to deploy.
+    /// 1. `[w]` Source program account recorded by `ScheduleDeploy`.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to deploy."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "source",
+        desc = "Source program account recorded by the schedule."
+    )]
+    ExecuteScheduledDeploy,
+
+    /// Cancels the deployment scheduled by `ScheduleDeploy`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account with a scheduled deployment.
+    /// 1. `[s]` Program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account with a scheduled deployment."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    CancelScheduledDeploy,
+
+    /// Closes a program account, retracting it first if it is deployed.
+    ///
+    /// Deployed programs can only be closed once the cooldown since their
+    /// last deployment has passed, like with `Retract`. All lamports of the
+    /// program account are transferred to the destination account, which must
+    /// not be the program account itself.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to close.
+    /// 1. `[s]` Program authority.
+    /// 2. `[w]` Destination account for the lamports of the program account.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to close."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "destination",
+        desc = "Destination account for the lamports of the program account."
+    )]
+    Close,
+
+    /// Creates and initializes a program account of the given size.
+    ///
+    /// The program account is allocated and assigned by the system program,
+    /// and funded by the payer up to the rent-exempt minimum of the state
+    /// header and `size` bytes of program data. Lamports already held by the
+    /// program account count towards that minimum. The program data is
+    /// zeroed, like after an initial `Truncate`.
+    ///
+    /// The system program allocates the account in a cross-program
+    /// invocation, so the header and `size` bytes of program data must not
+    /// exceed `MAX_PERMITTED_DATA_INCREASE` (10 KiB). Larger programs are
+    /// grown afterwards by `Truncate`, by at most as much per instruction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Program account to create.
+    /// 1. `[s]` Program authority.
+    /// 2. `[w, s]` Payer funding the program account.
+    /// 3. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "program",
+        desc = "Program account to create."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer funding the program account."
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program."
+    )]
+    Create {
+        /// Size of the program data, after the state header.
+        size: u32,
+    },
+
+    /// Creates and initializes a program account of the given size at the
+    /// address derived from the base address and the seed.
+    ///
+    /// Same as `Create`, except that the program account does not sign:
+    /// its address must be the one returned by
+    /// `state::find_program_address` for the base address and the seed,
+    /// along with the bump seed, and the base account signs instead. The
+    /// size is limited like that of `Create`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to create.
+    /// 1. `[s]` Base account the program address is derived from.
+    /// 2. `[s]` Program authority.
+    /// 3. `[w, s]` Payer funding the program account.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to create."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "base",
+        desc = "Base account the program address is derived from."
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        3,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer funding the program account."
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program."
+    )]
+    CreateWithSeed {
+        /// Seed the program address is derived from, at most `MAX_SEED_LEN`
+        /// bytes.
+        seed: Vec<u8>,
+        /// Size of the program data, after the state header.
+        size: u32,
+        /// Bump seed of the program address.
+        bump_seed: u8,
+    },
+
+    /// Sets or rotates the multisig of a program account.
+    ///
+    /// While a program has a multisig, the authority's signature is no
+    /// longer sufficient nor required: instructions requiring the authority
+    /// instead require `threshold` of the `signers` to sign, passed as
+    /// exactly `threshold` additional accounts after those of the
+    /// instruction, so that optional accounts remain unambiguous. See
+    /// `with_multisig_signers`.
+    ///
+    /// The multisig is removed by `RemoveMultisig` and whenever the
+    /// authority changes. The program account must hold enough lamports
+    /// to remain rent-exempt with the multisig.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to set the multisig of.
+    /// 1. `[s]` Program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to set the multisig of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    SetMultisig {
+        /// Number of distinct signers required, at least one.
+        threshold: u8,
+        /// Addresses allowed to sign, at most `MAX_MULTISIG_SIGNERS`.
+        signers: Vec<Pubkey>,
+    },
+
+    /// Removes the multisig of a program account, so that the authority
+    /// alone signs again.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to remove the multisig of.
+    /// 1. `[s]` Program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to remove the multisig of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    RemoveMultisig,
+
+    /// Proposes the deployment of a source program, to be approved by the
+    /// members of the authority before it is executed.
+    ///
+    /// The members are the signers of the multisig of the program if it has
+    /// one, the authority alone otherwise. The proposal account is created
+    /// at the address returned by `proposal::find_proposal_address`, funded
+    /// by the payer up to its rent-exempt minimum, and records the source
+    /// program, the hash of its program data and the proposer's approval.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Program account to propose the deployment of.
+    /// 1. `[w]` Proposal account to create.
+    /// 2. `[ ]` Undeployed source program account to deploy.
+    /// 3. `[s]` Member of the authority proposing the deployment.
+    /// 4. `[w, s]` Payer funding the proposal account.
+    /// 5. `[ ]` System program.
+    #[account(
+        0,
+        name = "program",
+        desc = "Program account to propose the deployment of."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "proposal",
+        desc = "Proposal account to create."
+    )]
+    #[account(
+        2,
+        name = "source",
+        desc = "Undeployed source program account to deploy."
+    )]
+    #[account(
+        3,
+        signer,
+        name = "proposer",
+        desc = "Member of the authority proposing the deployment."
+    )]
+    #[account(
+        4,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer funding the proposal account."
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program."
+    )]
+    ProposeDeploy,
+
+    /// Approves the deployment proposed for a program.
+    ///
+    /// Approvals of addresses which are no longer members of the authority
+    /// are dropped.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Program account of the proposal.
+    /// 1. `[w]` Proposal account.
+    /// 2. `[s]` Member of the authority approving the deployment.
+    #[account(
+        0,
+        name = "program",
+        desc = "Program account of the proposal."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "proposal",
+        desc = "Proposal account."
+    )]
+    #[account(
+        2,
+        signer,
+        name = "approver",
+        desc = "Member of the authority approving the deployment."
+    )]
+    Approve,
+
+    /// Executes a proposed deployment, once approved by as many members of
+    /// the authority as its multisig requires, or by the authority if it
+    /// has none. Can be sent by anyone.
+    ///
+    /// Same as `Retract` followed by `Deploy` with the source program, which
+    /// must be unchanged since the deployment was proposed, so the cooldown
+    /// applies. The proposal account is closed and its lamports refunded to
+    /// its payer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to deploy.
+    /// 1. `[w]` Proposal account.
+    /// 2. `[w]` Source program account of the proposal.
+    /// 3. `[w]` Payer of the proposal account.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to deploy."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "proposal",
+        desc = "Proposal account."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "source",
+        desc = "Source program account of the proposal."
+    )]
+    #[account(
+        3,
+        writable,
+        name = "payer",
+        desc = "Payer of the proposal account."
+    )]
+    ExecuteProposal,
+
+    /// Cancels the deployment proposed for a program, closing the proposal
+    /// account and refunding its lamports to its payer.
+    ///
+    /// Once the program is finalized or closed, the proposal can never be
+    /// executed, and it is cancelled without the signature of the authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account of the proposal.
+    /// 1. `[s]` Program authority.
+    /// 2. `[w]` Proposal account.
+    /// 3. `[w]` Payer of the proposal account.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account of the proposal."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "proposal",
+        desc = "Proposal account."
+    )]
+    #[account(
+        3,
+        writable,
+        name = "payer",
+        desc = "Payer of the proposal account."
+    )]
+    CancelProposal,
+
+    /// Sets or replaces the writer of a program account.
+    ///
+    /// The writer can sign instead of the authority to `Write`, `WriteBatch`,
+    /// `Copy` and `Truncate` the program while it is retracted, but nothing
+    /// else: in particular, it can neither close the program nor withdraw
+    /// lamports from it with `Truncate`. The writer must differ from the
+    /// program and its authority. It is removed by `RevokeWriter` and
+    /// whenever the authority changes. The program account must hold enough
+    /// lamports to remain rent-exempt with the writer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to set the writer of.
+    /// 1. `[s]` Program authority.
+    /// 2. `[]` Writer.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to set the writer of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    #[account(
+        2,
+        name = "writer",
+        desc = "Writer."
+    )]
+    SetWriter,
+
+    /// Revokes the writer of a program account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Program account to revoke the writer of.
+    /// 1. `[s]` Program authority.
+    #[account(
+        0,
+        writable,
+        name = "program",
+        desc = "Program account to revoke the writer of."
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Program authority."
+    )]
+    RevokeWriter,
+}
+
+/// Creates a
+/// [Write](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn write(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::Write { offset, bytes },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Truncate](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn truncate(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    destination_address: Option<&Pubkey>,
+    new_size: u32,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*program_address, true),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    if let Some(destination_address) = destination_address {
+        accounts.push(AccountMeta::new(*destination_address, false));
+    }
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::Truncate { new_size },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Deploy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn deploy(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    source_address: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    if let Some(source_address) = source_address {
+        accounts.push(AccountMeta::new(*source_address, false));
+    }
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Deploy, accounts)
+}
+
+/// Creates a
+/// [DeployWithHash](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn deploy_with_hash(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    source_address: Option<&Pubkey>,
+    expected_hash: [u8; 32],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    if let Some(source_address) = source_address {
+        accounts.push(AccountMeta::new(*source_address, false));
+    }
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::DeployWithHash { expected_hash },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Retract](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn retract(program_address: &Pubkey, authority_address: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Retract, accounts)
+}
+
+/// Creates a
+/// [TransferAuthority](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn transfer_authority(
+    program_address: &Pubkey,
+    current_authority_address: &Pubkey,
+    new_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*current_authority_address, true),
+        AccountMeta::new_readonly(*new_authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::TransferAuthority,
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Finalize](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn finalize(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    next_version_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*next_version_address, false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Finalize, accounts)
+}
+
+/// Creates a
+/// [Migrate](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn migrate(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    v3_program_address: &Pubkey,
+    v3_program_data_address: &Pubkey,
+    deploy: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, true),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*v3_program_address, false),
+        AccountMeta::new_readonly(*v3_program_data_address, false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::Migrate { deploy },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Copy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn copy(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    source_address: &Pubkey,
+    destination_offset: u32,
+    source_offset: u32,
+    length: u32,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*source_address, false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::Copy {
+            destination_offset,
+            source_offset,
+            length,
+        },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [WriteBatch](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn write_batch(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    segments: Vec<WriteSegment>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::WriteBatch { segments },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Hash](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn hash(program_address: &Pubkey, offset: u32, length: u32) -> Instruction {
+    let accounts = vec![AccountMeta::new_readonly(*program_address, false)];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::Hash { offset, length },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [ProposeAuthority](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn propose_authority(
+    program_address: &Pubkey,
+    current_authority_address: &Pubkey,
+    proposed_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*current_authority_address, true),
+        AccountMeta::new_readonly(*proposed_authority_address, false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::ProposeAuthority,
+        accounts,
+    )
+}
+
+/// Creates an
+/// [AcceptAuthority](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn accept_authority(
+    program_address: &Pubkey,
+    proposed_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*proposed_authority_address, true),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::AcceptAuthority, accounts)
+}
+
+/// Creates a
+/// [CancelAuthorityProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn cancel_authority_proposal(
+    program_address: &Pubkey,
+    current_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*current_authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::CancelAuthorityProposal,
+        accounts,
+    )
+}
+
+/// Creates a
+/// [ScheduleDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn schedule_deploy(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    source_address: &Pubkey,
+    not_before_slot: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*source_address, false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::ScheduleDeploy { not_before_slot },
+        accounts,
+    )
+}
+
+/// Creates an
+/// [ExecuteScheduledDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn execute_scheduled_deploy(program_address: &Pubkey, source_address: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new(*source_address, false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::ExecuteScheduledDeploy,
+        accounts,
+    )
+}
+
+/// Creates a
+/// [CancelScheduledDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn cancel_scheduled_deploy(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::CancelScheduledDeploy,
+        accounts,
+    )
+}
+
+/// Creates a
+/// [Close](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn close(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    destination_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(*destination_address, false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Close, accounts)
+}
+
+/// Creates a
+/// [Create](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn create(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    payer_address: &Pubkey,
+    size: u32,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, true),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Create { size }, accounts)
+}
+
+/// Creates a
+/// [CreateWithSeed](enum.LoaderV4Instruction.html)
+/// instruction.
+///
+/// The program address is derived with `state::find_program_address`.
+pub fn create_with_seed(
+    base_address: &Pubkey,
+    seed: &[u8],
+    authority_address: &Pubkey,
+    payer_address: &Pubkey,
+    size: u32,
+) -> Instruction {
+    let (program_address, bump_seed) = crate::state::find_program_address(base_address, seed);
+    let accounts = vec![
+        AccountMeta::new(program_address, false),
+        AccountMeta::new_readonly(*base_address, true),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::CreateWithSeed {
+            seed: seed.to_vec(),
+            size,
+            bump_seed,
+        },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [SetMultisig](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn set_multisig(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    threshold: u8,
+    signer_addresses: &[Pubkey],
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(
+        crate::id(),
+        &LoaderV4Instruction::SetMultisig {
+            threshold,
+            signers: signer_addresses.to_vec(),
+        },
+        accounts,
+    )
+}
+
+/// Creates a
+/// [RemoveMultisig](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn remove_multisig(program_address: &Pubkey, authority_address: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::RemoveMultisig, accounts)
+}
+
+/// Creates a
+/// [ProposeDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn propose_deploy(
+    program_address: &Pubkey,
+    source_address: &Pubkey,
+    proposer_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let (proposal_address, _) = crate::proposal::find_proposal_address(program_address);
+    let accounts = vec![
+        AccountMeta::new_readonly(*program_address, false),
+        AccountMeta::new(proposal_address, false),
+        AccountMeta::new_readonly(*source_address, false),
+        AccountMeta::new_readonly(*proposer_address, true),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::ProposeDeploy, accounts)
+}
+
+/// Creates an
+/// [Approve](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn approve(program_address: &Pubkey, approver_address: &Pubkey) -> Instruction {
+    let (proposal_address, _) = crate::proposal::find_proposal_address(program_address);
+    let accounts = vec![
+        AccountMeta::new_readonly(*program_address, false),
+        AccountMeta::new(proposal_address, false),
+        AccountMeta::new_readonly(*approver_address, true),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::Approve, accounts)
+}
+
+/// Creates an
+/// [ExecuteProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn execute_proposal(
+    program_address: &Pubkey,
+    source_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let (proposal_address, _) = crate::proposal::find_proposal_address(program_address);
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new(proposal_address, false),
+        AccountMeta::new(*source_address, false),
+        AccountMeta::new(*payer_address, false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::ExecuteProposal, accounts)
+}
+
+/// Creates a
+/// [CancelProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn cancel_proposal(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let (proposal_address, _) = crate::proposal::find_proposal_address(program_address);
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(proposal_address, false),
+        AccountMeta::new(*payer_address, false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::CancelProposal, accounts)
+}
+
+/// Creates a
+/// [SetWriter](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn set_writer(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    writer_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(*writer_address, false),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::SetWriter, accounts)
+}
+
+/// Creates a
+/// [RevokeWriter](enum.LoaderV4Instruction.html)
+/// instruction.
+pub fn revoke_writer(program_address: &Pubkey, authority_address: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    Instruction::new_with_bincode(crate::id(), &LoaderV4Instruction::RevokeWriter, accounts)
+}
+
+/// Turns an instruction signed by the program authority into one signed by
+/// signers of the multisig of the program instead.
+///
+/// The authority, the second account of the instructions requiring it, no
+/// longer signs and the signers are appended. They must be exactly as many
+/// as the threshold of the multisig, as the loader takes that many trailing
+/// accounts as the signers.
+pub fn with_multisig_signers(
+    mut instruction: Instruction,
+    signer_addresses: &[Pubkey],
+) -> Instruction {
+    if let Some(authority) = instruction.accounts.get_mut(1) {
+        authority.is_signer = false;
+    }
+    instruction.accounts.extend(
+        signer_addresses
+            .iter()
+            .map(|signer_address| AccountMeta::new_readonly(*signer_address, true)),
+    );
+    instruction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_multisig_signers() {
+        let program = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        // The signers directly follow the accounts of the instruction, even
+        // without its optional ones.
+        let instruction = with_multisig_signers(truncate(&program, &authority, None, 0), &signers);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(program, true),
+                AccountMeta::new_readonly(authority, false),
+                AccountMeta::new_readonly(signers[0], true),
+                AccountMeta::new_readonly(signers[1], true),
+            ]
+        );
+
+        // Instructions without an authority are left as they are.
+        let instruction = with_multisig_signers(hash(&program, 0, 0), &signers);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(program, false),
+                AccountMeta::new_readonly(signers[0], true),
+                AccountMeta::new_readonly(signers[1], true),
+            ]
+        );
+    }
 }
@@ -1,10 +1,15 @@
 //! Solana BPF Loader V4 (Upgradeable) program.
 #![allow(unexpected_cfgs)]
 
+pub mod elf;
 #[cfg(all(target_os = "solana", feature = "bpf-entrypoint"))]
 mod entrypoint;
+pub mod error;
+pub mod event;
+pub mod extension;
 pub mod instruction;
 pub mod processor;
+pub mod proposal;
 pub mod state;
 
 // [CORE BPF]: Unfortunately, the runtime still depends pretty heavily on this
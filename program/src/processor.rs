@@ -2,19 +2,29 @@
 
 use {
     crate::{
-        instruction::LoaderV4Instruction,
-        state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
+        elf,
+        error::LoaderV4Error,
+        event::{LoaderV4Event, LoaderV4EventKind},
+        extension::{self, ExtensionType, LayoutVersion, Multisig, ScheduledDeploy},
+        instruction::{LoaderV4Instruction, WriteSegment},
+        proposal::{DeployProposal, PROPOSAL_SEED_PREFIX},
+        state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS, PROGRAM_SEED_PREFIX},
     },
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         clock::{Clock, Slot},
-        entrypoint::ProgramResult,
+        entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+        hash::hash,
         msg,
+        program::{invoke_signed, set_return_data},
         program_error::ProgramError,
-        pubkey::Pubkey,
+        pubkey::{Pubkey, MAX_SEED_LEN},
         rent::Rent,
+        system_instruction, system_program,
         sysvar::Sysvar,
     },
+    std::ops::Range,
 };
 
 // Keep in sync with the constant from the program-runtime.
@@ -37,18 +47,35 @@ fn check_program_account(
     program_id: &Pubkey,
     program_info: &AccountInfo,
     authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
 ) -> Result<LoaderV4State, ProgramError> {
     if program_info.owner != program_id {
         msg!("Program not owned by loader");
         return Err(ProgramError::InvalidAccountOwner);
     }
-    let data = program_info.try_borrow_data()?;
-    let state = LoaderV4State::unpack(&data)?;
+    let program_data = program_info.try_borrow_data()?;
+    let state = LoaderV4State::unpack(&program_data)?;
     if !program_info.is_writable {
         msg!("Program is not writeable");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::ProgramNotWritable.into());
     }
-    if !authority_info.is_signer {
+    // A multisig replaces the authority's signature. Its signers are the
+    // last accounts, as many as the threshold, after at least the program
+    // account and the authority.
+    if let Some(multisig) = extension::get_extension(&program_data, ExtensionType::Multisig)? {
+        let multisig = Multisig::unpack(multisig)?;
+        let threshold = multisig.threshold as usize;
+        let signers = accounts
+            .len()
+            .checked_sub(threshold)
+            .filter(|start| *start >= 2)
+            .map(|start| &accounts[start..])
+            .unwrap_or_default();
+        if multisig.count_signed(signers) < threshold {
+            msg!("Not enough multisig signers signed");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    } else if !authority_info.is_signer {
         msg!("Authority did not sign");
         return Err(ProgramError::MissingRequiredSignature);
     }
@@ -60,7 +87,108 @@ fn check_program_account(
         msg!("Program is finalized");
         return Err(ProgramError::Immutable);
     }
-    Ok(*state)
+    Ok(state)
+}
+
+/// Checks that program data written to a program account of the legacy
+/// layout does not end with the extensions magic, as the account would then
+/// read as one of the extended layout, with a trailer forged by the writer.
+fn check_not_forged_trailer(layout: LayoutVersion, program_data: &[u8]) -> ProgramResult {
+    if layout == LayoutVersion::Legacy
+        && extension::layout_version(program_data) == LayoutVersion::Extended
+    {
+        msg!("Program data ends with the extensions magic");
+        return Err(LoaderV4Error::InvalidElf.into());
+    }
+    Ok(())
+}
+
+/// Checks a program account for an instruction which only modifies its
+/// program data, which its writer can sign instead of its authority.
+fn check_program_account_or_writer(
+    program_id: &Pubkey,
+    program_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> Result<LoaderV4State, ProgramError> {
+    if program_info.owner == program_id {
+        let program_data = program_info.try_borrow_data()?;
+        if is_writer(&program_data, authority_info) {
+            let state = LoaderV4State::unpack(&program_data)?;
+            if !program_info.is_writable {
+                msg!("Program is not writeable");
+                return Err(LoaderV4Error::ProgramNotWritable.into());
+            }
+            if matches!(state.status, LoaderV4Status::Finalized) {
+                msg!("Program is finalized");
+                return Err(ProgramError::Immutable);
+            }
+            return Ok(state);
+        }
+    }
+    check_program_account(program_id, program_info, authority_info, accounts)
+}
+
+/// Whether the signer of an instruction is the writer of the program.
+fn is_writer(program_data: &[u8], authority_info: &AccountInfo) -> bool {
+    authority_info.is_signer
+        && matches!(
+            extension::get_extension(program_data, ExtensionType::Writer),
+            Ok(Some(writer)) if writer == authority_info.key.as_ref()
+        )
+}
+
+/// Returns the accounts of an instruction on a checked program account
+/// without the trailing multisig signers, if the program has a multisig.
+fn without_multisig_signers<'a, 'b>(
+    program_info: &AccountInfo<'a>,
+    accounts: &'b [AccountInfo<'a>],
+) -> Result<&'b [AccountInfo<'a>], ProgramError> {
+    let program_data = program_info.try_borrow_data()?;
+    match extension::get_extension(&program_data, ExtensionType::Multisig)? {
+        Some(multisig) => {
+            let threshold = Multisig::unpack(multisig)?.threshold as usize;
+            Ok(&accounts[..accounts.len().saturating_sub(threshold)])
+        }
+        None => Ok(accounts),
+    }
+}
+
+/// Checks that a program account holds enough lamports to be rent-exempt.
+fn check_rent_exemption(program_info: &AccountInfo) -> ProgramResult {
+    let rent = <Rent as Sysvar>::get()?;
+    let required_lamports = rent.minimum_balance(program_info.data_len());
+    if program_info.lamports() < required_lamports {
+        msg!("Insufficient lamports, {} are required.", required_lamports);
+        return Err(ProgramError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+/// Logs the state transition of a program account.
+fn emit_event(
+    kind: LoaderV4EventKind,
+    program_info: &AccountInfo,
+    old_state: Option<LoaderV4State>,
+    new_state: Option<LoaderV4State>,
+    lamports: u64,
+) {
+    // Closed accounts have no program data.
+    let data_len = program_info
+        .try_borrow_data()
+        .ok()
+        .and_then(|data| extension::program_data_range(&data).ok())
+        .map(|range| range.len())
+        .unwrap_or(0);
+    LoaderV4Event {
+        kind,
+        program_address: *program_info.key,
+        old_state,
+        new_state,
+        data_len: data_len as u64,
+        lamports,
+    }
+    .emit();
 }
 
 /// Processes an
@@ -77,26 +205,186 @@ fn process_write(
     let program_info = next_account_info(accounts_iter)?;
     let authority_info = next_account_info(accounts_iter)?;
 
-    let state = check_program_account(program_id, program_info, authority_info)?;
+    let state =
+        check_program_account_or_writer(program_id, program_info, authority_info, accounts)?;
 
     if !matches!(state.status, LoaderV4Status::Retracted) {
         msg!("Program is not retracted");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::ProgramNotRetracted.into());
     }
 
     let end_offset = (offset as usize).saturating_add(bytes.len());
 
-    program_info
-        .try_borrow_mut_data()?
-        .get_mut(
-            LoaderV4State::program_data_offset().saturating_add(offset as usize)
-                ..LoaderV4State::program_data_offset().saturating_add(end_offset),
-        )
-        .ok_or_else(|| {
-            msg!("Write out of bounds");
-            ProgramError::AccountDataTooSmall
-        })?
-        .copy_from_slice(&bytes);
+    {
+        let mut program_data = program_info.try_borrow_mut_data()?;
+        let layout = extension::layout_version(&program_data);
+        let range = extension::program_data_range(&program_data)?;
+        program_data[range]
+            .get_mut(offset as usize..end_offset)
+            .ok_or_else(|| {
+                msg!("Write out of bounds");
+                ProgramError::AccountDataTooSmall
+            })?
+            .copy_from_slice(&bytes);
+        check_not_forged_trailer(layout, &program_data)?;
+    }
+
+    emit_event(
+        LoaderV4EventKind::Write,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [WriteBatch](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_write_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    segments: Vec<WriteSegment>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    let state =
+        check_program_account_or_writer(program_id, program_info, authority_info, accounts)?;
+
+    if !matches!(state.status, LoaderV4Status::Retracted) {
+        msg!("Program is not retracted");
+        return Err(LoaderV4Error::ProgramNotRetracted.into());
+    }
+
+    {
+        let mut program_data = program_info.try_borrow_mut_data()?;
+        let layout = extension::layout_version(&program_data);
+        let program_data_range = extension::program_data_range(&program_data)?;
+
+        // Check every segment before writing any, so that a batch is never
+        // partially applied.
+        let ranges = segments
+            .iter()
+            .map(|segment| {
+                let start = program_data_range
+                    .start
+                    .saturating_add(segment.offset as usize);
+                let end = start.saturating_add(segment.bytes.len());
+                if end > program_data_range.end {
+                    msg!("Write out of bounds");
+                    return Err(ProgramError::AccountDataTooSmall);
+                }
+                Ok(start..end)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (segment, range) in segments.iter().zip(ranges) {
+            program_data[range].copy_from_slice(&segment.bytes);
+        }
+        check_not_forged_trailer(layout, &program_data)?;
+    }
+
+    emit_event(
+        LoaderV4EventKind::WriteBatch,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Returns the range of the program data in a source account of a
+/// [Copy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn source_program_data_range(
+    program_id: &Pubkey,
+    source_info: &AccountInfo,
+) -> Result<Range<usize>, ProgramError> {
+    let source_data = source_info.try_borrow_data()?;
+    if source_info.owner == program_id {
+        extension::program_data_range(&source_data)
+    } else if source_info.owner == &bpf_loader_upgradeable::id() {
+        match bincode::deserialize(&source_data) {
+            Ok(UpgradeableLoaderState::Buffer { .. }) => {
+                Ok(UpgradeableLoaderState::size_of_buffer_metadata()..source_data.len())
+            }
+            Ok(UpgradeableLoaderState::ProgramData { .. }) => {
+                Ok(UpgradeableLoaderState::size_of_programdata_metadata()..source_data.len())
+            }
+            _ => {
+                msg!("Source is not a loader-v3 buffer or program data account");
+                Err(ProgramError::InvalidAccountData)
+            }
+        }
+    } else {
+        Ok(0..source_data.len())
+    }
+}
+
+/// Processes a
+/// [Copy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_copy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    destination_offset: u32,
+    source_offset: u32,
+    length: u32,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+
+    let state =
+        check_program_account_or_writer(program_id, program_info, authority_info, accounts)?;
+
+    if !matches!(state.status, LoaderV4Status::Retracted) {
+        msg!("Program is not retracted");
+        return Err(LoaderV4Error::ProgramNotRetracted.into());
+    }
+
+    let source_range = source_program_data_range(program_id, source_info)?;
+    let source_start = source_range.start.saturating_add(source_offset as usize);
+    let source_end = source_start.saturating_add(length as usize);
+    let layout = extension::layout_version(&program_info.try_borrow_data()?);
+    let destination_range = extension::program_data_range(&program_info.try_borrow_data()?)?;
+    let destination_start = destination_range
+        .start
+        .saturating_add(destination_offset as usize);
+    let destination_end = destination_start.saturating_add(length as usize);
+
+    if source_end > source_range.end {
+        msg!("Read out of bounds");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if destination_end > destination_range.end {
+        msg!("Write out of bounds");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if source_info.key == program_info.key {
+        let mut program_data = program_info.try_borrow_mut_data()?;
+        program_data.copy_within(source_start..source_end, destination_start);
+    } else {
+        let source_data = source_info.try_borrow_data()?;
+        program_info.try_borrow_mut_data()?[destination_start..destination_end]
+            .copy_from_slice(&source_data[source_start..source_end]);
+    }
+    check_not_forged_trailer(layout, &program_info.try_borrow_data()?)?;
+
+    emit_event(
+        LoaderV4EventKind::Copy,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
     Ok(())
 }
 
@@ -112,14 +400,14 @@ fn process_truncate(program_id: &Pubkey, accounts: &[AccountInfo], new_size: u32
     let is_initialization =
         new_size > 0 && program_info.data_len() < LoaderV4State::program_data_offset();
 
-    if is_initialization {
+    let old_state = if is_initialization {
         if program_info.owner != program_id {
             msg!("Program not owned by loader");
             return Err(ProgramError::InvalidAccountOwner);
         }
         if !program_info.is_writable {
             msg!("Program is not writeable");
-            return Err(ProgramError::InvalidArgument);
+            return Err(LoaderV4Error::ProgramNotWritable.into());
         }
         if !program_info.is_signer {
             msg!("Program did not sign");
@@ -129,34 +417,62 @@ fn process_truncate(program_id: &Pubkey, accounts: &[AccountInfo], new_size: u32
             msg!("Authority did not sign");
             return Err(ProgramError::MissingRequiredSignature);
         }
+        None
     } else {
-        let state = check_program_account(program_id, program_info, authority_info)?;
+        let state =
+            check_program_account_or_writer(program_id, program_info, authority_info, accounts)?;
         if !matches!(state.status, LoaderV4Status::Retracted) {
             msg!("Program is not retracted");
-            return Err(ProgramError::InvalidArgument);
+            return Err(LoaderV4Error::ProgramNotRetracted.into());
         }
+        Some(state)
+    };
+    // The writer signs alone, without multisig signers, and can neither
+    // close the program nor withdraw its lamports.
+    let signed_by_writer =
+        !is_initialization && is_writer(&program_info.try_borrow_data()?, authority_info);
+    if signed_by_writer && new_size == 0 {
+        msg!("Only the authority can close the program");
+        return Err(ProgramError::IncorrectAuthority);
     }
+    let destination_info = if is_initialization || signed_by_writer {
+        accounts.get(2)
+    } else {
+        without_multisig_signers(program_info, accounts)?.get(2)
+    };
 
+    // The header and the extension trailer, if any, keep their size.
+    let new_len = if is_initialization {
+        LoaderV4State::program_data_offset()
+    } else {
+        let program_data = program_info.try_borrow_data()?;
+        let range = extension::program_data_range(&program_data)?;
+        program_data.len().saturating_sub(range.len())
+    }
+    .saturating_add(new_size as usize);
     let required_lamports = if new_size == 0 {
         0
     } else {
         let rent = <Rent as Sysvar>::get()?;
-        rent.minimum_balance(LoaderV4State::program_data_offset().saturating_add(new_size as usize))
-            .max(1)
+        rent.minimum_balance(new_len).max(1)
     };
 
+    let lamports_to_receive = program_info.lamports().saturating_sub(required_lamports);
     match program_info.lamports().cmp(&required_lamports) {
         std::cmp::Ordering::Less => {
             msg!("Insufficient lamports, {} are required.", required_lamports);
             return Err(ProgramError::InsufficientFunds);
         }
         std::cmp::Ordering::Greater => {
-            let destination_info = next_account_info(accounts_iter)?;
+            if signed_by_writer {
+                msg!("Only the authority can withdraw lamports");
+                return Err(ProgramError::IncorrectAuthority);
+            }
+            let destination_info = destination_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
             if !destination_info.is_writable {
                 msg!("Recipient is not writeable");
-                return Err(ProgramError::InvalidArgument);
+                return Err(LoaderV4Error::DestinationNotWritable.into());
             }
-            let lamports_to_receive = program_info.lamports().saturating_sub(required_lamports);
             let new_destination_lamports = destination_info
                 .lamports()
                 .saturating_add(lamports_to_receive);
@@ -166,36 +482,52 @@ fn process_truncate(program_id: &Pubkey, accounts: &[AccountInfo], new_size: u32
         std::cmp::Ordering::Equal => {}
     }
 
-    if new_size == 0 {
+    let new_state = if new_size == 0 {
         program_info.realloc(0, true)?;
+        None
     } else {
-        program_info.realloc(
-            LoaderV4State::program_data_offset().saturating_add(new_size as usize),
-            true,
-        )?;
         if is_initialization {
-            let mut data = program_info.try_borrow_mut_data()?;
-            let state = LoaderV4State::unpack_mut(&mut data)?;
-            state.slot = 0;
-            state.status = LoaderV4Status::Retracted;
-            state.authority_address_or_next_version = *authority_info.key;
+            program_info.realloc(new_len, true)?;
+            let state = LoaderV4State {
+                slot: 0,
+                authority_address_or_next_version: *authority_info.key,
+                status: LoaderV4Status::Retracted,
+            };
+            state.pack(&mut program_info.try_borrow_mut_data()?)?;
+            Some(state)
+        } else {
+            let layout = extension::layout_version(&program_info.try_borrow_data()?);
+            extension::resize_program_data(program_info, new_size as usize)?;
+            check_not_forged_trailer(layout, &program_info.try_borrow_data()?)?;
+            old_state
         }
-    }
+    };
 
+    emit_event(
+        LoaderV4EventKind::Truncate,
+        program_info,
+        old_state,
+        new_state,
+        lamports_to_receive,
+    );
     Ok(())
 }
 
 /// Processes a
 /// [Deploy](enum.LoaderV4Instruction.html)
 /// instruction.
-fn process_deploy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_deploy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    expected_hash: Option<[u8; 32]>,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let program_info = next_account_info(accounts_iter)?;
     let authority_info = next_account_info(accounts_iter)?;
-    let source_info = next_account_info(accounts_iter).ok();
 
-    let state = check_program_account(program_id, program_info, authority_info)?;
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+    let source_info = without_multisig_signers(program_info, accounts)?.get(2);
 
     let current_slot = <Clock as Sysvar>::get()?.slot;
 
@@ -206,67 +538,147 @@ fn process_deploy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResul
     // which is < DEPLOYMENT_COOLDOWN_IN_SLOTS).
     if state.slot != 0 && state.slot.saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS) > current_slot {
         msg!("Program was deployed recently, cooldown still in effect");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::DeploymentCooldown.into());
     }
 
     if !matches!(state.status, LoaderV4Status::Retracted) {
         msg!("Destination program is not retracted");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::ProgramNotRetracted.into());
     }
 
-    let buffer_info = if let Some(ref source_program) = source_info {
-        let source_state = check_program_account(program_id, source_program, authority_info)?;
+    // The authority signed for the program above, possibly through its
+    // multisig, so the source only has to share it.
+    if let Some(source_program) = source_info {
+        if source_program.owner != program_id {
+            msg!("Source program not owned by loader");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let source_state = LoaderV4State::unpack(&source_program.try_borrow_data()?)?;
+        if !source_program.is_writable {
+            msg!("Source program is not writeable");
+            return Err(LoaderV4Error::ProgramNotWritable.into());
+        }
+        if source_state.authority_address_or_next_version != state.authority_address_or_next_version
+        {
+            msg!("Source program has a different authority");
+            return Err(ProgramError::IncorrectAuthority);
+        }
+        if matches!(source_state.status, LoaderV4Status::Finalized) {
+            msg!("Source program is finalized");
+            return Err(ProgramError::Immutable);
+        }
         if !matches!(source_state.status, LoaderV4Status::Retracted) {
             msg!("Source program is not retracted");
-            return Err(ProgramError::InvalidArgument);
+            return Err(LoaderV4Error::SourceProgramNotRetracted.into());
         }
-        source_program
-    } else {
-        &program_info
-    };
+    }
+
+    deploy(
+        program_info,
+        source_info,
+        state,
+        expected_hash,
+        current_slot,
+        if expected_hash.is_some() {
+            LoaderV4EventKind::DeployWithHash
+        } else {
+            LoaderV4EventKind::Deploy
+        },
+    )
+}
+
+/// Verifies the program data and marks the program as deployed, after moving
+/// the program data of the source program into it if there is one.
+fn deploy<'a>(
+    program_info: &AccountInfo<'a>,
+    source_info: Option<&AccountInfo<'a>>,
+    mut state: LoaderV4State,
+    expected_hash: Option<[u8; 32]>,
+    current_slot: Slot,
+    kind: LoaderV4EventKind,
+) -> ProgramResult {
+    // Only `ExecuteScheduledDeploy`, which removes the schedule first, can
+    // deploy a program with a scheduled deployment.
+    if extension::get_extension(
+        &program_info.try_borrow_data()?,
+        ExtensionType::ScheduledDeploy,
+    )?
+    .is_some()
+    {
+        msg!("Program has a scheduled deployment");
+        return Err(LoaderV4Error::DeployScheduled.into());
+    }
 
-    let _programdata = buffer_info
-        .try_borrow_data()?
-        .get(LoaderV4State::program_data_offset()..)
-        .ok_or(ProgramError::AccountDataTooSmall)?;
+    let buffer_info = source_info.unwrap_or(program_info);
+
+    {
+        let buffer_data = buffer_info.try_borrow_data()?;
+        let programdata = &buffer_data[extension::program_data_range(&buffer_data)?];
+        // A program of the legacy layout would mistake such program data of
+        // a source for an extension trailer, which the writer of the source
+        // could have forged.
+        if source_info.is_some() && programdata.ends_with(&extension::EXTENSIONS_MAGIC) {
+            msg!("Program data ends with the extensions magic");
+            return Err(LoaderV4Error::InvalidElf.into());
+        }
+        if let Some(expected_hash) = expected_hash {
+            if hash(programdata).to_bytes() != expected_hash {
+                msg!("Program data does not match the expected hash");
+                return Err(LoaderV4Error::ProgramDataHashMismatch.into());
+            }
+        }
+        elf::verify(programdata).map_err(|err| {
+            msg!("Invalid ELF: {}", err);
+            LoaderV4Error::InvalidElf
+        })?;
+    }
 
     let deployment_slot = state.slot;
     let _effective_slot = deployment_slot.saturating_add(DELAY_VISIBILITY_SLOT_OFFSET);
 
-    // [CORE BPF]: We'll see what happens with on-chain verification...
-    // Something like this would be nice:
-    // invoke(
-    //     &solana_bpf_verify_program::instruction::verify(buffer_info.key),
-    //     &[buffer_info.clone()],
-    // )?;
+    let transfer_lamports = if let Some(source_info) = source_info {
+        // The program keeps its own header and extensions.
+        let source_range = extension::program_data_range(&source_info.try_borrow_data()?)?;
+        let new_len = program_info
+            .data_len()
+            .saturating_sub(extension::program_data_range(&program_info.try_borrow_data()?)?.len())
+            .saturating_add(source_range.len());
 
-    if let Some(source_info) = source_info {
         let rent = <Rent as Sysvar>::get()?;
-        let required_lamports = rent.minimum_balance(source_info.data_len());
+        let required_lamports = rent.minimum_balance(new_len);
         let transfer_lamports = required_lamports.saturating_sub(program_info.lamports());
         let new_program_lamports = program_info.lamports().saturating_add(transfer_lamports);
         let new_source_lamports = source_info.lamports().saturating_sub(transfer_lamports);
 
+        extension::resize_program_data(program_info, source_range.len())?;
         {
-            if program_info.data_len() < source_info.data_len() {
-                program_info.realloc(source_info.data_len(), true)?;
-            }
             let mut program_data = program_info.try_borrow_mut_data()?;
-            let source_data = source_info.try_borrow_mut_data()?;
-            program_data[..].copy_from_slice(&source_data[..]);
+            let program_range = extension::program_data_range(&program_data)?;
+            program_data[program_range]
+                .copy_from_slice(&source_info.try_borrow_data()?[source_range]);
         }
         source_info.realloc(0, true)?;
 
         **program_info.try_borrow_mut_lamports()? = new_program_lamports;
         **source_info.try_borrow_mut_lamports()? = new_source_lamports;
-    }
-    let mut data = program_info.try_borrow_mut_data()?;
-    let state = LoaderV4State::unpack_mut(&mut data)?;
+        transfer_lamports
+    } else {
+        0
+    };
+    let old_state = state;
     state.slot = current_slot;
     state.status = LoaderV4Status::Deployed;
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
 
     // [CORE BPF]: Store modified entry in program cache.
 
+    emit_event(
+        kind,
+        program_info,
+        Some(old_state),
+        Some(state),
+        transfer_lamports,
+    );
     Ok(())
 }
 
@@ -279,26 +691,33 @@ fn process_retract(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResu
     let program_info = next_account_info(accounts_iter)?;
     let authority_info = next_account_info(accounts_iter)?;
 
-    let state = check_program_account(program_id, program_info, authority_info)?;
+    let mut state = check_program_account(program_id, program_info, authority_info, accounts)?;
 
     let current_slot = <Clock as Sysvar>::get()?.slot;
 
     if state.slot.saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS) > current_slot {
         msg!("Program was deployed recently, cooldown still in effect");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::DeploymentCooldown.into());
     }
 
     if !matches!(state.status, LoaderV4Status::Deployed) {
         msg!("Program is not deployed");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::ProgramNotDeployed.into());
     }
 
-    let mut data = program_info.try_borrow_mut_data()?;
-    let state = LoaderV4State::unpack_mut(&mut data)?;
+    let old_state = state;
     state.status = LoaderV4Status::Retracted;
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
 
     // [CORE BPF]: Store modified entry in program cache.
 
+    emit_event(
+        LoaderV4EventKind::Retract,
+        program_info,
+        Some(old_state),
+        Some(state),
+        0,
+    );
     Ok(())
 }
 
@@ -312,7 +731,7 @@ fn process_transfer_authority(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     let old_authority_info = next_account_info(accounts_iter)?;
     let new_authority_info = next_account_info(accounts_iter)?;
 
-    let state = check_program_account(program_id, program_info, old_authority_info)?;
+    let mut state = check_program_account(program_id, program_info, old_authority_info, accounts)?;
 
     if !new_authority_info.is_signer {
         msg!("New authority did not sign");
@@ -321,13 +740,24 @@ fn process_transfer_authority(program_id: &Pubkey, accounts: &[AccountInfo]) ->
 
     if state.authority_address_or_next_version == *new_authority_info.key {
         msg!("No change");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::AuthorityUnchanged.into());
     }
 
-    let mut data = program_info.try_borrow_mut_data()?;
-    let state = LoaderV4State::unpack_mut(&mut data)?;
+    let old_state = state;
     state.authority_address_or_next_version = *new_authority_info.key;
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
+    extension::remove_extension(program_info, ExtensionType::PendingAuthority)?;
+    extension::remove_extension(program_info, ExtensionType::Multisig)?;
+    extension::remove_extension(program_info, ExtensionType::Writer)?;
+    extension::remove_extension(program_info, ExtensionType::ScheduledDeploy)?;
 
+    emit_event(
+        LoaderV4EventKind::TransferAuthority,
+        program_info,
+        Some(old_state),
+        Some(state),
+        0,
+    );
     Ok(())
 }
 
@@ -341,11 +771,11 @@ fn process_finalize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramRes
     let authority_info = next_account_info(accounts_iter)?;
     let next_version_info = next_account_info(accounts_iter)?;
 
-    let state = check_program_account(program_id, program_info, authority_info)?;
+    let mut state = check_program_account(program_id, program_info, authority_info, accounts)?;
 
     if !matches!(state.status, LoaderV4Status::Deployed) {
         msg!("Program must be deployed to be finalized");
-        return Err(ProgramError::InvalidArgument);
+        return Err(LoaderV4Error::ProgramNotDeployed.into());
     }
 
     if next_version_info.owner != program_id {
@@ -353,8 +783,7 @@ fn process_finalize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramRes
         return Err(ProgramError::InvalidAccountOwner);
     }
 
-    let next_version_data = next_version_info.try_borrow_data()?;
-    let next_version_state = LoaderV4State::unpack(&next_version_data)?;
+    let next_version_state = LoaderV4State::unpack(&next_version_info.try_borrow_data()?)?;
 
     if next_version_state.authority_address_or_next_version != *authority_info.key {
         msg!("Next version has a different authority");
@@ -365,41 +794,1283 @@ fn process_finalize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramRes
         return Err(ProgramError::Immutable);
     }
 
-    let mut data = program_info.try_borrow_mut_data()?;
-    let state = LoaderV4State::unpack_mut(&mut data)?;
+    let old_state = state;
     state.authority_address_or_next_version = *next_version_info.key;
     state.status = LoaderV4Status::Finalized;
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
 
+    emit_event(
+        LoaderV4EventKind::Finalize,
+        program_info,
+        Some(old_state),
+        Some(state),
+        0,
+    );
     Ok(())
 }
 
 /// Processes a
-/// [LoaderV4Instruction](enum.LoaderV4Instruction.html).
-pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
-    match limited_deserialize(input)? {
-        LoaderV4Instruction::Write { offset, bytes } => {
-            msg!("Instruction: Write");
-            process_write(program_id, accounts, offset, bytes)
+/// [Migrate](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_migrate(program_id: &Pubkey, accounts: &[AccountInfo], deploy: bool) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let v3_program_info = next_account_info(accounts_iter)?;
+    let v3_program_data_info = next_account_info(accounts_iter)?;
+
+    if program_info.owner != program_id {
+        msg!("Program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !program_info.is_writable {
+        msg!("Program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+    if !program_info.is_signer {
+        msg!("Program did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !authority_info.is_signer {
+        msg!("Authority did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    {
+        // A zeroed header can not belong to an initialized program, since its
+        // authority would have to be the default address.
+        let program_data = program_info.try_borrow_data()?;
+        if program_data
+            .get(..LoaderV4State::program_data_offset())
+            .is_some_and(|header| header.iter().any(|byte| *byte != 0))
+        {
+            msg!("Program is already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
         }
-        LoaderV4Instruction::Truncate { new_size } => {
-            msg!("Instruction: Truncate");
-            process_truncate(program_id, accounts, new_size)
+    }
+
+    if v3_program_info.owner != &bpf_loader_upgradeable::id() {
+        msg!("Loader-v3 program not owned by loader-v3");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    match bincode::deserialize(&v3_program_info.try_borrow_data()?) {
+        Ok(UpgradeableLoaderState::Program {
+            programdata_address,
+        }) if programdata_address == *v3_program_data_info.key => {}
+        _ => {
+            msg!("Invalid loader-v3 program account");
+            return Err(ProgramError::InvalidAccountData);
         }
-        LoaderV4Instruction::Deploy => {
-            msg!("Instruction: Deploy");
-            process_deploy(program_id, accounts)
+    }
+
+    if v3_program_data_info.owner != &bpf_loader_upgradeable::id() {
+        msg!("Loader-v3 program data not owned by loader-v3");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let v3_program_data = v3_program_data_info.try_borrow_data()?;
+    let upgrade_authority_address = match bincode::deserialize(&v3_program_data) {
+        Ok(UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        }) => upgrade_authority_address,
+        _ => {
+            msg!("Invalid loader-v3 program data account");
+            return Err(ProgramError::InvalidAccountData);
         }
-        LoaderV4Instruction::Retract => {
-            msg!("Instruction: Retract");
-            process_retract(program_id, accounts)
+    };
+    let elf = v3_program_data
+        .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    match upgrade_authority_address {
+        None => {
+            msg!("Loader-v3 program is immutable");
+            return Err(ProgramError::Immutable);
         }
-        LoaderV4Instruction::TransferAuthority => {
-            msg!("Instruction: TransferAuthority");
-            process_transfer_authority(program_id, accounts)
+        Some(address) if address != *authority_info.key => {
+            msg!("Incorrect authority provided");
+            return Err(ProgramError::IncorrectAuthority);
         }
-        LoaderV4Instruction::Finalize => {
-            msg!("Instruction: Finalize");
-            process_finalize(program_id, accounts)
+        Some(_) => {}
+    }
+
+    if elf.ends_with(&extension::EXTENSIONS_MAGIC) {
+        msg!("Program data ends with the extensions magic");
+        return Err(LoaderV4Error::InvalidElf.into());
+    }
+
+    let new_len = LoaderV4State::program_data_offset().saturating_add(elf.len());
+    let required_lamports = <Rent as Sysvar>::get()?.minimum_balance(new_len).max(1);
+    if program_info.lamports() < required_lamports {
+        msg!("Insufficient lamports, {} are required.", required_lamports);
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let state = if deploy {
+        elf::verify(elf).map_err(|err| {
+            msg!("Invalid ELF: {}", err);
+            LoaderV4Error::InvalidElf
+        })?;
+        LoaderV4State {
+            slot: <Clock as Sysvar>::get()?.slot,
+            authority_address_or_next_version: *authority_info.key,
+            status: LoaderV4Status::Deployed,
+        }
+    } else {
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: *authority_info.key,
+            status: LoaderV4Status::Retracted,
+        }
+    };
+
+    program_info.realloc(new_len, true)?;
+    {
+        let mut program_data = program_info.try_borrow_mut_data()?;
+        state.pack(&mut program_data)?;
+        program_data[LoaderV4State::program_data_offset()..].copy_from_slice(elf);
+    }
+
+    // [CORE BPF]: Store modified entry in program cache.
+
+    emit_event(
+        LoaderV4EventKind::Migrate,
+        program_info,
+        None,
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [Hash](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_hash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    offset: u32,
+    length: u32,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+
+    if program_info.owner != program_id {
+        msg!("Program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let program_data = program_info.try_borrow_data()?;
+    let end = (offset as usize).saturating_add(length as usize);
+    let bytes = program_data[extension::program_data_range(&program_data)?]
+        .get(offset as usize..end)
+        .ok_or_else(|| {
+            msg!("Read out of bounds");
+            ProgramError::AccountDataTooSmall
+        })?;
+
+    set_return_data(hash(bytes).as_ref());
+    Ok(())
+}
+
+/// Processes a
+/// [ProposeAuthority](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_propose_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let current_authority_info = next_account_info(accounts_iter)?;
+    let proposed_authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, current_authority_info, accounts)?;
+
+    if state.authority_address_or_next_version == *proposed_authority_info.key {
+        msg!("No change");
+        return Err(LoaderV4Error::AuthorityUnchanged.into());
+    }
+
+    extension::set_extension(
+        program_info,
+        ExtensionType::PendingAuthority,
+        proposed_authority_info.key.as_ref(),
+    )?;
+    check_rent_exemption(program_info)?;
+
+    emit_event(
+        LoaderV4EventKind::ProposeAuthority,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes an
+/// [AcceptAuthority](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_accept_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let proposed_authority_info = next_account_info(accounts_iter)?;
+
+    if program_info.owner != program_id {
+        msg!("Program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let mut state = LoaderV4State::unpack(&program_info.try_borrow_data()?)?;
+    if !program_info.is_writable {
+        msg!("Program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+    if !proposed_authority_info.is_signer {
+        msg!("Proposed authority did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if matches!(state.status, LoaderV4Status::Finalized) {
+        msg!("Program is finalized");
+        return Err(ProgramError::Immutable);
+    }
+    {
+        let program_data = program_info.try_borrow_data()?;
+        let Some(pending_authority) =
+            extension::get_extension(&program_data, ExtensionType::PendingAuthority)?
+        else {
+            msg!("No pending authority");
+            return Err(LoaderV4Error::NoPendingAuthority.into());
+        };
+        if pending_authority != proposed_authority_info.key.as_ref() {
+            msg!("Incorrect authority provided");
+            return Err(ProgramError::IncorrectAuthority);
+        }
+    }
+
+    let old_state = state;
+    state.authority_address_or_next_version = *proposed_authority_info.key;
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
+    extension::remove_extension(program_info, ExtensionType::PendingAuthority)?;
+    extension::remove_extension(program_info, ExtensionType::Multisig)?;
+    extension::remove_extension(program_info, ExtensionType::Writer)?;
+    extension::remove_extension(program_info, ExtensionType::ScheduledDeploy)?;
+
+    emit_event(
+        LoaderV4EventKind::AcceptAuthority,
+        program_info,
+        Some(old_state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [CancelAuthorityProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_cancel_authority_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let current_authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, current_authority_info, accounts)?;
+
+    if !extension::remove_extension(program_info, ExtensionType::PendingAuthority)? {
+        msg!("No pending authority");
+        return Err(LoaderV4Error::NoPendingAuthority.into());
+    }
+
+    emit_event(
+        LoaderV4EventKind::CancelAuthorityProposal,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [ScheduleDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_schedule_deploy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    not_before_slot: Slot,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if not_before_slot <= <Clock as Sysvar>::get()?.slot {
+        msg!("Scheduled deployment must be in a future slot");
+        return Err(LoaderV4Error::ScheduledDeployNotInFuture.into());
+    }
+    if source_info.key == program_info.key {
+        msg!("Source program must differ from the program");
+        return Err(LoaderV4Error::SourceIsProgram.into());
+    }
+    let hash = {
+        let source_data = source_info.try_borrow_data()?;
+        check_source_program(program_id, source_info, &source_data, &state)?;
+        hash(&source_data[extension::program_data_range(&source_data)?]).to_bytes()
+    };
+
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: *source_info.key,
+        not_before_slot,
+        hash,
+    };
+    extension::set_extension(
+        program_info,
+        ExtensionType::ScheduledDeploy,
+        &scheduled_deploy.pack(),
+    )?;
+    check_rent_exemption(program_info)?;
+
+    emit_event(
+        LoaderV4EventKind::ScheduleDeploy,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Checks that a source program of a scheduled deployment is retracted and
+/// under the authority of the program.
+fn check_source_program(
+    program_id: &Pubkey,
+    source_info: &AccountInfo,
+    source_data: &[u8],
+    state: &LoaderV4State,
+) -> ProgramResult {
+    if source_info.owner != program_id {
+        msg!("Source program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let source_state = LoaderV4State::unpack(source_data)?;
+    if source_state.authority_address_or_next_version != state.authority_address_or_next_version {
+        msg!("Source program has a different authority");
+        return Err(ProgramError::IncorrectAuthority);
+    }
+    if !matches!(source_state.status, LoaderV4Status::Retracted) {
+        msg!("Source program is not retracted");
+        return Err(LoaderV4Error::SourceProgramNotRetracted.into());
+    }
+    Ok(())
+}
+
+/// Processes an
+/// [ExecuteScheduledDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_execute_scheduled_deploy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+
+    if program_info.owner != program_id {
+        msg!("Program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let state = LoaderV4State::unpack(&program_info.try_borrow_data()?)?;
+    if !program_info.is_writable {
+        msg!("Program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+    if matches!(state.status, LoaderV4Status::Finalized) {
+        msg!("Program is finalized");
+        return Err(ProgramError::Immutable);
+    }
+    let scheduled_deploy = {
+        let program_data = program_info.try_borrow_data()?;
+        let Some(scheduled_deploy) =
+            extension::get_extension(&program_data, ExtensionType::ScheduledDeploy)?
+        else {
+            msg!("No scheduled deployment");
+            return Err(LoaderV4Error::NoScheduledDeploy.into());
+        };
+        ScheduledDeploy::unpack(scheduled_deploy)?
+    };
+
+    if *source_info.key != scheduled_deploy.source_address {
+        msg!("Incorrect source program provided");
+        return Err(LoaderV4Error::IncorrectSourceProgram.into());
+    }
+    check_source_program(
+        program_id,
+        source_info,
+        &source_info.try_borrow_data()?,
+        &state,
+    )?;
+    if !source_info.is_writable {
+        msg!("Source program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+
+    let current_slot = <Clock as Sysvar>::get()?.slot;
+
+    if current_slot < scheduled_deploy.not_before_slot {
+        msg!(
+            "Scheduled deployment is not due until slot {}",
+            scheduled_deploy.not_before_slot
+        );
+        return Err(LoaderV4Error::ScheduledDeployNotDue.into());
+    }
+
+    // Same as `Retract` followed by `Deploy`.
+    if state.slot != 0 && state.slot.saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS) > current_slot {
+        msg!("Program was deployed recently, cooldown still in effect");
+        return Err(LoaderV4Error::DeploymentCooldown.into());
+    }
+
+    extension::remove_extension(program_info, ExtensionType::ScheduledDeploy)?;
+
+    deploy(
+        program_info,
+        Some(source_info),
+        state,
+        Some(scheduled_deploy.hash),
+        current_slot,
+        LoaderV4EventKind::ExecuteScheduledDeploy,
+    )
+}
+
+/// Processes a
+/// [CancelScheduledDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_cancel_scheduled_deploy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if !extension::remove_extension(program_info, ExtensionType::ScheduledDeploy)? {
+        msg!("No scheduled deployment");
+        return Err(LoaderV4Error::NoScheduledDeploy.into());
+    }
+
+    emit_event(
+        LoaderV4EventKind::CancelScheduledDeploy,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [Close](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_close(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if destination_info.key == program_info.key {
+        msg!("Destination must differ from the program");
+        return Err(LoaderV4Error::DestinationIsProgram.into());
+    }
+    if !destination_info.is_writable {
+        msg!("Recipient is not writeable");
+        return Err(LoaderV4Error::DestinationNotWritable.into());
+    }
+
+    // Same as `Retract` followed by `Truncate` to size zero.
+    if matches!(state.status, LoaderV4Status::Deployed) {
+        let current_slot = <Clock as Sysvar>::get()?.slot;
+        if state.slot.saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS) > current_slot {
+            msg!("Program was deployed recently, cooldown still in effect");
+            return Err(LoaderV4Error::DeploymentCooldown.into());
+        }
+    }
+
+    let lamports_to_receive = program_info.lamports();
+    let new_destination_lamports = destination_info
+        .lamports()
+        .saturating_add(lamports_to_receive);
+    **program_info.try_borrow_mut_lamports()? = 0;
+    **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+    program_info.realloc(0, true)?;
+
+    // [CORE BPF]: Store modified entry in program cache.
+
+    emit_event(
+        LoaderV4EventKind::Close,
+        program_info,
+        Some(state),
+        None,
+        lamports_to_receive,
+    );
+    Ok(())
+}
+
+/// Funds, allocates and assigns a new account to the program.
+///
+/// Unlike the system program's `CreateAccount`, this does not fail if the
+/// account was funded beforehand: only the lamports missing for rent
+/// exemption are transferred from the payer. Returns the transferred
+/// lamports.
+fn create_account<'a>(
+    program_id: &Pubkey,
+    account_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64, ProgramError> {
+    let required_lamports = <Rent as Sysvar>::get()?
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke_signed(
+            &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+            &[
+                payer_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, space as u64),
+        &[account_info.clone(), system_program_info.clone()],
+        signer_seeds,
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account_info.key, program_id),
+        &[account_info.clone(), system_program_info.clone()],
+        signer_seeds,
+    )?;
+    Ok(required_lamports)
+}
+
+/// Creates the program account through the system program, funded by the
+/// payer, and initializes it as retracted with the given authority.
+///
+/// `signer_seeds` are those of the program account if it is a PDA, which
+/// then does not need to sign the instruction.
+#[allow(clippy::too_many_arguments)]
+fn create_program_account<'a>(
+    program_id: &Pubkey,
+    program_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    size: u32,
+    signer_seeds: &[&[&[u8]]],
+    kind: LoaderV4EventKind,
+) -> ProgramResult {
+    if !authority_info.is_signer {
+        msg!("Authority did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !payer_info.is_signer {
+        msg!("Payer did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !system_program::check_id(system_program_info.key) {
+        msg!("Invalid system program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // The system program allocates the account through a cross-program
+    // invocation, which can grow it by at most `MAX_PERMITTED_DATA_INCREASE`.
+    let new_len = LoaderV4State::program_data_offset().saturating_add(size as usize);
+    if new_len > MAX_PERMITTED_DATA_INCREASE {
+        msg!(
+            "Program account must not exceed {} bytes when created",
+            MAX_PERMITTED_DATA_INCREASE
+        );
+        return Err(LoaderV4Error::CreateSizeTooLarge.into());
+    }
+    let lamports = create_account(
+        program_id,
+        program_info,
+        payer_info,
+        system_program_info,
+        new_len,
+        signer_seeds,
+    )?;
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: *authority_info.key,
+        status: LoaderV4Status::Retracted,
+    };
+    state.pack(&mut program_info.try_borrow_mut_data()?)?;
+
+    emit_event(kind, program_info, None, Some(state), lamports);
+    Ok(())
+}
+
+/// Processes a
+/// [Create](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_create(program_id: &Pubkey, accounts: &[AccountInfo], size: u32) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let system_program_info = next_account_info(accounts_iter)?;
+
+    if !program_info.is_signer {
+        msg!("Program did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    create_program_account(
+        program_id,
+        program_info,
+        authority_info,
+        payer_info,
+        system_program_info,
+        size,
+        &[],
+        LoaderV4EventKind::Create,
+    )
+}
+
+/// Processes a
+/// [CreateWithSeed](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_create_with_seed(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    seed: &[u8],
+    size: u32,
+    bump_seed: u8,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let base_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let system_program_info = next_account_info(accounts_iter)?;
+
+    if !base_info.is_signer {
+        msg!("Base did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if seed.len() > MAX_SEED_LEN {
+        msg!("Seed is too long");
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+    let program_address = Pubkey::create_program_address(
+        &[
+            PROGRAM_SEED_PREFIX,
+            base_info.key.as_ref(),
+            seed,
+            &[bump_seed],
+        ],
+        program_id,
+    )
+    .inspect_err(|_| msg!("Invalid bump seed"))?;
+    if program_address != *program_info.key {
+        msg!("Program address does not match the base and seed");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account(
+        program_id,
+        program_info,
+        authority_info,
+        payer_info,
+        system_program_info,
+        size,
+        &[&[
+            PROGRAM_SEED_PREFIX,
+            base_info.key.as_ref(),
+            seed,
+            &[bump_seed],
+        ]],
+        LoaderV4EventKind::CreateWithSeed,
+    )
+}
+
+/// Processes a
+/// [SetMultisig](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_set_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    threshold: u8,
+    signers: Vec<Pubkey>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    let multisig = Multisig { threshold, signers };
+    multisig
+        .validate()
+        .inspect_err(|_| msg!("Invalid multisig"))?;
+
+    extension::set_extension(program_info, ExtensionType::Multisig, &multisig.pack())?;
+    check_rent_exemption(program_info)?;
+
+    emit_event(
+        LoaderV4EventKind::SetMultisig,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [RemoveMultisig](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_remove_multisig(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if !extension::remove_extension(program_info, ExtensionType::Multisig)? {
+        msg!("No multisig");
+        return Err(LoaderV4Error::NoMultisig.into());
+    }
+
+    emit_event(
+        LoaderV4EventKind::RemoveMultisig,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Returns the members of the authority of a program and how many of them
+/// must approve a proposal: the signers of its multisig if it has one, the
+/// authority alone otherwise.
+fn authority_members(
+    program_data: &[u8],
+    state: &LoaderV4State,
+) -> Result<(Vec<Pubkey>, usize), ProgramError> {
+    match extension::get_extension(program_data, ExtensionType::Multisig)? {
+        Some(multisig) => {
+            let multisig = Multisig::unpack(multisig)?;
+            Ok((multisig.signers, multisig.threshold as usize))
+        }
+        None => Ok((vec![state.authority_address_or_next_version], 1)),
+    }
+}
+
+/// Checks that a program account is owned by the loader and not finalized,
+/// without requiring its authority, and returns its state along with the
+/// members of its authority and their threshold.
+fn check_proposed_program(
+    program_id: &Pubkey,
+    program_info: &AccountInfo,
+) -> Result<(LoaderV4State, Vec<Pubkey>, usize), ProgramError> {
+    if program_info.owner != program_id {
+        msg!("Program not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let program_data = program_info.try_borrow_data()?;
+    let state = LoaderV4State::unpack(&program_data)?;
+    if matches!(state.status, LoaderV4Status::Finalized) {
+        msg!("Program is finalized");
+        return Err(ProgramError::Immutable);
+    }
+    let (members, threshold) = authority_members(&program_data, &state)?;
+    Ok((state, members, threshold))
+}
+
+/// Checks that a proposal account is the one of the program and returns the
+/// proposal.
+fn check_proposal(
+    program_id: &Pubkey,
+    program_info: &AccountInfo,
+    proposal_info: &AccountInfo,
+) -> Result<DeployProposal, ProgramError> {
+    if proposal_info.owner != program_id {
+        msg!("Proposal not owned by loader");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !proposal_info.is_writable {
+        msg!("Proposal is not writeable");
+        return Err(LoaderV4Error::ProposalNotWritable.into());
+    }
+    let proposal = DeployProposal::unpack(&proposal_info.try_borrow_data()?)?;
+    if proposal.program_address != *program_info.key {
+        msg!("Proposal is for another program");
+        return Err(LoaderV4Error::IncorrectProposal.into());
+    }
+    Ok(proposal)
+}
+
+/// Closes a proposal account, refunding its lamports to its payer.
+fn close_proposal(
+    proposal: &DeployProposal,
+    proposal_info: &AccountInfo,
+    payer_info: &AccountInfo,
+) -> ProgramResult {
+    if *payer_info.key != proposal.payer_address {
+        msg!("Incorrect payer provided");
+        return Err(LoaderV4Error::IncorrectPayer.into());
+    }
+    if !payer_info.is_writable {
+        msg!("Payer is not writeable");
+        return Err(LoaderV4Error::DestinationNotWritable.into());
+    }
+    let new_payer_lamports = payer_info
+        .lamports()
+        .checked_add(proposal_info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **payer_info.try_borrow_mut_lamports()? = new_payer_lamports;
+    **proposal_info.try_borrow_mut_lamports()? = 0;
+    proposal_info.realloc(0, true)
+}
+
+/// Processes a
+/// [ProposeDeploy](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_propose_deploy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+    let proposer_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let system_program_info = next_account_info(accounts_iter)?;
+
+    let (state, members, _) = check_proposed_program(program_id, program_info)?;
+
+    if !proposer_info.is_signer {
+        msg!("Proposer did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !members.contains(proposer_info.key) {
+        msg!("Proposer is not a member of the authority");
+        return Err(ProgramError::IncorrectAuthority);
+    }
+    if source_info.key == program_info.key {
+        msg!("Source program must differ from the program");
+        return Err(LoaderV4Error::SourceIsProgram.into());
+    }
+    let hash = {
+        let source_data = source_info.try_borrow_data()?;
+        check_source_program(program_id, source_info, &source_data, &state)?;
+        hash(&source_data[extension::program_data_range(&source_data)?]).to_bytes()
+    };
+
+    let (proposal_address, bump_seed) = Pubkey::find_program_address(
+        &[PROPOSAL_SEED_PREFIX, program_info.key.as_ref()],
+        program_id,
+    );
+    if proposal_address != *proposal_info.key {
+        msg!("Proposal address does not match the program");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !payer_info.is_signer {
+        msg!("Payer did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !system_program::check_id(system_program_info.key) {
+        msg!("Invalid system program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    create_account(
+        program_id,
+        proposal_info,
+        payer_info,
+        system_program_info,
+        DeployProposal::LEN,
+        &[&[
+            PROPOSAL_SEED_PREFIX,
+            program_info.key.as_ref(),
+            &[bump_seed],
+        ]],
+    )?;
+
+    let proposal = DeployProposal {
+        slot: <Clock as Sysvar>::get()?.slot,
+        program_address: *program_info.key,
+        source_address: *source_info.key,
+        hash,
+        payer_address: *payer_info.key,
+        approvals: vec![*proposer_info.key],
+    };
+    proposal.pack(&mut proposal_info.try_borrow_mut_data()?)?;
+
+    emit_event(
+        LoaderV4EventKind::ProposeDeploy,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes an
+/// [Approve](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_approve(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let approver_info = next_account_info(accounts_iter)?;
+
+    let (state, members, _) = check_proposed_program(program_id, program_info)?;
+    let mut proposal = check_proposal(program_id, program_info, proposal_info)?;
+
+    if !approver_info.is_signer {
+        msg!("Approver did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !members.contains(approver_info.key) {
+        msg!("Approver is not a member of the authority");
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    proposal
+        .approvals
+        .retain(|approval| members.contains(approval));
+    if proposal.approvals.contains(approver_info.key) {
+        msg!("Already approved");
+        return Err(LoaderV4Error::AlreadyApproved.into());
+    }
+    proposal.approvals.push(*approver_info.key);
+    proposal.pack(&mut proposal_info.try_borrow_mut_data()?)?;
+
+    emit_event(
+        LoaderV4EventKind::Approve,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes an
+/// [ExecuteProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_execute_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    let (state, members, threshold) = check_proposed_program(program_id, program_info)?;
+    if !program_info.is_writable {
+        msg!("Program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+    let proposal = check_proposal(program_id, program_info, proposal_info)?;
+
+    if *source_info.key != proposal.source_address {
+        msg!("Incorrect source program provided");
+        return Err(LoaderV4Error::IncorrectSourceProgram.into());
+    }
+    check_source_program(
+        program_id,
+        source_info,
+        &source_info.try_borrow_data()?,
+        &state,
+    )?;
+    if !source_info.is_writable {
+        msg!("Source program is not writeable");
+        return Err(LoaderV4Error::ProgramNotWritable.into());
+    }
+
+    // Only approvals of current members count.
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approval| members.contains(approval))
+        .count();
+    if approvals < threshold {
+        msg!("Proposal has {} of {} approvals", approvals, threshold);
+        return Err(LoaderV4Error::ProposalNotApproved.into());
+    }
+
+    let current_slot = <Clock as Sysvar>::get()?.slot;
+
+    // Same as `Retract` followed by `Deploy`.
+    if state.slot != 0 && state.slot.saturating_add(DEPLOYMENT_COOLDOWN_IN_SLOTS) > current_slot {
+        msg!("Program was deployed recently, cooldown still in effect");
+        return Err(LoaderV4Error::DeploymentCooldown.into());
+    }
+
+    close_proposal(&proposal, proposal_info, payer_info)?;
+
+    deploy(
+        program_info,
+        Some(source_info),
+        state,
+        Some(proposal.hash),
+        current_slot,
+        LoaderV4EventKind::ExecuteProposal,
+    )
+}
+
+/// Processes a
+/// [CancelProposal](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_cancel_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    // The proposal of a finalized or closed program can never be executed,
+    // so anyone can close it, which only refunds its payer.
+    let state = if program_info.owner == program_id && !program_info.data_is_empty() {
+        Some(LoaderV4State::unpack(&program_info.try_borrow_data()?)?)
+    } else {
+        None
+    };
+    let state = match state {
+        Some(state) if !matches!(state.status, LoaderV4Status::Finalized) => Some(
+            check_program_account(program_id, program_info, authority_info, accounts)?,
+        ),
+        state => state,
+    };
+    let proposal = check_proposal(program_id, program_info, proposal_info)?;
+
+    close_proposal(&proposal, proposal_info, payer_info)?;
+
+    emit_event(
+        LoaderV4EventKind::CancelProposal,
+        program_info,
+        state,
+        state,
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [SetWriter](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_set_writer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let writer_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if writer_info.key == program_info.key
+        || writer_info.key == &state.authority_address_or_next_version
+    {
+        msg!("Writer must differ from the program and its authority");
+        return Err(LoaderV4Error::InvalidWriter.into());
+    }
+    extension::set_extension(
+        program_info,
+        ExtensionType::Writer,
+        writer_info.key.as_ref(),
+    )?;
+    check_rent_exemption(program_info)?;
+
+    emit_event(
+        LoaderV4EventKind::SetWriter,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [RevokeWriter](enum.LoaderV4Instruction.html)
+/// instruction.
+fn process_revoke_writer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    let state = check_program_account(program_id, program_info, authority_info, accounts)?;
+
+    if !extension::remove_extension(program_info, ExtensionType::Writer)? {
+        msg!("No writer");
+        return Err(LoaderV4Error::NoWriter.into());
+    }
+
+    emit_event(
+        LoaderV4EventKind::RevokeWriter,
+        program_info,
+        Some(state),
+        Some(state),
+        0,
+    );
+    Ok(())
+}
+
+/// Processes a
+/// [LoaderV4Instruction](enum.LoaderV4Instruction.html).
+pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    match limited_deserialize(input)? {
+        LoaderV4Instruction::Write { offset, bytes } => {
+            msg!("Instruction: Write");
+            process_write(program_id, accounts, offset, bytes)
+        }
+        LoaderV4Instruction::Truncate { new_size } => {
+            msg!("Instruction: Truncate");
+            process_truncate(program_id, accounts, new_size)
+        }
+        LoaderV4Instruction::Deploy => {
+            msg!("Instruction: Deploy");
+            process_deploy(program_id, accounts, None)
+        }
+        LoaderV4Instruction::Retract => {
+            msg!("Instruction: Retract");
+            process_retract(program_id, accounts)
+        }
+        LoaderV4Instruction::TransferAuthority => {
+            msg!("Instruction: TransferAuthority");
+            process_transfer_authority(program_id, accounts)
+        }
+        LoaderV4Instruction::Finalize => {
+            msg!("Instruction: Finalize");
+            process_finalize(program_id, accounts)
+        }
+        LoaderV4Instruction::Migrate { deploy } => {
+            msg!("Instruction: Migrate");
+            process_migrate(program_id, accounts, deploy)
+        }
+        LoaderV4Instruction::Copy {
+            destination_offset,
+            source_offset,
+            length,
+        } => {
+            msg!("Instruction: Copy");
+            process_copy(
+                program_id,
+                accounts,
+                destination_offset,
+                source_offset,
+                length,
+            )
+        }
+        LoaderV4Instruction::WriteBatch { segments } => {
+            msg!("Instruction: WriteBatch");
+            process_write_batch(program_id, accounts, segments)
+        }
+        LoaderV4Instruction::DeployWithHash { expected_hash } => {
+            msg!("Instruction: DeployWithHash");
+            process_deploy(program_id, accounts, Some(expected_hash))
+        }
+        LoaderV4Instruction::Hash { offset, length } => {
+            msg!("Instruction: Hash");
+            process_hash(program_id, accounts, offset, length)
+        }
+        LoaderV4Instruction::ProposeAuthority => {
+            msg!("Instruction: ProposeAuthority");
+            process_propose_authority(program_id, accounts)
+        }
+        LoaderV4Instruction::AcceptAuthority => {
+            msg!("Instruction: AcceptAuthority");
+            process_accept_authority(program_id, accounts)
+        }
+        LoaderV4Instruction::CancelAuthorityProposal => {
+            msg!("Instruction: CancelAuthorityProposal");
+            process_cancel_authority_proposal(program_id, accounts)
+        }
+        LoaderV4Instruction::ScheduleDeploy { not_before_slot } => {
+            msg!("Instruction: ScheduleDeploy");
+            process_schedule_deploy(program_id, accounts, not_before_slot)
+        }
+        LoaderV4Instruction::ExecuteScheduledDeploy => {
+            msg!("Instruction: ExecuteScheduledDeploy");
+            process_execute_scheduled_deploy(program_id, accounts)
+        }
+        LoaderV4Instruction::CancelScheduledDeploy => {
+            msg!("Instruction: CancelScheduledDeploy");
+            process_cancel_scheduled_deploy(program_id, accounts)
+        }
+        LoaderV4Instruction::Close => {
+            msg!("Instruction: Close");
+            process_close(program_id, accounts)
+        }
+        LoaderV4Instruction::Create { size } => {
+            msg!("Instruction: Create");
+            process_create(program_id, accounts, size)
+        }
+        LoaderV4Instruction::CreateWithSeed {
+            seed,
+            size,
+            bump_seed,
+        } => {
+            msg!("Instruction: CreateWithSeed");
+            process_create_with_seed(program_id, accounts, &seed, size, bump_seed)
+        }
+        LoaderV4Instruction::SetMultisig { threshold, signers } => {
+            msg!("Instruction: SetMultisig");
+            process_set_multisig(program_id, accounts, threshold, signers)
+        }
+        LoaderV4Instruction::RemoveMultisig => {
+            msg!("Instruction: RemoveMultisig");
+            process_remove_multisig(program_id, accounts)
+        }
+        LoaderV4Instruction::ProposeDeploy => {
+            msg!("Instruction: ProposeDeploy");
+            process_propose_deploy(program_id, accounts)
+        }
+        LoaderV4Instruction::Approve => {
+            msg!("Instruction: Approve");
+            process_approve(program_id, accounts)
+        }
+        LoaderV4Instruction::ExecuteProposal => {
+            msg!("Instruction: ExecuteProposal");
+            process_execute_proposal(program_id, accounts)
+        }
+        LoaderV4Instruction::CancelProposal => {
+            msg!("Instruction: CancelProposal");
+            process_cancel_proposal(program_id, accounts)
+        }
+        LoaderV4Instruction::SetWriter => {
+            msg!("Instruction: SetWriter");
+            process_set_writer(program_id, accounts)
+        }
+        LoaderV4Instruction::RevokeWriter => {
+            msg!("Instruction: RevokeWriter");
+            process_revoke_writer(program_id, accounts)
         }
     }
 }
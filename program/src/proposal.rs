@@ -0,0 +1,178 @@
+//! Deployment proposals.
+//!
+//! A proposal account is owned by the loader, at the address derived by
+//! `find_proposal_address` from its program, so that a program has at most
+//! one pending proposal. It is laid out as:
+//!
+//! - `u64` slot in which the deployment was proposed.
+//! - `PROPOSAL_AUTHORITY` and the `u64` status `Retracted`, in place of the
+//!   authority and the status of program accounts. A proposal is thus a
+//!   valid header of a retracted program, which the runtime never executes,
+//!   and whose authority can never sign to modify it as one.
+//! - Address of the program to deploy.
+//! - Address of the source program.
+//! - Hash of the program data of the source program when proposed.
+//! - Address of the payer, refunded when the proposal is closed.
+//! - `u8` number of approvals, followed by the approving addresses, padded
+//!   to `MAX_MULTISIG_SIGNERS`.
+
+use {
+    crate::{extension::MAX_MULTISIG_SIGNERS, state::LoaderV4Status},
+    solana_program::{clock::Slot, program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Prefix of the seeds of proposal accounts.
+pub const PROPOSAL_SEED_PREFIX: &[u8] = b"proposal";
+
+/// Authority in the header of proposal accounts: the address of the system
+/// program, which never signs, so no program account has it.
+pub const PROPOSAL_AUTHORITY: Pubkey = Pubkey::new_from_array([0; 32]);
+
+/// Derives the address of the proposal account of a program, along with its
+/// bump seed.
+pub fn find_proposal_address(program_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED_PREFIX, program_address.as_ref()],
+        &crate::id(),
+    )
+}
+
+/// A deployment proposed by `ProposeDeploy`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DeployProposal {
+    /// Slot in which the deployment was proposed.
+    pub slot: Slot,
+    /// Program account to deploy.
+    pub program_address: Pubkey,
+    /// Program account to deploy the program data of.
+    pub source_address: Pubkey,
+    /// Hash of the program data of the source program when the deployment
+    /// was proposed.
+    pub hash: [u8; 32],
+    /// Account refunded the lamports of the proposal when it is closed.
+    pub payer_address: Pubkey,
+    /// Addresses which approved the deployment.
+    pub approvals: Vec<Pubkey>,
+}
+
+impl DeployProposal {
+    /// Size of a proposal account.
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 32 + 32 + 32 + 1 + 32 * MAX_MULTISIG_SIGNERS;
+
+    /// Unpacks the data of a proposal account.
+    ///
+    /// Fails with `InvalidAccountData` unless the data is of a proposal.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN
+            || data[0x08..0x28] != PROPOSAL_AUTHORITY.to_bytes()
+            || data[0x28..0x30] != (LoaderV4Status::Retracted as u64).to_le_bytes()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let approvals_len = data[0xb0] as usize;
+        if approvals_len > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self {
+            slot: u64::from_le_bytes(data[..0x08].try_into().unwrap()),
+            program_address: Pubkey::new_from_array(data[0x30..0x50].try_into().unwrap()),
+            source_address: Pubkey::new_from_array(data[0x50..0x70].try_into().unwrap()),
+            hash: data[0x70..0x90].try_into().unwrap(),
+            payer_address: Pubkey::new_from_array(data[0x90..0xb0].try_into().unwrap()),
+            approvals: data[0xb1..]
+                .chunks_exact(32)
+                .take(approvals_len)
+                .map(|approval| Pubkey::new_from_array(approval.try_into().unwrap()))
+                .collect(),
+        })
+    }
+
+    /// Packs a proposal into the data of a proposal account.
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        if data.len() != Self::LEN || self.approvals.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        data.fill(0);
+        data[..0x08].copy_from_slice(&self.slot.to_le_bytes());
+        data[0x08..0x28].copy_from_slice(PROPOSAL_AUTHORITY.as_ref());
+        data[0x28..0x30].copy_from_slice(&(LoaderV4Status::Retracted as u64).to_le_bytes());
+        data[0x30..0x50].copy_from_slice(self.program_address.as_ref());
+        data[0x50..0x70].copy_from_slice(self.source_address.as_ref());
+        data[0x70..0x90].copy_from_slice(&self.hash);
+        data[0x90..0xb0].copy_from_slice(self.payer_address.as_ref());
+        data[0xb0] = self.approvals.len() as u8;
+        for (approval, data) in self.approvals.iter().zip(data[0xb1..].chunks_exact_mut(32)) {
+            data.copy_from_slice(approval.as_ref());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::LoaderV4State};
+
+    fn proposal() -> DeployProposal {
+        DeployProposal {
+            slot: 0x0102_0304_0506_0708,
+            program_address: Pubkey::new_unique(),
+            source_address: Pubkey::new_unique(),
+            hash: [7; 32],
+            payer_address: Pubkey::new_unique(),
+            approvals: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack() {
+        let proposal = proposal();
+        let mut data = vec![0xff; DeployProposal::LEN];
+        proposal.pack(&mut data).unwrap();
+        assert_eq!(data.len(), 529);
+        assert_eq!(data[..8], proposal.slot.to_le_bytes());
+        assert_eq!(data[0xb0], 2);
+        assert_eq!(data[0xf1..], [0; 32 * (MAX_MULTISIG_SIGNERS - 2)]);
+        assert_eq!(DeployProposal::unpack(&data), Ok(proposal.clone()));
+
+        assert_eq!(
+            DeployProposal::unpack(&data[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            proposal.pack(&mut data[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        data[0xb0] = MAX_MULTISIG_SIGNERS as u8 + 1;
+        assert_eq!(
+            DeployProposal::unpack(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_retracted_program_header() {
+        let mut data = vec![0; DeployProposal::LEN];
+        proposal().pack(&mut data).unwrap();
+        assert_eq!(
+            LoaderV4State::unpack(&data),
+            Ok(LoaderV4State {
+                slot: proposal().slot,
+                authority_address_or_next_version: PROPOSAL_AUTHORITY,
+                status: LoaderV4Status::Retracted,
+            })
+        );
+
+        // A program account is not mistaken for a proposal.
+        let mut program_data = data.clone();
+        program_data[0x08..0x28].copy_from_slice(Pubkey::new_unique().as_ref());
+        assert_eq!(
+            DeployProposal::unpack(&program_data),
+            Err(ProgramError::InvalidAccountData)
+        );
+        data[0x28] = LoaderV4Status::Deployed as u8;
+        assert_eq!(
+            DeployProposal::unpack(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
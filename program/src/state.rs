@@ -1,10 +1,28 @@
 //! Program state types.
 
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use {
+    crate::error::LoaderV4Error,
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+};
 
 /// Cooldown before a program can be un-/redeployed again
 pub const DEPLOYMENT_COOLDOWN_IN_SLOTS: u64 = 750;
 
+/// Prefix of the seeds of program accounts created by `CreateWithSeed`.
+pub const PROGRAM_SEED_PREFIX: &[u8] = b"program";
+
+/// Derives the address of the program account created by `CreateWithSeed`
+/// from a base address and a seed, along with its bump seed.
+///
+/// Panics if the seed is longer than `MAX_SEED_LEN`, like
+/// `Pubkey::find_program_address`.
+pub fn find_program_address(base_address: &Pubkey, seed: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROGRAM_SEED_PREFIX, base_address.as_ref(), seed],
+        &crate::id(),
+    )
+}
+
 #[repr(u64)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LoaderV4Status {
@@ -29,43 +47,65 @@ pub struct LoaderV4State {
     /// Deployment status.
     pub status: LoaderV4Status,
     // The raw program data follows this serialized structure in the
-    // account's data.
+    // account's data, followed by the extension trailer if there is one. See
+    // the `extension` module.
+}
+
+impl TryFrom<u64> for LoaderV4Status {
+    type Error = LoaderV4Error;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Retracted),
+            1 => Ok(Self::Deployed),
+            2 => Ok(Self::Finalized),
+            _ => Err(LoaderV4Error::InvalidProgramState),
+        }
+    }
 }
 
 impl LoaderV4State {
-    /// Size of a serialized program account.
+    /// Size of the serialized state header, which the program data follows.
+    ///
+    /// Use `extension::program_data_range` for where the program data ends.
     pub const fn program_data_offset() -> usize {
         std::mem::size_of::<Self>()
     }
 
     /// Unpacks a byte buffer into a LoaderV4State.
-    pub fn unpack(data: &[u8]) -> Result<&Self, ProgramError> {
-        unsafe {
-            let data = data
-                .get(0..LoaderV4State::program_data_offset())
-                .ok_or(ProgramError::AccountDataTooSmall)?
-                .try_into()
-                .unwrap();
-            Ok(std::mem::transmute::<
-                &[u8; LoaderV4State::program_data_offset()],
-                &LoaderV4State,
-            >(data))
-        }
+    ///
+    /// The buffer does not need to be aligned. Fails with
+    /// `AccountDataTooSmall` if the buffer is too short and with
+    /// `LoaderV4Error::InvalidProgramState` if the status is unknown.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::program_data_offset()] = data
+            .get(0..Self::program_data_offset())
+            .and_then(|data| data.try_into().ok())
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        let (slot, rest) = data.split_at(8);
+        let (authority_address_or_next_version, status) = rest.split_at(32);
+        let status = u64::from_le_bytes(status.try_into().unwrap());
+        Ok(Self {
+            slot: u64::from_le_bytes(slot.try_into().unwrap()),
+            authority_address_or_next_version: Pubkey::new_from_array(
+                authority_address_or_next_version.try_into().unwrap(),
+            ),
+            status: LoaderV4Status::try_from(status)?,
+        })
     }
 
-    /// Unpacks a byte buffer into a mutable LoaderV4State.
-    pub fn unpack_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
-        unsafe {
-            let data = data
-                .get_mut(0..LoaderV4State::program_data_offset())
-                .ok_or(ProgramError::AccountDataTooSmall)?
-                .try_into()
-                .unwrap();
-            Ok(std::mem::transmute::<
-                &mut [u8; LoaderV4State::program_data_offset()],
-                &mut LoaderV4State,
-            >(data))
-        }
+    /// Packs a LoaderV4State into the start of a byte buffer.
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        let data = data
+            .get_mut(0..Self::program_data_offset())
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        let (slot, rest) = data.split_at_mut(8);
+        let (authority_address_or_next_version, status) = rest.split_at_mut(32);
+        slot.copy_from_slice(&self.slot.to_le_bytes());
+        authority_address_or_next_version
+            .copy_from_slice(self.authority_address_or_next_version.as_ref());
+        status.copy_from_slice(&(self.status as u64).to_le_bytes());
+        Ok(())
     }
 }
 
@@ -83,4 +123,64 @@ mod tests {
         assert_eq!(offset_of!(LoaderV4State, status), 0x28);
         assert_eq!(LoaderV4State::program_data_offset(), 0x30);
     }
+
+    #[test]
+    fn test_pack_unpack() {
+        let state = LoaderV4State {
+            slot: 0x0102_0304_0506_0708,
+            authority_address_or_next_version: Pubkey::new_unique(),
+            status: LoaderV4Status::Finalized,
+        };
+
+        // Unaligned, with trailing program data.
+        let mut data = vec![0xff; LoaderV4State::program_data_offset() + 9];
+        state.pack(&mut data[1..]).unwrap();
+        assert_eq!(data[0], 0xff);
+        assert_eq!(data[1..9], state.slot.to_le_bytes());
+        assert_eq!(
+            data[9..41],
+            state.authority_address_or_next_version.to_bytes()
+        );
+        assert_eq!(data[41..49], 2u64.to_le_bytes());
+        assert_eq!(LoaderV4State::unpack(&data[1..]), Ok(state));
+    }
+
+    #[test]
+    fn test_unpack_too_small() {
+        let data = [0; LoaderV4State::program_data_offset()];
+        for len in 0..data.len() {
+            assert_eq!(
+                LoaderV4State::unpack(&data[..len]),
+                Err(ProgramError::AccountDataTooSmall)
+            );
+        }
+        let state = LoaderV4State::unpack(&data).unwrap();
+        assert_eq!(
+            state.pack(&mut [0; LoaderV4State::program_data_offset() - 1]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_unpack_invalid_status() {
+        let mut data = [0; LoaderV4State::program_data_offset()];
+        let mut check = |status: u64| {
+            data[0x28..].copy_from_slice(&status.to_le_bytes());
+            assert_eq!(
+                LoaderV4State::unpack(&data),
+                Err(LoaderV4Error::InvalidProgramState.into()),
+                "status {status}"
+            );
+        };
+
+        // Every out-of-range value of the low byte, then a set bit in every
+        // higher position on top of each valid status.
+        (3..=u8::MAX as u64).for_each(&mut check);
+        for bit in 8..u64::BITS {
+            for status in 0..3u64 {
+                check(status | 1 << bit);
+            }
+        }
+        check(u64::MAX);
+    }
 }
@@ -0,0 +1,242 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, ScheduledDeploy},
+        instruction::accept_authority,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+#[test]
+fn fail_proposed_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = accept_authority(&program, &proposed_authority);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::PendingAuthority, proposed_authority.as_ref())],
+                    elf,
+                ),
+            ),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_no_pending_authority() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &accept_authority(&program, &proposed_authority),
+        &[
+            (program, loader_v4_state_account(&state, elf)), // No proposal.
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::NoPendingAuthority.into())],
+    );
+}
+
+#[test]
+fn fail_proposed_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &accept_authority(&program, &proposed_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        Pubkey::new_unique().as_ref(), // Mismatch.
+                    )],
+                    elf,
+                ),
+            ),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_program_finalized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Finalized, // Finalized.
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &accept_authority(&program, &proposed_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::PendingAuthority, proposed_authority.as_ref())],
+                    elf,
+                ),
+            ),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // The account keeps an empty extension trailer.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: proposed_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &accept_authority(&program, &proposed_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::PendingAuthority, proposed_authority.as_ref())],
+                    elf,
+                ),
+            ),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_cancels_scheduled_deploy() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: Pubkey::new_unique(),
+        not_before_slot: 1_000,
+        hash: [7; 32],
+    };
+
+    // The deployment was scheduled by the old authority.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: proposed_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &accept_authority(&program, &proposed_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[
+                        (ExtensionType::PendingAuthority, proposed_authority.as_ref()),
+                        (ExtensionType::ScheduledDeploy, &scheduled_deploy.pack()),
+                    ],
+                    elf,
+                ),
+            ),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
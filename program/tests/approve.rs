@@ -0,0 +1,197 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{deploy_proposal_account, loader_v4_extended_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig},
+        instruction::approve,
+        proposal::{find_proposal_address, DeployProposal},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// A deployed program with a 2-of-3 multisig.
+fn program_account(authority: &Pubkey, signers: &[Pubkey]) -> AccountSharedData {
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::Multisig, &multisig.pack())],
+        &[4; 1_500],
+    )
+}
+
+fn proposal(program: &Pubkey, approvals: Vec<Pubkey>) -> DeployProposal {
+    DeployProposal {
+        slot: 0,
+        program_address: *program,
+        source_address: Pubkey::new_unique(),
+        hash: [7; 32],
+        payer_address: Pubkey::new_unique(),
+        approvals,
+    }
+}
+
+#[test]
+fn fail_approver_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let mut instruction = approve(&program, &signers[1]);
+    instruction.accounts[2].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&program, vec![signers[0]])),
+            ),
+            (signers[1], AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_approver_not_member() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    mollusk.process_and_validate_instruction(
+        &approve(&program, &authority),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&program, vec![signers[0]])),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_proposal_of_other_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    mollusk.process_and_validate_instruction(
+        &approve(&program, &signers[1]),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&Pubkey::new_unique(), vec![signers[0]])),
+            ),
+            (signers[1], AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::IncorrectProposal.into())],
+    );
+}
+
+#[test]
+fn fail_already_approved() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    mollusk.process_and_validate_instruction(
+        &approve(&program, &signers[0]),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&program, vec![signers[0]])),
+            ),
+            (signers[0], AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::AlreadyApproved.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let former_signer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    // The approval of the former signer is dropped.
+    let proposal_before = proposal(&program, vec![former_signer, signers[0]]);
+    let check_proposal = deploy_proposal_account(&DeployProposal {
+        approvals: vec![signers[0], signers[2]],
+        ..proposal_before.clone()
+    });
+
+    mollusk.process_and_validate_instruction(
+        &approve(&program, &signers[2]),
+        &[
+            (program, program_account(&authority, &signers)),
+            (proposal_address, deploy_proposal_account(&proposal_before)),
+            (signers[2], AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal_address)
+                .data(check_proposal.data())
+                .build(),
+        ],
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::ExtensionType,
+        instruction::cancel_authority_proposal,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+#[test]
+fn fail_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &cancel_authority_proposal(&program, &authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        Pubkey::new_unique().as_ref(),
+                    )],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_no_pending_authority() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &cancel_authority_proposal(&program, &authority),
+        &[
+            (program, loader_v4_state_account(&state, elf)), // No proposal.
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::NoPendingAuthority.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // The account keeps an empty extension trailer, with the authority
+    // unchanged.
+    let check_data = loader_v4_extended_account(&state, &[], elf).data().to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &cancel_authority_proposal(&program, &authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        Pubkey::new_unique().as_ref(),
+                    )],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
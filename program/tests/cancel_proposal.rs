@@ -0,0 +1,210 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{deploy_proposal_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        instruction::cancel_proposal,
+        proposal::{find_proposal_address, DeployProposal},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+fn proposal(program: &Pubkey, payer: &Pubkey, authority: &Pubkey) -> DeployProposal {
+    DeployProposal {
+        slot: 0,
+        program_address: *program,
+        source_address: Pubkey::new_unique(),
+        hash: [7; 32],
+        payer_address: *payer,
+        approvals: vec![*authority],
+    }
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    let mut instruction = cancel_proposal(&program, &authority, &payer);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, &[4; 1_500])),
+            (authority, AccountSharedData::default()),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&program, &payer, &authority)),
+            ),
+            (payer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_incorrect_payer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let other_payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &cancel_proposal(&program, &authority, &other_payer),
+        &[
+            (program, loader_v4_state_account(&state, &[4; 1_500])),
+            (authority, AccountSharedData::default()),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(&program, &payer, &authority)),
+            ),
+            (other_payer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::IncorrectPayer.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    let proposal_account = deploy_proposal_account(&proposal(&program, &payer, &authority));
+    let proposal_lamports = proposal_account.lamports();
+
+    mollusk.process_and_validate_instruction(
+        &cancel_proposal(&program, &authority, &payer),
+        &[
+            (program, loader_v4_state_account(&state, &[4; 1_500])),
+            (authority, AccountSharedData::default()),
+            (proposal_address, proposal_account),
+            (payer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal_address)
+                .data(&[])
+                .lamports(0)
+                .build(),
+            Check::account(&payer).lamports(proposal_lamports).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_program_finalized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: program,
+        status: LoaderV4Status::Finalized,
+    };
+
+    let proposal_account = deploy_proposal_account(&proposal(&program, &payer, &authority));
+    let proposal_lamports = proposal_account.lamports();
+
+    // The proposal can no longer be executed.
+    let mut instruction = cancel_proposal(&program, &authority, &payer);
+    instruction.accounts[1].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, &[4; 1_500])),
+            (authority, AccountSharedData::default()),
+            (proposal_address, proposal_account),
+            (payer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal_address)
+                .data(&[])
+                .lamports(0)
+                .build(),
+            Check::account(&payer).lamports(proposal_lamports).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_program_closed() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    let proposal_account = deploy_proposal_account(&proposal(&program, &payer, &authority));
+    let proposal_lamports = proposal_account.lamports();
+
+    // The proposal can no longer be executed.
+    let mut instruction = cancel_proposal(&program, &authority, &payer);
+    instruction.accounts[1].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                program,
+                AccountSharedData::new(0, 0, &solana_loader_v4_program::id()),
+            ),
+            (authority, AccountSharedData::default()),
+            (proposal_address, proposal_account),
+            (payer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal_address)
+                .data(&[])
+                .lamports(0)
+                .build(),
+            Check::account(&payer).lamports(proposal_lamports).build(),
+        ],
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, ScheduledDeploy},
+        instruction::cancel_scheduled_deploy,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+fn scheduled_deploy() -> [u8; ScheduledDeploy::LEN] {
+    ScheduledDeploy {
+        source_address: Pubkey::new_unique(),
+        not_before_slot: 1_000,
+        hash: [7; 32],
+    }
+    .pack()
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = cancel_scheduled_deploy(&program, &authority);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::ScheduledDeploy, &scheduled_deploy())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_no_scheduled_deploy() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &cancel_scheduled_deploy(&program, &authority),
+        &[
+            (program, loader_v4_state_account(&state, elf)), // Nothing scheduled.
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::NoScheduledDeploy.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // The account keeps an empty extension trailer.
+    let check_data = loader_v4_extended_account(&state, &[], elf).data().to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &cancel_scheduled_deploy(&program, &authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::ScheduledDeploy, &scheduled_deploy())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
@@ -0,0 +1,236 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::ExtensionType,
+        instruction::close,
+        state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = close(&program, &authority, &destination);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_program_finalized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Finalized, // Finalized.
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &close(&program, &authority, &destination),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn fail_destination_is_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &close(&program, &authority, &program),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::DestinationIsProgram.into())],
+    );
+}
+
+#[test]
+fn fail_destination_not_writable() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = close(&program, &authority, &destination);
+    instruction.accounts[2].is_writable = false; // Not writable.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::DestinationNotWritable.into())],
+    );
+}
+
+#[test]
+fn fail_deployed_in_cooldown() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 1, // Deployed too recently.
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &close(&program, &authority, &destination),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::DeploymentCooldown.into())],
+    );
+}
+
+#[test]
+fn success_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let program_account = loader_v4_state_account(&state, elf);
+    let expected_destination_lamports = program_account.lamports() + 1_000;
+
+    mollusk.process_and_validate_instruction(
+        &close(&program, &authority, &destination),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (
+                destination,
+                AccountSharedData::new(1_000, 0, &Pubkey::default()),
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&[]).lamports(0).build(),
+            Check::account(&destination)
+                .lamports(expected_destination_lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_deployed() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 1,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // Extensions are closed along with the program.
+    let program_account = loader_v4_extended_account(
+        &state,
+        &[(
+            ExtensionType::PendingAuthority,
+            Pubkey::new_unique().as_ref(),
+        )],
+        elf,
+    );
+    let expected_destination_lamports = program_account.lamports();
+
+    mollusk.process_and_validate_instruction(
+        &close(&program, &authority, &destination),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&[]).lamports(0).build(),
+            Check::account(&destination)
+                .lamports(expected_destination_lamports)
+                .build(),
+        ],
+    );
+}
@@ -3,7 +3,11 @@
 
 use {
     mollusk_svm::Mollusk,
-    solana_loader_v4_program::state::LoaderV4State,
+    solana_loader_v4_program::{
+        extension::{ExtensionType, EXTENSIONS_MAGIC},
+        proposal::DeployProposal,
+        state::LoaderV4State,
+    },
     solana_sdk::{account::AccountSharedData, rent::Rent, system_program},
 };
 
@@ -20,10 +24,38 @@ pub fn loader_v4_state_account(
     additional_bytes: &[u8],
 ) -> AccountSharedData {
     let mut data = vec![0; LoaderV4State::program_data_offset()];
-    {
-        *LoaderV4State::unpack_mut(&mut data).unwrap() = *state;
+    state.pack(&mut data).unwrap();
+    data.extend_from_slice(additional_bytes);
+
+    let space = data.len();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut account = AccountSharedData::new(lamports, space, &solana_loader_v4_program::id());
+    account.set_data_from_slice(&data);
+
+    account
+}
+
+/// A program account of the extended layout, with the given extensions in
+/// the trailer after the program data.
+pub fn loader_v4_extended_account(
+    state: &LoaderV4State,
+    extensions: &[(ExtensionType, &[u8])],
+    additional_bytes: &[u8],
+) -> AccountSharedData {
+    let mut area = Vec::new();
+    for (extension_type, value) in extensions {
+        area.extend_from_slice(&(*extension_type as u16).to_le_bytes());
+        area.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        area.extend_from_slice(value);
     }
+
+    let mut data = vec![0; LoaderV4State::program_data_offset()];
+    state.pack(&mut data).unwrap();
     data.extend_from_slice(additional_bytes);
+    data.extend_from_slice(&area);
+    data.extend_from_slice(&(area.len() as u32).to_le_bytes());
+    data.extend_from_slice(&EXTENSIONS_MAGIC);
 
     let space = data.len();
     let lamports = Rent::default().minimum_balance(space);
@@ -33,3 +65,34 @@ pub fn loader_v4_state_account(
 
     account
 }
+
+/// The number of bytes the first extension with a value of `len` bytes adds
+/// to a program account: the entry header, the value, the length of the
+/// extension area and the magic.
+pub fn extension_space(len: usize) -> usize {
+    4 + len + 4 + EXTENSIONS_MAGIC.len()
+}
+
+/// A minimal valid SBPF ELF, padded with `fill` up to 1,500 bytes.
+pub fn valid_elf(fill: u8) -> Vec<u8> {
+    let mut elf = include_bytes!("fixtures/noop.elf").to_vec();
+    elf.resize(1_500, fill);
+    elf
+}
+
+/// A proposal account holding the given proposal.
+pub fn deploy_proposal_account(proposal: &DeployProposal) -> AccountSharedData {
+    let mut data = vec![0; DeployProposal::LEN];
+    proposal.pack(&mut data).unwrap();
+
+    let lamports = Rent::default().minimum_balance(DeployProposal::LEN);
+
+    let mut account = AccountSharedData::new(
+        lamports,
+        DeployProposal::LEN,
+        &solana_loader_v4_program::id(),
+    );
+    account.set_data_from_slice(&data);
+
+    account
+}
@@ -0,0 +1,508 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, EXTENSIONS_MAGIC},
+        instruction::copy,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        bpf_loader_upgradeable,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+fn data_account(owner: &Pubkey, data: &[u8]) -> AccountSharedData {
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        owner,
+    );
+    account.set_data_from_slice(data);
+    account
+}
+
+#[test]
+fn fail_program_not_owned_by_loader() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // Incorrect owner.
+    let mut program_account = loader_v4_state_account(&state, uninitialized_data);
+    program_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 12),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_program_not_writable() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    let mut instruction = copy(&program, &authority, &source, 0, 0, 12);
+    instruction.accounts[0].is_writable = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
+    );
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    let mut instruction = copy(&program, &authority, &source, 0, 0, 12);
+    instruction.accounts[1].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 12),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_program_finalized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Finalized, // Finalized.
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 12),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn fail_program_not_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed, // Not retracted.
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 12),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_source_invalid_loader_v3_state() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // A loader-v3 program account, which holds no program data.
+    let mut source_data = 2u32.to_le_bytes().to_vec();
+    source_data.extend_from_slice(Pubkey::new_unique().as_ref());
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 12),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                data_account(&bpf_loader_upgradeable::id(), &source_data),
+            ),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn fail_read_out_of_bounds() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 4, 12), // Past the source end.
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn fail_write_out_of_bounds() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 30, 0, 12), // Past the program end.
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn fail_extensions_out_of_bounds() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let pending_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::PendingAuthority, pending_authority.as_ref())];
+
+    // The extensions of the source can not be read.
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 48),
+        &[
+            (program, loader_v4_state_account(&state, &[0; 48])),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_extended_account(&state, &extensions, &[4; 12]),
+            ),
+        ],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+
+    // Nor can the extensions of the program be written.
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 8, 0, 12),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[0; 12]),
+            ),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &[4; 12])),
+        ],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn fail_forged_extension_trailer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // The program would read as one of the extended layout.
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 28, 0, 8),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                data_account(&Pubkey::new_unique(), &EXTENSIONS_MAGIC),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    let mut check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        state.pack(&mut data).unwrap();
+        data.extend_from_slice(uninitialized_data);
+        data
+    };
+
+    // Copy from a plain data account, from the start of its data.
+    let source_data = (0..24).collect::<Vec<u8>>();
+    check_data[LoaderV4State::program_data_offset().saturating_add(4)
+        ..LoaderV4State::program_data_offset().saturating_add(16)]
+        .copy_from_slice(&source_data[8..20]);
+
+    let result = mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 4, 8, 12),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, data_account(&Pubkey::new_unique(), &source_data)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+
+    // Copy within the program account itself.
+    check_data.copy_within(
+        LoaderV4State::program_data_offset().saturating_add(4)
+            ..LoaderV4State::program_data_offset().saturating_add(16),
+        LoaderV4State::program_data_offset().saturating_add(24),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &program, 24, 4, 12),
+        &[
+            (program, result.get_account(&program).unwrap().clone()),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_loader_v4_source() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // Any loader-v4 program can be copied from, whatever its authority.
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let source_elf = &[8; 36];
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        state.pack(&mut data).unwrap();
+        data.extend_from_slice(source_elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 36),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&source_state, source_elf)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_loader_v3_buffer_source() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // `[tag: u32 = 1][option: u8][authority: Pubkey][elf]`.
+    let source_elf = &[8; 36];
+    let mut source_data = 1u32.to_le_bytes().to_vec();
+    source_data.push(1);
+    source_data.extend_from_slice(Pubkey::new_unique().as_ref());
+    source_data.extend_from_slice(source_elf);
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        state.pack(&mut data).unwrap();
+        data.extend_from_slice(source_elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &copy(&program, &authority, &source, 0, 0, 36),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                data_account(&bpf_loader_upgradeable::id(), &source_data),
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_state_account, setup, system_account_with_lamports},
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        instruction::create,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        entrypoint::MAX_PERMITTED_DATA_INCREASE,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+#[test]
+fn fail_program_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let mut instruction = create(&program, &authority, &payer, 1_500);
+    instruction.accounts[0].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let mut instruction = create(&program, &authority, &payer, 1_500);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_payer_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let mut instruction = create(&program, &authority, &payer, 1_500);
+    instruction.accounts[2].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_invalid_system_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let not_system_program = Pubkey::new_unique();
+
+    let mut instruction = create(&program, &authority, &payer, 1_500);
+    instruction.accounts[3].pubkey = not_system_program; // Not the system program.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            (not_system_program, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
+
+#[test]
+fn fail_size_too_large() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    // One byte more than the system program can allocate in a CPI.
+    let size = MAX_PERMITTED_DATA_INCREASE - LoaderV4State::program_data_offset() + 1;
+
+    mollusk.process_and_validate_instruction(
+        &create(&program, &authority, &payer, size as u32),
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(LoaderV4Error::CreateSizeTooLarge.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    // A retracted program of the given size, zeroed after the header.
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let check_account = loader_v4_state_account(&state, &[0; 1_500]);
+    let lamports = Rent::default().minimum_balance(check_account.data().len());
+
+    mollusk.process_and_validate_instruction(
+        &create(&program, &authority, &payer, 1_500),
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(check_account.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_max_size() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let size = MAX_PERMITTED_DATA_INCREASE - LoaderV4State::program_data_offset();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let check_account = loader_v4_state_account(&state, &vec![0; size]);
+    let lamports = Rent::default().minimum_balance(check_account.data().len());
+
+    mollusk.process_and_validate_instruction(
+        &create(&program, &authority, &payer, size as u32),
+        &[
+            (program, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(check_account.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - lamports)
+                .build(),
+        ],
+    );
+}
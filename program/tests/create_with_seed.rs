@@ -0,0 +1,282 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_state_account, setup, system_account_with_lamports},
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        instruction::{create_with_seed, LoaderV4Instruction},
+        state::{find_program_address, LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        entrypoint::MAX_PERMITTED_DATA_INCREASE,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+#[test]
+fn fail_base_not_signer() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, _) = find_program_address(&base, b"program");
+
+    let mut instruction = create_with_seed(&base, b"program", &authority, &payer, 1_500);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_seed_too_long() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+
+    let mut instruction = create_with_seed(&base, b"program", &authority, &payer, 1_500);
+    instruction.accounts[0].pubkey = program;
+    instruction.data = bincode::serialize(&LoaderV4Instruction::CreateWithSeed {
+        seed: vec![7; 33], // One byte too long.
+        size: 1_500,
+        bump_seed: 255,
+    })
+    .unwrap();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MaxSeedLengthExceeded)],
+    );
+}
+
+#[test]
+fn fail_incorrect_program_address() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    // Derived from another seed.
+    let (program, _) = find_program_address(&base, b"other");
+
+    let mut instruction = create_with_seed(&base, b"program", &authority, &payer, 1_500);
+    instruction.accounts[0].pubkey = program;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn fail_incorrect_bump_seed() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, bump_seed) = find_program_address(&base, b"program");
+
+    let mut instruction = create_with_seed(&base, b"program", &authority, &payer, 1_500);
+    instruction.data = bincode::serialize(&LoaderV4Instruction::CreateWithSeed {
+        seed: b"program".to_vec(),
+        size: 1_500,
+        bump_seed: bump_seed.wrapping_sub(1), // Not the canonical bump seed.
+    })
+    .unwrap();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn fail_size_too_large() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, _) = find_program_address(&base, b"program");
+
+    // One byte more than the system program can allocate in a CPI.
+    let size = MAX_PERMITTED_DATA_INCREASE - LoaderV4State::program_data_offset() + 1;
+
+    mollusk.process_and_validate_instruction(
+        &create_with_seed(&base, b"program", &authority, &payer, size as u32),
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(LoaderV4Error::CreateSizeTooLarge.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, _) = find_program_address(&base, b"program");
+
+    // A retracted program of the given size, zeroed after the header.
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let check_account = loader_v4_state_account(&state, &[0; 1_500]);
+    let lamports = Rent::default().minimum_balance(check_account.data().len());
+
+    mollusk.process_and_validate_instruction(
+        &create_with_seed(&base, b"program", &authority, &payer, 1_500),
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(check_account.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_prefunded() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, _) = find_program_address(&base, b"program");
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let check_account = loader_v4_state_account(&state, &[0; 1_500]);
+    let lamports = Rent::default().minimum_balance(check_account.data().len());
+
+    // Lamports sent to the program address beforehand only reduce what the
+    // payer has to fund.
+    mollusk.process_and_validate_instruction(
+        &create_with_seed(&base, b"program", &authority, &payer, 1_500),
+        &[
+            (program, system_account_with_lamports(1_000)),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(check_account.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - (lamports - 1_000))
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_max_size() {
+    let mollusk = setup();
+
+    let base = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (program, _) = find_program_address(&base, b"program");
+
+    let size = MAX_PERMITTED_DATA_INCREASE - LoaderV4State::program_data_offset();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let check_account = loader_v4_state_account(&state, &vec![0; size]);
+    let lamports = Rent::default().minimum_balance(check_account.data().len());
+
+    mollusk.process_and_validate_instruction(
+        &create_with_seed(&base, b"program", &authority, &payer, size as u32),
+        &[
+            (program, AccountSharedData::default()),
+            (base, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(check_account.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - lamports)
+                .build(),
+        ],
+    );
+}
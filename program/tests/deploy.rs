@@ -3,14 +3,17 @@
 mod common;
 
 use {
-    common::{loader_v4_state_account, setup},
+    common::{loader_v4_extended_account, loader_v4_state_account, setup, valid_elf},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
-        instruction::deploy,
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig, ScheduledDeploy, EXTENSIONS_MAGIC},
+        instruction::{deploy, deploy_with_hash, with_multisig_signers},
         state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
     },
     solana_sdk::{
-        account::{AccountSharedData, WritableAccount},
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        hash::hash,
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -28,7 +31,7 @@ fn fail_program_not_owned_by_loader() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     // Incorrect owner.
     let mut program_account = loader_v4_state_account(&state, elf);
@@ -78,7 +81,7 @@ fn fail_program_not_writable() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     let mut instruction = deploy(&program, &authority, None);
     instruction.accounts[0].is_writable = false;
@@ -89,7 +92,7 @@ fn fail_program_not_writable() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -105,7 +108,7 @@ fn fail_authority_not_signer() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     let mut instruction = deploy(&program, &authority, None);
     instruction.accounts[1].is_signer = false;
@@ -132,7 +135,7 @@ fn fail_authority_mismatch() {
         authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, None),
@@ -144,6 +147,38 @@ fn fail_authority_mismatch() {
     );
 }
 
+#[test]
+fn fail_writer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &valid_elf(4);
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &writer, None),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
 #[test]
 fn fail_program_finalized() {
     let mollusk = setup();
@@ -156,7 +191,7 @@ fn fail_program_finalized() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Finalized, // Finalized.
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, None),
@@ -181,7 +216,7 @@ fn fail_program_deployed_in_same_slot() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, None),
@@ -189,7 +224,7 @@ fn fail_program_deployed_in_same_slot() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::DeploymentCooldown.into())],
     );
 }
 
@@ -205,7 +240,7 @@ fn fail_program_not_retracted() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Deployed, // Not retracted.
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, None),
@@ -213,7 +248,243 @@ fn fail_program_not_retracted() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_deploy_scheduled() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: Pubkey::new_unique(),
+        not_before_slot: 1_000,
+        hash: [7; 32],
+    };
+
+    // The scheduled deployment has to be cancelled first.
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, None),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::ScheduledDeploy, &scheduled_deploy.pack())],
+                    &valid_elf(4),
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::DeployScheduled.into())],
+    );
+}
+
+#[test]
+fn fail_invalid_elf() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500]; // Not an ELF.
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, None),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn fail_hash_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &valid_elf(4);
+    let expected_hash = hash(&valid_elf(5)).to_bytes(); // Mismatch.
+
+    mollusk.process_and_validate_instruction(
+        &deploy_with_hash(&program, &authority, None, expected_hash),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::ProgramDataHashMismatch.into())],
+    );
+}
+
+#[test]
+fn success_expected_hash() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &valid_elf(4);
+    let expected_hash = hash(elf).to_bytes();
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
+        }
+        .pack(&mut data)
+        .unwrap();
+        data.extend_from_slice(elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &deploy_with_hash(&program, &authority, None, expected_hash),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_multisig() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &valid_elf(4);
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    let extensions = [(ExtensionType::Multisig, &multisig.pack()[..])];
+
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            status: LoaderV4Status::Deployed,
+            ..state
+        },
+        &extensions,
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    // The signers are not mistaken for a source program.
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(deploy(&program, &authority, None), &signers),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, elf),
+            ),
+            (authority, AccountSharedData::default()),
+            (signers[0], AccountSharedData::default()),
+            (signers[1], AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_multisig_with_source() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    let extensions = [(ExtensionType::Multisig, &multisig.pack()[..])];
+
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let source_elf = &valid_elf(8);
+
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            status: LoaderV4Status::Deployed,
+            ..state
+        },
+        &extensions,
+        source_elf,
+    )
+    .data()
+    .to_vec();
+
+    // The multisig signs for the source as well, since it has the same
+    // authority.
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(deploy(&program, &authority, Some(&source)), &signers),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[]),
+            ),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&source_state, source_elf)),
+            (signers[0], AccountSharedData::default()),
+            (signers[1], AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source).data(&[]).build(),
+        ],
     );
 }
 
@@ -229,18 +500,18 @@ fn success() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let elf = &[4; 1_500];
+    let elf = &valid_elf(4);
 
     // First deploy for the first time.
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: 0,
-                authority_address_or_next_version: authority,
-                status: LoaderV4Status::Deployed,
-            };
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(elf);
         data
     };
@@ -253,7 +524,6 @@ fn success() {
         ],
         &[
             Check::success(),
-            Check::compute_units(1_057),
             Check::account(&program).data(&check_data).build(),
         ],
     );
@@ -267,13 +537,13 @@ fn success() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: mollusk.sysvars.clock.slot,
-                authority_address_or_next_version: authority,
-                status: LoaderV4Status::Deployed,
-            };
+        LoaderV4State {
+            slot: mollusk.sysvars.clock.slot,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(elf);
         data
     };
@@ -286,7 +556,6 @@ fn success() {
         ],
         &[
             Check::success(),
-            Check::compute_units(1_057),
             Check::account(&program).data(&check_data).build(),
         ],
     );
@@ -311,7 +580,7 @@ fn fail_source_program_not_retracted() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Deployed, // Not retracted.
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, Some(&source)),
@@ -320,7 +589,7 @@ fn fail_source_program_not_retracted() {
             (authority, AccountSharedData::default()),
             (source, loader_v4_state_account(&source_state, source_elf)),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::SourceProgramNotRetracted.into())],
     );
 }
 
@@ -343,7 +612,7 @@ fn fail_source_program_not_owned_by_loader() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     // Incorrect owner.
     let mut source_account = loader_v4_state_account(&source_state, source_elf);
@@ -409,7 +678,7 @@ fn fail_source_program_not_writable() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     let mut instruction = deploy(&program, &authority, Some(&source));
     instruction.accounts[0].is_writable = false; // Not writable.
@@ -421,7 +690,7 @@ fn fail_source_program_not_writable() {
             (authority, AccountSharedData::default()),
             (source, loader_v4_state_account(&source_state, source_elf)),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -444,7 +713,7 @@ fn fail_source_authority_not_signer() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     let mut instruction = deploy(&program, &authority, Some(&source));
     instruction.accounts[1].is_signer = false; // Not a signer.
@@ -479,7 +748,7 @@ fn fail_source_authority_mismatch() {
         authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, Some(&source)),
@@ -511,7 +780,7 @@ fn fail_source_program_finalized() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Finalized, // Finalized.
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     mollusk.process_and_validate_instruction(
         &deploy(&program, &authority, Some(&source)),
@@ -524,6 +793,186 @@ fn fail_source_program_finalized() {
     );
 }
 
+#[test]
+fn fail_source_program_invalid_elf() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let source_elf = &[8; 1_500]; // Not an ELF.
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, Some(&source)),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&source_state, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn fail_source_hash_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &valid_elf(4);
+
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let source_elf = &valid_elf(8);
+
+    // The hash covers the source program data, not the destination's.
+    mollusk.process_and_validate_instruction(
+        &deploy_with_hash(&program, &authority, Some(&source), hash(elf).to_bytes()),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&source_state, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::ProgramDataHashMismatch.into())],
+    );
+}
+
+#[test]
+fn fail_source_program_forged_extensions() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let forger = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    // The program data of the source ends in a forged trailer granting the
+    // program away.
+    let mut source_elf = valid_elf(8);
+    let forged_len = source_elf.len() - 4 - 32 - 4 - 8;
+    source_elf.truncate(forged_len);
+    source_elf.extend_from_slice(&(ExtensionType::PendingAuthority as u16).to_le_bytes());
+    source_elf.extend_from_slice(&32u16.to_le_bytes());
+    source_elf.extend_from_slice(forger.as_ref());
+    source_elf.extend_from_slice(&36u32.to_le_bytes());
+    source_elf.extend_from_slice(&EXTENSIONS_MAGIC);
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, Some(&source)),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_extended_account(
+                    &source_state,
+                    &[(ExtensionType::PendingAuthority, forger.as_ref())],
+                    &source_elf,
+                ),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn success_source_program_extended() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let pending_authority = Pubkey::new_unique();
+    let source_pending_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::PendingAuthority, pending_authority.as_ref())];
+
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let source_elf = &valid_elf(8);
+
+    // The program keeps its extensions, and only takes the program data of
+    // the source.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            status: LoaderV4Status::Deployed,
+            ..state
+        },
+        &extensions,
+        source_elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, Some(&source)),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &valid_elf(4)[..1_000]),
+            ),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_extended_account(
+                    &source_state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        source_pending_authority.as_ref(),
+                    )],
+                    source_elf,
+                ),
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source).data(&[]).build(),
+        ],
+    );
+}
+
 #[test]
 fn success_source_program() {
     let mut mollusk = setup();
@@ -543,18 +992,18 @@ fn success_source_program() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[8; 1_500];
+    let source_elf = &valid_elf(8);
 
     // First deploy for the first time.
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: 0,
-                authority_address_or_next_version: authority,
-                status: LoaderV4Status::Deployed,
-            };
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(source_elf);
         data
     };
@@ -568,7 +1017,6 @@ fn success_source_program() {
         ],
         &[
             Check::success(),
-            Check::compute_units(2_121),
             Check::account(&program).data(&check_data).build(),
             Check::account(&source).data(&[]).build(),
         ],
@@ -582,7 +1030,7 @@ fn success_source_program() {
         authority_address_or_next_version: authority,
         status: LoaderV4Status::Retracted,
     };
-    let source_elf = &[9; 1_500];
+    let source_elf = &valid_elf(9);
 
     // Change the program to `Retracted` in-line.
     let mut program_account = result.get_account(&program).unwrap().clone();
@@ -590,13 +1038,13 @@ fn success_source_program() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: mollusk.sysvars.clock.slot,
-                authority_address_or_next_version: authority,
-                status: LoaderV4Status::Deployed,
-            };
+        LoaderV4State {
+            slot: mollusk.sysvars.clock.slot,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(source_elf);
         data
     };
@@ -610,7 +1058,50 @@ fn success_source_program() {
         ],
         &[
             Check::success(),
-            Check::compute_units(2_048),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source).data(&[]).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_source_program_smaller_than_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0, // Not deployed yet.
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let source_elf = &valid_elf(8);
+
+    // The program shrinks to the size of the source program data.
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
+        }
+        .pack(&mut data)
+        .unwrap();
+        data.extend_from_slice(source_elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &deploy(&program, &authority, Some(&source)),
+        &[
+            (program, loader_v4_state_account(&state, &[4; 3_000])),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&state, source_elf)),
+        ],
+        &[
+            Check::success(),
             Check::account(&program).data(&check_data).build(),
             Check::account(&source).data(&[]).build(),
         ],
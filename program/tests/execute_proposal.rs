@@ -0,0 +1,268 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{
+        deploy_proposal_account, loader_v4_extended_account, loader_v4_state_account, setup,
+        valid_elf,
+    },
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig},
+        instruction::execute_proposal,
+        proposal::{find_proposal_address, DeployProposal},
+        state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        hash::hash,
+        pubkey::Pubkey,
+    },
+};
+
+/// A program deployed in slot 1 with a 2-of-2 multisig.
+fn program_account(authority: &Pubkey, signers: &[Pubkey]) -> AccountSharedData {
+    let state = LoaderV4State {
+        slot: 1,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::Multisig, &multisig.pack())],
+        &valid_elf(4),
+    )
+}
+
+fn source_account(authority: &Pubkey, source_elf: &[u8]) -> AccountSharedData {
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Retracted,
+    };
+    loader_v4_state_account(&source_state, source_elf)
+}
+
+fn proposal(
+    program: &Pubkey,
+    source: &Pubkey,
+    source_elf: &[u8],
+    payer: &Pubkey,
+    approvals: Vec<Pubkey>,
+) -> DeployProposal {
+    DeployProposal {
+        slot: 0,
+        program_address: *program,
+        source_address: *source,
+        hash: hash(source_elf).to_bytes(),
+        payer_address: *payer,
+        approvals,
+    }
+}
+
+#[test]
+fn fail_not_approved() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_proposal(&program, &source, &payer),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                // One approval of a current signer, one of a former one.
+                deploy_proposal_account(&proposal(
+                    &program,
+                    &source,
+                    source_elf,
+                    &payer,
+                    vec![signers[0], Pubkey::new_unique()],
+                )),
+            ),
+            (source, source_account(&authority, source_elf)),
+            (payer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::ProposalNotApproved.into())],
+    );
+}
+
+#[test]
+fn fail_incorrect_source() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let source = Pubkey::new_unique();
+    let other_source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_proposal(&program, &other_source, &payer),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(
+                    &program,
+                    &source,
+                    source_elf,
+                    &payer,
+                    signers.to_vec(),
+                )),
+            ),
+            (other_source, source_account(&authority, source_elf)),
+            (payer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::IncorrectSourceProgram.into())],
+    );
+}
+
+#[test]
+fn fail_source_changed() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+
+    mollusk.process_and_validate_instruction(
+        &execute_proposal(&program, &source, &payer),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(
+                    &program,
+                    &source,
+                    &valid_elf(8),
+                    &payer,
+                    signers.to_vec(),
+                )),
+            ),
+            // Rewritten since the deployment was proposed.
+            (source, source_account(&authority, &valid_elf(9))),
+            (payer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::ProgramDataHashMismatch.into())],
+    );
+}
+
+#[test]
+fn fail_cooldown() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(DEPLOYMENT_COOLDOWN_IN_SLOTS); // Deployed in slot 1.
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_proposal(&program, &source, &payer),
+        &[
+            (program, program_account(&authority, &signers)),
+            (
+                proposal_address,
+                deploy_proposal_account(&proposal(
+                    &program,
+                    &source,
+                    source_elf,
+                    &payer,
+                    signers.to_vec(),
+                )),
+            ),
+            (source, source_account(&authority, source_elf)),
+            (payer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::DeploymentCooldown.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal_address, _) = find_proposal_address(&program);
+    let source_elf = &valid_elf(8);
+
+    // The program keeps its multisig and is deployed from the source.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            slot: mollusk.sysvars.clock.slot,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
+        },
+        &[(
+            ExtensionType::Multisig,
+            &Multisig {
+                threshold: 2,
+                signers: signers.to_vec(),
+            }
+            .pack(),
+        )],
+        source_elf,
+    )
+    .data()
+    .to_vec();
+
+    let proposal_account = deploy_proposal_account(&proposal(
+        &program,
+        &source,
+        source_elf,
+        &payer,
+        signers.to_vec(),
+    ));
+    let proposal_lamports = proposal_account.lamports();
+
+    mollusk.process_and_validate_instruction(
+        &execute_proposal(&program, &source, &payer),
+        &[
+            (program, program_account(&authority, &signers)),
+            (proposal_address, proposal_account),
+            (source, source_account(&authority, source_elf)),
+            (payer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source).data(&[]).build(),
+            Check::account(&proposal_address)
+                .data(&[])
+                .lamports(0)
+                .build(),
+            Check::account(&payer).lamports(proposal_lamports).build(),
+        ],
+    );
+}
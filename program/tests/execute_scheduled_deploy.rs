@@ -0,0 +1,240 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup, valid_elf},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, ScheduledDeploy},
+        instruction::execute_scheduled_deploy,
+        state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        hash::hash,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// A program account deployed in slot 1, with a deployment of `source_elf`
+/// from `source` scheduled for `not_before_slot`.
+fn scheduled_program_account(
+    authority: &Pubkey,
+    source: &Pubkey,
+    source_elf: &[u8],
+    not_before_slot: u64,
+) -> AccountSharedData {
+    let state = LoaderV4State {
+        slot: 1,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: *source,
+        not_before_slot,
+        hash: hash(source_elf).to_bytes(),
+    };
+    loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::ScheduledDeploy, &scheduled_deploy.pack())],
+        &valid_elf(4),
+    )
+}
+
+fn source_account(authority: &Pubkey, source_elf: &[u8]) -> AccountSharedData {
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Retracted,
+    };
+    loader_v4_state_account(&source_state, source_elf)
+}
+
+#[test]
+fn fail_no_scheduled_deploy() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (source, source_account(&authority, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::NoScheduledDeploy.into())],
+    );
+}
+
+#[test]
+fn fail_incorrect_source() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let other_source = Pubkey::new_unique();
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &other_source),
+        &[
+            (
+                program,
+                scheduled_program_account(&authority, &source, source_elf, 1_000),
+            ),
+            (other_source, source_account(&authority, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::IncorrectSourceProgram.into())],
+    );
+}
+
+#[test]
+fn fail_source_authority_changed() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (
+                program,
+                scheduled_program_account(&authority, &source, source_elf, 1_000),
+            ),
+            (source, source_account(&Pubkey::new_unique(), source_elf)),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_not_due() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (
+                program,
+                scheduled_program_account(
+                    &authority,
+                    &source,
+                    source_elf,
+                    1_001 + DEPLOYMENT_COOLDOWN_IN_SLOTS, // One slot too early.
+                ),
+            ),
+            (source, source_account(&authority, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::ScheduledDeployNotDue.into())],
+    );
+}
+
+#[test]
+fn fail_cooldown() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(DEPLOYMENT_COOLDOWN_IN_SLOTS); // Deployed in slot 1.
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let source_elf = &valid_elf(8);
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (
+                program,
+                scheduled_program_account(&authority, &source, source_elf, 0),
+            ),
+            (source, source_account(&authority, source_elf)),
+        ],
+        &[Check::err(LoaderV4Error::DeploymentCooldown.into())],
+    );
+}
+
+#[test]
+fn fail_source_changed() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (
+                program,
+                scheduled_program_account(&authority, &source, &valid_elf(8), 1_000),
+            ),
+            // Rewritten since the deployment was scheduled.
+            (source, source_account(&authority, &valid_elf(9))),
+        ],
+        &[Check::err(LoaderV4Error::ProgramDataHashMismatch.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000 + DEPLOYMENT_COOLDOWN_IN_SLOTS);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let source_elf = &valid_elf(8);
+
+    // The deployed program is replaced, without the schedule.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            slot: mollusk.sysvars.clock.slot,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
+        },
+        &[],
+        source_elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &execute_scheduled_deploy(&program, &source),
+        &[
+            (
+                program,
+                scheduled_program_account(&authority, &source, source_elf, 1_000),
+            ),
+            (source, source_account(&authority, source_elf)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source).data(&[]).build(),
+        ],
+    );
+}
@@ -3,9 +3,11 @@
 mod common;
 
 use {
-    common::{loader_v4_state_account, setup},
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::ExtensionType,
         instruction::finalize,
         state::{LoaderV4State, LoaderV4Status},
     },
@@ -125,7 +127,7 @@ fn fail_program_not_writable() {
                 loader_v4_state_account(&next_version_state, next_version_elf),
             ),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -204,6 +206,50 @@ fn fail_authority_mismatch() {
     );
 }
 
+#[test]
+fn fail_writer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+    let next_version = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let next_version_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: writer,
+        status: LoaderV4Status::Deployed,
+    };
+    let next_version_elf = &[8; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &finalize(&program, &writer, &next_version),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (writer, AccountSharedData::default()),
+            (
+                next_version,
+                loader_v4_state_account(&next_version_state, next_version_elf),
+            ),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
 #[test]
 fn fail_program_finalized() {
     let mollusk = setup();
@@ -272,7 +318,7 @@ fn fail_program_not_deployed() {
                 loader_v4_state_account(&next_version_state, next_version_elf),
             ),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotDeployed.into())],
     );
 }
 
@@ -313,6 +359,43 @@ fn fail_next_version_not_owned_by_loader() {
     );
 }
 
+#[test]
+fn fail_next_version_corrupt_state() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let next_version = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let next_version_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let next_version_elf = &[8; 1_500];
+
+    // Unknown status.
+    let mut next_version_account = loader_v4_state_account(&next_version_state, next_version_elf);
+    next_version_account.data_as_mut_slice()[0x28..0x30].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    mollusk.process_and_validate_instruction(
+        &finalize(&program, &authority, &next_version),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (next_version, next_version_account),
+        ],
+        &[Check::err(LoaderV4Error::InvalidProgramState.into())],
+    );
+}
+
 #[test]
 fn fail_next_version_authority_mismatch() {
     let mollusk = setup();
@@ -409,13 +492,13 @@ fn success() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: 0,
-                authority_address_or_next_version: next_version,
-                status: LoaderV4Status::Finalized,
-            };
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: next_version,
+            status: LoaderV4Status::Finalized,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(elf);
         data
     };
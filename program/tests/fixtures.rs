@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::setup,
+    mollusk_svm::Mollusk,
+    mollusk_svm_fuzz_fixture_firedancer::Fixture,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+    },
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+fn fixture_paths(dir: &str, extension: &str) -> Vec<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(dir);
+    let mut paths = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("Unable to read {}: {}", dir.display(), err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert!(!paths.is_empty(), "No fixtures in {}", dir.display());
+    paths
+}
+
+/// Encodes an instruction result the way Firedancer fixtures store it: `0`
+/// for success, otherwise the `InstructionError` variant index plus one.
+fn program_result(result: &Result<(), InstructionError>) -> (i32, u32) {
+    match result {
+        Ok(()) => (0, 0),
+        Err(err) => {
+            let serialized = bincode::serialize(err).unwrap();
+            let code = i32::from_le_bytes(serialized[0..4].try_into().unwrap()) + 1;
+            let custom_code = match err {
+                InstructionError::Custom(code) => *code,
+                _ => 0,
+            };
+            (code, custom_code)
+        }
+    }
+}
+
+/// Processes the fixture's instruction and compares the result and the
+/// modified accounts with the fixture's effects.
+///
+/// Compute units are not compared, since they depend on the toolchain the
+/// program was built with; the program tests check them where they matter.
+fn process_and_validate_fixture(mollusk: &mut Mollusk, path: &Path, fixture: Fixture) {
+    let Fixture { input, output, .. } = fixture;
+
+    mollusk.warp_to_slot(input.slot_context.slot);
+    mollusk.feature_set = input.epoch_context.feature_set;
+    mollusk.compute_budget.compute_unit_limit = input.compute_units_available;
+
+    let accounts = input
+        .accounts
+        .into_iter()
+        .map(|(key, account, _)| (key, account))
+        .collect::<Vec<(Pubkey, AccountSharedData)>>();
+    let metas = input
+        .instruction_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: accounts[account.index_in_transaction as usize].0,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let instruction = Instruction::new_with_bytes(input.program_id, &input.instruction_data, metas);
+
+    let result = mollusk.process_instruction(&instruction, &accounts);
+
+    assert_eq!(
+        program_result(&result.raw_result),
+        (output.program_result, output.program_custom_code),
+        "Unexpected result for {}",
+        path.display(),
+    );
+    for (key, expected, _) in &output.modified_accounts {
+        assert_eq!(
+            result.get_account(key),
+            Some(expected),
+            "Unexpected account {} for {}",
+            key,
+            path.display(),
+        );
+    }
+}
+
+fn replay(paths: &[PathBuf], load: fn(&str) -> Fixture) {
+    let mut mollusk = setup();
+    for path in paths {
+        let fixture = load(path.to_str().unwrap());
+        process_and_validate_fixture(&mut mollusk, path, fixture);
+    }
+}
+
+#[test]
+fn replay_json_fixtures() {
+    replay(
+        &fixture_paths("fuzz/json", "json"),
+        Fixture::load_from_json_file,
+    );
+}
+
+#[test]
+fn replay_blob_fixtures() {
+    replay(
+        &fixture_paths("fuzz/blob", "fix"),
+        Fixture::load_from_blob_file,
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_state_account, setup, valid_elf},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        instruction::hash,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+#[test]
+fn fail_program_not_owned_by_loader() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &valid_elf(4);
+
+    // Incorrect owner.
+    let mut program_account = loader_v4_state_account(&state, elf);
+    program_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &hash(&program, 0, elf.len() as u32),
+        &[(program, program_account)],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_program_invalid_state() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+
+    // Invalid state.
+    let mut program_account =
+        AccountSharedData::new(100_000_000_000, 12, &solana_loader_v4_program::id());
+    program_account.set_data_from_slice(&[4; 12]);
+
+    mollusk.process_and_validate_instruction(
+        &hash(&program, 0, 0),
+        &[(program, program_account)],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn fail_read_out_of_bounds() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &valid_elf(4);
+
+    mollusk.process_and_validate_instruction(
+        &hash(&program, 8, elf.len() as u32), // Past the end.
+        &[(program, loader_v4_state_account(&state, elf))],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &valid_elf(4);
+
+    // The whole program data.
+    mollusk.process_and_validate_instruction(
+        &hash(&program, 0, elf.len() as u32),
+        &[(program, loader_v4_state_account(&state, elf))],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn success_range_of_finalized_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(),
+        status: LoaderV4Status::Finalized,
+    };
+    let elf = &valid_elf(4);
+
+    // Only part of the program data, without the state header.
+    mollusk.process_and_validate_instruction(
+        &hash(&program, 16, 64),
+        &[(program, loader_v4_state_account(&state, elf))],
+        &[Check::success()],
+    );
+}
@@ -0,0 +1,541 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_state_account, setup, valid_elf},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::EXTENSIONS_MAGIC,
+        instruction::migrate,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+/// Builds a loader-v3 program account by hand:
+/// `[tag: u32 = 2][programdata_address: Pubkey]`.
+fn v3_program_account(program_data: &Pubkey) -> AccountSharedData {
+    let mut data = 2u32.to_le_bytes().to_vec();
+    data.extend_from_slice(program_data.as_ref());
+
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &bpf_loader_upgradeable::id(),
+    );
+    account.set_data_from_slice(&data);
+    account.set_executable(true);
+    account
+}
+
+/// Builds a loader-v3 program data account by hand:
+/// `[tag: u32 = 3][slot: u64][option: u8][authority: Pubkey][elf]`.
+fn v3_program_data_account(authority: Option<&Pubkey>, elf: &[u8]) -> AccountSharedData {
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&42u64.to_le_bytes());
+    match authority {
+        Some(authority) => {
+            data.push(1);
+            data.extend_from_slice(authority.as_ref());
+        }
+        None => data.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0),
+    }
+    data.extend_from_slice(elf);
+
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &bpf_loader_upgradeable::id(),
+    );
+    account.set_data_from_slice(&data);
+    account
+}
+
+/// An empty program account funded for the migration of `elf`.
+fn empty_program_account(elf: &[u8]) -> AccountSharedData {
+    let lamports = Rent::default()
+        .minimum_balance(LoaderV4State::program_data_offset().saturating_add(elf.len()));
+    AccountSharedData::new(lamports, 0, &solana_loader_v4_program::id())
+}
+
+#[test]
+fn fail_program_not_owned_by_loader() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // Incorrect owner.
+    let mut program_account = empty_program_account(elf);
+    program_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_program_not_writable() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    let mut instruction = migrate(&program, &authority, &v3_program, &v3_program_data, false);
+    instruction.accounts[0].is_writable = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
+    );
+}
+
+#[test]
+fn fail_program_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    let mut instruction = migrate(&program, &authority, &v3_program, &v3_program_data, false);
+    instruction.accounts[0].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    let mut instruction = migrate(&program, &authority, &v3_program, &v3_program_data, false);
+    instruction.accounts[1].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_program_already_initialized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn fail_v3_program_not_owned_by_loader_v3() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // Incorrect owner.
+    let mut v3_program_account = v3_program_account(&v3_program_data);
+    v3_program_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_v3_program_data_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&Pubkey::new_unique())), // Mismatch.
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn fail_v3_program_data_not_owned_by_loader_v3() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // Incorrect owner.
+    let mut v3_program_data_account = v3_program_data_account(Some(&authority), elf);
+    v3_program_data_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (v3_program_data, v3_program_data_account),
+        ],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_v3_program_data_invalid_state() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // A program account where the program data account is expected.
+    let v3_program_data_account = v3_program_account(&v3_program_data);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (v3_program_data, v3_program_data_account),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn fail_v3_program_immutable() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(None, elf), // No upgrade authority.
+            ),
+        ],
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn fail_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&Pubkey::new_unique()), elf), // Mismatch.
+            ),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_program_insufficient_lamports() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // Not rent exempt after the migration.
+    let program_account = AccountSharedData::new(1, 0, &solana_loader_v4_program::id());
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn fail_invalid_elf() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &[4; 1_500]; // Not an ELF.
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, true),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn fail_forged_extension_trailer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+
+    // The program would read as one of the extended layout.
+    let mut elf = valid_elf(4);
+    elf.extend_from_slice(&EXTENSIONS_MAGIC);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(&elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), &elf),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn success_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &[4; 1_500]; // Not verified, since it is not deployed.
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Retracted,
+        }
+        .pack(&mut data)
+        .unwrap();
+        data.extend_from_slice(elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, false),
+        &[
+            (program, empty_program_account(elf)),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&v3_program_data)
+                .data(v3_program_data_account(Some(&authority), elf).data())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_deployed_preallocated() {
+    let mut mollusk = setup();
+    mollusk.warp_to_slot(1_000);
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let v3_program = Pubkey::new_unique();
+    let v3_program_data = Pubkey::new_unique();
+    let elf = &valid_elf(4);
+
+    // Allocated upfront with zeroed data, as done by the client.
+    let space = LoaderV4State::program_data_offset().saturating_add(elf.len());
+    let program_account = AccountSharedData::new(
+        Rent::default().minimum_balance(space),
+        space,
+        &solana_loader_v4_program::id(),
+    );
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        LoaderV4State {
+            slot: 1_000,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Deployed,
+        }
+        .pack(&mut data)
+        .unwrap();
+        data.extend_from_slice(elf);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program, &authority, &v3_program, &v3_program_data, true),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+            (v3_program, v3_program_account(&v3_program_data)),
+            (
+                v3_program_data,
+                v3_program_data_account(Some(&authority), elf),
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{extension_space, loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::ExtensionType,
+        instruction::propose_authority,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+/// A program account with enough lamports to hold a pending authority.
+fn funded_program_account(state: &LoaderV4State, elf: &[u8]) -> AccountSharedData {
+    let mut account = loader_v4_state_account(state, elf);
+    account
+        .set_lamports(Rent::default().minimum_balance(account.data().len() + extension_space(32)));
+    account
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = propose_authority(&program, &authority, &proposed_authority);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_program_finalized() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Finalized, // Finalized.
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn fail_no_change() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = authority; // No change.
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::AuthorityUnchanged.into())],
+    );
+}
+
+#[test]
+fn fail_insufficient_lamports() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            // Only rent-exempt without the extension.
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // The authority only changes once the proposal is accepted.
+    let check_data = loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::PendingAuthority, proposed_authority.as_ref())],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_replace_proposal() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proposed_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let check_data = loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::PendingAuthority, proposed_authority.as_ref())],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &propose_authority(&program, &authority, &proposed_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        Pubkey::new_unique().as_ref(), // Previous proposal.
+                    )],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+            (proposed_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
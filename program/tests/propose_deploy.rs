@@ -0,0 +1,295 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{
+        deploy_proposal_account, loader_v4_extended_account, loader_v4_state_account, setup,
+        system_account_with_lamports, valid_elf,
+    },
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig},
+        instruction::propose_deploy,
+        proposal::{find_proposal_address, DeployProposal},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        hash::hash,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+fn source_account(authority: &Pubkey, source_elf: &[u8]) -> AccountSharedData {
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: *authority,
+        status: LoaderV4Status::Retracted,
+    };
+    loader_v4_state_account(&source_state, source_elf)
+}
+
+#[test]
+fn fail_proposer_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    let mut instruction = propose_deploy(&program, &source, &authority, &payer);
+    instruction.accounts[3].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, AccountSharedData::default()),
+            (source, source_account(&authority, &valid_elf(8))),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_proposer_not_member() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![Pubkey::new_unique()],
+    };
+
+    mollusk.process_and_validate_instruction(
+        // The authority is no member once the program has a multisig.
+        &propose_deploy(&program, &source, &authority, &payer),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    &valid_elf(4),
+                ),
+            ),
+            (proposal, AccountSharedData::default()),
+            (source, source_account(&authority, &valid_elf(8))),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_source_program_not_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &propose_deploy(&program, &source, &authority, &payer),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, AccountSharedData::default()),
+            // Deployed.
+            (source, loader_v4_state_account(&state, &valid_elf(8))),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(LoaderV4Error::SourceProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_source_is_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &propose_deploy(&program, &program, &authority, &payer),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(LoaderV4Error::SourceIsProgram.into())],
+    );
+}
+
+#[test]
+fn fail_incorrect_proposal_address() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    let mut instruction = propose_deploy(&program, &source, &authority, &payer);
+    instruction.accounts[1].pubkey = proposal; // Not derived from the program.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, AccountSharedData::default()),
+            (source, source_account(&authority, &valid_elf(8))),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_elf = &valid_elf(8);
+
+    // Approved by the proposer, the program is left as is.
+    let check_proposal = deploy_proposal_account(&DeployProposal {
+        slot: mollusk.sysvars.clock.slot,
+        program_address: program,
+        source_address: source,
+        hash: hash(source_elf).to_bytes(),
+        payer_address: payer,
+        approvals: vec![authority],
+    });
+    let lamports = Rent::default().minimum_balance(DeployProposal::LEN);
+
+    mollusk.process_and_validate_instruction(
+        &propose_deploy(&program, &source, &authority, &payer),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, AccountSharedData::default()),
+            (source, source_account(&authority, source_elf)),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal)
+                .data(check_proposal.data())
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&program)
+                .data(loader_v4_state_account(&state, &valid_elf(4)).data())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_prefunded() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let (proposal, _) = find_proposal_address(&program);
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let lamports = Rent::default().minimum_balance(DeployProposal::LEN);
+
+    // Lamports sent to the proposal address beforehand do not block the
+    // proposal, they only reduce what the payer has to fund.
+    mollusk.process_and_validate_instruction(
+        &propose_deploy(&program, &source, &authority, &payer),
+        &[
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (proposal, system_account_with_lamports(1_000)),
+            (source, source_account(&authority, &valid_elf(8))),
+            (authority, AccountSharedData::default()),
+            (payer, system_account_with_lamports(100_000_000)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&proposal)
+                .lamports(lamports)
+                .owner(&solana_loader_v4_program::id())
+                .build(),
+            Check::account(&payer)
+                .lamports(100_000_000 - (lamports - 1_000))
+                .build(),
+        ],
+    );
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig},
+        instruction::{remove_multisig, with_multisig_signers},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+#[test]
+fn fail_multisig_not_signed() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![Pubkey::new_unique()],
+    };
+
+    mollusk.process_and_validate_instruction(
+        // Signed by the authority only.
+        &remove_multisig(&program, &authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_no_multisig() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &remove_multisig(&program, &authority),
+        &[
+            (program, loader_v4_state_account(&state, elf)), // No multisig.
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::NoMultisig.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![signer],
+    };
+
+    // The account keeps an empty extension trailer.
+    let check_data = loader_v4_extended_account(&state, &[], elf).data().to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(remove_multisig(&program, &authority), &[signer]),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+            (signer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
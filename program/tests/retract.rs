@@ -6,6 +6,7 @@ use {
     common::{loader_v4_state_account, setup},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
+        error::LoaderV4Error,
         instruction::retract,
         state::{LoaderV4State, LoaderV4Status, DEPLOYMENT_COOLDOWN_IN_SLOTS},
     },
@@ -66,6 +67,34 @@ fn fail_program_invalid_state() {
     );
 }
 
+#[test]
+fn fail_program_corrupt_state() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // Unknown status.
+    let mut program_account = loader_v4_state_account(&state, elf);
+    program_account.data_as_mut_slice()[0x28..0x30].copy_from_slice(&3u64.to_le_bytes());
+
+    mollusk.process_and_validate_instruction(
+        &retract(&program, &authority),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidProgramState.into())],
+    );
+}
+
 #[test]
 fn fail_program_not_writable() {
     let mollusk = setup();
@@ -89,7 +118,7 @@ fn fail_program_not_writable() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -189,7 +218,7 @@ fn fail_program_deployed_in_same_slot() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::DeploymentCooldown.into())],
     );
 }
 
@@ -214,7 +243,7 @@ fn fail_program_not_deployed() {
             (program, loader_v4_state_account(&state, elf)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotDeployed.into())],
     );
 }
 
@@ -235,13 +264,13 @@ fn success() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: 0,
-                authority_address_or_next_version: authority,
-                status: LoaderV4Status::Retracted,
-            };
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: authority,
+            status: LoaderV4Status::Retracted,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(elf);
         data
     };
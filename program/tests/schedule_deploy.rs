@@ -0,0 +1,260 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{
+        extension_space, loader_v4_extended_account, loader_v4_state_account, setup, valid_elf,
+    },
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, ScheduledDeploy},
+        instruction::schedule_deploy,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        hash::hash,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+/// A program account with enough lamports to hold a scheduled deployment.
+fn funded_program_account(state: &LoaderV4State, elf: &[u8]) -> AccountSharedData {
+    let mut account = loader_v4_state_account(state, elf);
+    account.set_lamports(
+        Rent::default()
+            .minimum_balance(account.data().len() + extension_space(ScheduledDeploy::LEN)),
+    );
+    account
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    let mut instruction = schedule_deploy(&program, &authority, &source, 1_000);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, funded_program_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&state, &valid_elf(8))),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_slot_not_in_future() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_state = LoaderV4State {
+        status: LoaderV4Status::Retracted,
+        ..state
+    };
+
+    // The deployment would be due right away.
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &source, mollusk.sysvars.clock.slot),
+        &[
+            (program, funded_program_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_state_account(&source_state, &valid_elf(8)),
+            ),
+        ],
+        &[Check::err(LoaderV4Error::ScheduledDeployNotInFuture.into())],
+    );
+}
+
+#[test]
+fn fail_source_is_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &program, 1_000),
+        &[
+            (program, funded_program_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::SourceIsProgram.into())],
+    );
+}
+
+#[test]
+fn fail_source_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
+        status: LoaderV4Status::Retracted,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &source, 1_000),
+        &[
+            (program, funded_program_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_state_account(&source_state, &valid_elf(8)),
+            ),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_source_program_not_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &source, 1_000),
+        &[
+            (program, funded_program_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            // Deployed.
+            (source, loader_v4_state_account(&state, &valid_elf(8))),
+        ],
+        &[Check::err(LoaderV4Error::SourceProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_insufficient_lamports() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_state = LoaderV4State {
+        status: LoaderV4Status::Retracted,
+        ..state
+    };
+
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &source, 1_000),
+        &[
+            // Only rent-exempt without the extension.
+            (program, loader_v4_state_account(&state, &valid_elf(4))),
+            (authority, AccountSharedData::default()),
+            (
+                source,
+                loader_v4_state_account(&source_state, &valid_elf(8)),
+            ),
+        ],
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let source_state = LoaderV4State {
+        status: LoaderV4Status::Retracted,
+        ..state
+    };
+    let elf = &valid_elf(4);
+    let source_elf = &valid_elf(8);
+
+    // The program is left as is until the deployment is executed.
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: source,
+        not_before_slot: 1_000,
+        hash: hash(source_elf).to_bytes(),
+    };
+    let check_data = loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::ScheduledDeploy, &scheduled_deploy.pack())],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &schedule_deploy(&program, &authority, &source, 1_000),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (source, loader_v4_state_account(&source_state, source_elf)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+            Check::account(&source)
+                .data(loader_v4_state_account(&source_state, source_elf).data())
+                .build(),
+        ],
+    );
+}
@@ -0,0 +1,278 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{extension_space, loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig},
+        instruction::{set_multisig, with_multisig_signers},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+/// A program account with enough lamports to hold a multisig of up to three
+/// signers.
+fn funded_program_account(state: &LoaderV4State, elf: &[u8]) -> AccountSharedData {
+    let mut account = loader_v4_state_account(state, elf);
+    account.set_lamports(
+        Rent::default().minimum_balance(account.data().len() + extension_space(1 + 3 * 32)),
+    );
+    account
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = set_multisig(&program, &authority, 2, &signers);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_threshold_too_high() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_multisig(&program, &authority, 3, &signers), // Unreachable.
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidMultisig.into())],
+    );
+}
+
+#[test]
+fn fail_duplicate_signers() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_multisig(&program, &authority, 2, &[signer, signer]),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidMultisig.into())],
+    );
+}
+
+#[test]
+fn fail_insufficient_lamports() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_multisig(&program, &authority, 2, &signers),
+        &[
+            // Only rent-exempt without the extension.
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    let check_data =
+        loader_v4_extended_account(&state, &[(ExtensionType::Multisig, &multisig.pack())], elf)
+            .data()
+            .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &set_multisig(&program, &authority, 2, &signers),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_rotate_below_threshold() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+
+    // The authority alone no longer suffices, nor does one signer.
+    let mut instruction = with_multisig_signers(
+        set_multisig(&program, &authority, 1, &[Pubkey::new_unique()]),
+        &signers[..1],
+    );
+    instruction.accounts[1].is_signer = true;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+            (signers[0], AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn success_rotate() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let new_signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    let multisig = Multisig {
+        threshold: 2,
+        signers: signers.to_vec(),
+    };
+    let new_multisig = Multisig {
+        threshold: 1,
+        signers: new_signers.to_vec(),
+    };
+    let check_data = loader_v4_extended_account(
+        &state,
+        &[(ExtensionType::Multisig, &new_multisig.pack())],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    // Two of the three current signers, without the authority.
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(
+            set_multisig(&program, &authority, 1, &new_signers),
+            &[signers[0], signers[2]],
+        ),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+            (signers[0], AccountSharedData::default()),
+            (signers[2], AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
@@ -0,0 +1,244 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{extension_space, loader_v4_extended_account, loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::ExtensionType,
+        instruction::set_writer,
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+};
+
+/// A program account with enough lamports to hold a writer.
+fn funded_program_account(state: &LoaderV4State, elf: &[u8]) -> AccountSharedData {
+    let mut account = loader_v4_state_account(state, elf);
+    account
+        .set_lamports(Rent::default().minimum_balance(account.data().len() + extension_space(32)));
+    account
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let mut instruction = set_writer(&program, &authority, &writer);
+    instruction.accounts[1].is_signer = false; // Not a signer.
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_writer_not_authority() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let new_writer = Pubkey::new_unique();
+
+    mollusk.process_and_validate_instruction(
+        // The writer can not replace itself.
+        &set_writer(&program, &writer, &new_writer),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (writer, AccountSharedData::default()),
+            (new_writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_writer_is_authority() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_writer(&program, &authority, &authority),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidWriter.into())],
+    );
+}
+
+#[test]
+fn fail_writer_is_program() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_writer(&program, &authority, &program),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidWriter.into())],
+    );
+}
+
+#[test]
+fn fail_insufficient_lamports() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &set_writer(&program, &authority, &writer),
+        &[
+            // Only rent-exempt without the extension.
+            (program, loader_v4_state_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let check_data =
+        loader_v4_extended_account(&state, &[(ExtensionType::Writer, writer.as_ref())], elf)
+            .data()
+            .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &set_writer(&program, &authority, &writer),
+        &[
+            (program, funded_program_account(&state, elf)),
+            (authority, AccountSharedData::default()),
+            (writer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_replace() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let old_writer = Pubkey::new_unique();
+    let new_writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    let check_data =
+        loader_v4_extended_account(&state, &[(ExtensionType::Writer, new_writer.as_ref())], elf)
+            .data()
+            .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &set_writer(&program, &authority, &new_writer),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, old_writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (authority, AccountSharedData::default()),
+            (new_writer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
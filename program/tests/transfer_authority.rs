@@ -3,14 +3,16 @@
 mod common;
 
 use {
-    common::{loader_v4_state_account, setup},
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
-        instruction::transfer_authority,
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig, ScheduledDeploy},
+        instruction::{transfer_authority, with_multisig_signers},
         state::{LoaderV4State, LoaderV4Status},
     },
     solana_sdk::{
-        account::{AccountSharedData, WritableAccount},
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -95,7 +97,7 @@ fn fail_program_not_writable() {
             (old_authority, AccountSharedData::default()),
             (new_authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -154,6 +156,40 @@ fn fail_authority_mismatch() {
     );
 }
 
+#[test]
+fn fail_writer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    mollusk.process_and_validate_instruction(
+        &transfer_authority(&program, &writer, &new_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (writer, AccountSharedData::default()),
+            (new_authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
 #[test]
 fn fail_program_finalized() {
     let mollusk = setup();
@@ -231,7 +267,7 @@ fn fail_no_change() {
             (old_authority, AccountSharedData::default()),
             (new_authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::AuthorityUnchanged.into())],
     );
 }
 
@@ -252,13 +288,13 @@ fn success() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = LoaderV4State {
-                slot: 0,
-                authority_address_or_next_version: new_authority,
-                status: LoaderV4Status::Deployed,
-            };
+        LoaderV4State {
+            slot: 0,
+            authority_address_or_next_version: new_authority,
+            status: LoaderV4Status::Deployed,
         }
+        .pack(&mut data)
+        .unwrap();
         data.extend_from_slice(elf);
         data
     };
@@ -277,3 +313,216 @@ fn success() {
         ],
     );
 }
+
+#[test]
+fn success_cancels_pending_authority() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let old_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: old_authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+
+    // The account keeps an empty extension trailer.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: new_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &transfer_authority(&program, &old_authority, &new_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(
+                        ExtensionType::PendingAuthority,
+                        Pubkey::new_unique().as_ref(),
+                    )],
+                    elf,
+                ),
+            ),
+            (old_authority, AccountSharedData::default()),
+            (new_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_removes_multisig() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let old_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: old_authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![signer],
+    };
+
+    // The new authority signs alone from now on, and the account keeps an
+    // empty extension trailer.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: new_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(
+            transfer_authority(&program, &old_authority, &new_authority),
+            &[signer],
+        ),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Multisig, &multisig.pack())],
+                    elf,
+                ),
+            ),
+            (old_authority, AccountSharedData::default()),
+            (new_authority, AccountSharedData::default()),
+            (signer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_removes_writer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let old_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: old_authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let elf = &[4; 1_500];
+
+    // The writer was delegated by the old authority. The account keeps an
+    // empty extension trailer.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: new_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &transfer_authority(&program, &old_authority, &new_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    elf,
+                ),
+            ),
+            (old_authority, AccountSharedData::default()),
+            (new_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_cancels_scheduled_deploy() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let old_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: old_authority,
+        status: LoaderV4Status::Deployed,
+    };
+    let elf = &[4; 1_500];
+    let scheduled_deploy = ScheduledDeploy {
+        source_address: Pubkey::new_unique(),
+        not_before_slot: 1_000,
+        hash: [7; 32],
+    };
+
+    // The deployment was scheduled by the old authority. The account keeps an
+    // empty extension trailer.
+    let check_data = loader_v4_extended_account(
+        &LoaderV4State {
+            authority_address_or_next_version: new_authority,
+            ..state
+        },
+        &[],
+        elf,
+    )
+    .data()
+    .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &transfer_authority(&program, &old_authority, &new_authority),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::ScheduledDeploy, &scheduled_deploy.pack())],
+                    elf,
+                ),
+            ),
+            (old_authority, AccountSharedData::default()),
+            (new_authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
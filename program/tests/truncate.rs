@@ -3,10 +3,12 @@
 mod common;
 
 use {
-    common::{loader_v4_state_account, setup},
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
-        instruction::truncate,
+        error::LoaderV4Error,
+        extension::{ExtensionType, Multisig, EXTENSIONS_MAGIC},
+        instruction::{truncate, with_multisig_signers},
         state::{LoaderV4State, LoaderV4Status},
     },
     solana_sdk::{
@@ -69,7 +71,7 @@ fn fail_initialization_program_not_writable() {
             ),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -148,9 +150,7 @@ fn success_initialization() {
 
     let check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = expected_state;
-        }
+        expected_state.pack(&mut data).unwrap();
         data.extend_from_slice(uninitialized_data);
         data
     };
@@ -240,7 +240,7 @@ fn fail_program_not_writable() {
             (program, loader_v4_state_account(&state, uninitialized_data)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -351,7 +351,7 @@ fn fail_program_not_retracted() {
             (program, loader_v4_state_account(&state, uninitialized_data)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
     );
 }
 
@@ -414,7 +414,37 @@ fn fail_destination_not_writable() {
             (authority, AccountSharedData::default()),
             (destination, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::DestinationNotWritable.into())],
+    );
+}
+
+#[test]
+fn fail_forged_extension_trailer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: mollusk.sysvars.clock.slot,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let mut program_data = vec![0; 28];
+    program_data.extend_from_slice(&EXTENSIONS_MAGIC);
+    program_data.extend_from_slice(&[0; 4]);
+
+    // Shrinking the program would make it read as one of the extended
+    // layout.
+    mollusk.process_and_validate_instruction(
+        &truncate(&program, &authority, Some(&destination), 36),
+        &[
+            (program, loader_v4_state_account(&state, &program_data)),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
     );
 }
 
@@ -435,9 +465,7 @@ fn success() {
 
     let mut check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = state;
-        }
+        state.pack(&mut data).unwrap();
         data.extend_from_slice(uninitialized_data);
         data
     };
@@ -504,3 +532,198 @@ fn success() {
         ],
     );
 }
+
+#[test]
+fn fail_writer_close() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::Writer, writer.as_ref())];
+
+    mollusk.process_and_validate_instruction(
+        &truncate(&program, &writer, Some(&writer), 0),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[4; 1_200]),
+            ),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_writer_shrink() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::Writer, writer.as_ref())];
+
+    // Shrinking would release lamports, which only the authority can
+    // withdraw.
+    mollusk.process_and_validate_instruction(
+        &truncate(&program, &writer, Some(&writer), 600),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[4; 1_200]),
+            ),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_multisig_no_destination() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![signer],
+    };
+    let extensions = [(ExtensionType::Multisig, &multisig.pack()[..])];
+
+    // The signer is not mistaken for the destination of the excess lamports.
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(truncate(&program, &authority, None, 600), &[signer]),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[4; 1_200]),
+            ),
+            (authority, AccountSharedData::default()),
+            (signer, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+fn success_multisig() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let multisig = Multisig {
+        threshold: 1,
+        signers: vec![signer],
+    };
+    let extensions = [(ExtensionType::Multisig, &multisig.pack()[..])];
+
+    let program_account = loader_v4_extended_account(&state, &extensions, &[4; 1_200]);
+    let shrunk_account = loader_v4_extended_account(&state, &extensions, &[4; 600]);
+
+    mollusk.process_and_validate_instruction(
+        &with_multisig_signers(
+            truncate(&program, &authority, Some(&destination), 600),
+            &[signer],
+        ),
+        &[
+            (program, program_account.clone()),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+            (signer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(shrunk_account.data())
+                .lamports(shrunk_account.lamports())
+                .build(),
+            Check::account(&destination)
+                .lamports(program_account.lamports() - shrunk_account.lamports())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_extended() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let pending_authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::PendingAuthority, pending_authority.as_ref())];
+
+    // The extension trailer moves along with the end of the program data,
+    // which is zeroed where it grows.
+    let mut grown_data = vec![4; 600];
+    grown_data.resize(1_200, 0);
+    let grown_account = loader_v4_extended_account(&state, &extensions, &grown_data);
+
+    let mut program_account = loader_v4_extended_account(&state, &extensions, &[4; 600]);
+    program_account.set_lamports(grown_account.lamports());
+
+    let result = mollusk.process_and_validate_instruction(
+        &truncate(&program, &authority, None, 1_200),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(grown_account.data()).build(),
+        ],
+    );
+
+    let shrunk_account = loader_v4_extended_account(&state, &extensions, &[4; 300]);
+
+    mollusk.process_and_validate_instruction(
+        &truncate(&program, &authority, Some(&destination), 300),
+        &[
+            (program, result.get_account(&program).unwrap().clone()),
+            (authority, AccountSharedData::default()),
+            (destination, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program)
+                .data(shrunk_account.data())
+                .lamports(shrunk_account.lamports())
+                .build(),
+        ],
+    );
+}
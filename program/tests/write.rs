@@ -3,14 +3,16 @@
 mod common;
 
 use {
-    common::{loader_v4_state_account, setup},
+    common::{loader_v4_extended_account, loader_v4_state_account, setup},
     mollusk_svm::result::Check,
     solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::{ExtensionType, EXTENSIONS_MAGIC},
         instruction::write,
         state::{LoaderV4State, LoaderV4Status},
     },
     solana_sdk::{
-        account::{AccountSharedData, WritableAccount},
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -89,7 +91,7 @@ fn fail_program_not_writable() {
             (program, loader_v4_state_account(&state, uninitialized_data)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotWritable.into())],
     );
 }
 
@@ -188,7 +190,32 @@ fn fail_program_not_retracted() {
             (program, loader_v4_state_account(&state, uninitialized_data)),
             (authority, AccountSharedData::default()),
         ],
-        &[Check::err(ProgramError::InvalidArgument)],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_forged_extension_trailer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // The program would read as one of the extended layout.
+    mollusk.process_and_validate_instruction(
+        &write(&program, &authority, 28, EXTENSIONS_MAGIC.to_vec()),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
     );
 }
 
@@ -208,9 +235,7 @@ fn success() {
 
     let mut check_data = {
         let mut data = vec![0; LoaderV4State::program_data_offset()];
-        {
-            *LoaderV4State::unpack_mut(&mut data).unwrap() = state;
-        }
+        state.pack(&mut data).unwrap();
         data.extend_from_slice(uninitialized_data);
         data
     };
@@ -253,3 +278,105 @@ fn success() {
         ],
     );
 }
+
+#[test]
+fn success_extended_layout() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::PendingAuthority, [9; 32].as_slice())];
+
+    // The offset is relative to the program data, which comes before the
+    // extension trailer.
+    let check_data = loader_v4_extended_account(&state, &extensions, &[0, 0, 4, 4])
+        .data()
+        .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &write(&program, &authority, 2, vec![4; 2]),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[0; 4]),
+            ),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_writer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let extensions = [(ExtensionType::Writer, writer.as_ref())];
+
+    let check_data = loader_v4_extended_account(&state, &extensions, &[4; 4])
+        .data()
+        .to_vec();
+
+    mollusk.process_and_validate_instruction(
+        &write(&program, &writer, 0, vec![4; 4]),
+        &[
+            (
+                program,
+                loader_v4_extended_account(&state, &extensions, &[0; 4]),
+            ),
+            (writer, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_writer_program_not_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let writer = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed,
+    };
+
+    mollusk.process_and_validate_instruction(
+        &write(&program, &writer, 0, vec![4; 4]),
+        &[
+            (
+                program,
+                loader_v4_extended_account(
+                    &state,
+                    &[(ExtensionType::Writer, writer.as_ref())],
+                    &[0; 4],
+                ),
+            ),
+            (writer, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
+    );
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use {
+    common::{loader_v4_state_account, setup},
+    mollusk_svm::result::Check,
+    solana_loader_v4_program::{
+        error::LoaderV4Error,
+        extension::EXTENSIONS_MAGIC,
+        instruction::{write_batch, WriteSegment},
+        state::{LoaderV4State, LoaderV4Status},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+fn segments() -> Vec<WriteSegment> {
+    vec![
+        WriteSegment {
+            offset: 0,
+            bytes: vec![4; 4],
+        },
+        WriteSegment {
+            offset: 20,
+            bytes: vec![8; 16],
+        },
+    ]
+}
+
+#[test]
+fn fail_program_not_owned_by_loader() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // Incorrect owner.
+    let mut program_account = loader_v4_state_account(&state, uninitialized_data);
+    program_account.set_owner(Pubkey::new_unique());
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments()),
+        &[
+            (program, program_account),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    let mut instruction = write_batch(&program, &authority, segments());
+    instruction.accounts[1].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_authority_mismatch() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: Pubkey::new_unique(), // Mismatch.
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments()),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_program_not_retracted() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Deployed, // Not retracted.
+    };
+    let uninitialized_data = &[0; 36];
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments()),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::ProgramNotRetracted.into())],
+    );
+}
+
+#[test]
+fn fail_segment_out_of_bounds() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // Only the last segment is out of bounds.
+    let mut segments = segments();
+    segments.push(WriteSegment {
+        offset: 30,
+        bytes: vec![12; 8],
+    });
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::AccountDataTooSmall)],
+    );
+}
+
+#[test]
+fn fail_forged_extension_trailer() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // The program would read as one of the extended layout.
+    let mut segments = segments();
+    segments.push(WriteSegment {
+        offset: 28,
+        bytes: EXTENSIONS_MAGIC.to_vec(),
+    });
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[Check::err(LoaderV4Error::InvalidElf.into())],
+    );
+}
+
+#[test]
+fn success() {
+    let mollusk = setup();
+
+    let program = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let state = LoaderV4State {
+        slot: 0,
+        authority_address_or_next_version: authority,
+        status: LoaderV4Status::Retracted,
+    };
+    let uninitialized_data = &[0; 36];
+
+    // Overlapping segments are written in order.
+    let mut segments = segments();
+    segments.push(WriteSegment {
+        offset: 32,
+        bytes: vec![12; 4],
+    });
+
+    let check_data = {
+        let mut data = vec![0; LoaderV4State::program_data_offset()];
+        state.pack(&mut data).unwrap();
+        data.extend_from_slice(&[4; 4]);
+        data.extend_from_slice(&[0; 16]);
+        data.extend_from_slice(&[8; 12]);
+        data.extend_from_slice(&[12; 4]);
+        data
+    };
+
+    mollusk.process_and_validate_instruction(
+        &write_batch(&program, &authority, segments),
+        &[
+            (program, loader_v4_state_account(&state, uninitialized_data)),
+            (authority, AccountSharedData::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&program).data(&check_data).build(),
+        ],
+    );
+}
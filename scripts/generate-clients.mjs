@@ -19,6 +19,57 @@ kinobi.update(
   })
 );
 
+// The program decodes instructions with bincode: enum tags are `u32` and
+// byte vectors are prefixed with a `u64` length.
+kinobi.update(
+  k.bottomUpTransformerVisitor([
+    {
+      select: "[instructionArgumentNode]discriminator",
+      transform: (node) =>
+        k.instructionArgumentNode({
+          ...node,
+          type: k.numberTypeNode("u32"),
+        }),
+    },
+    {
+      select: "[instructionNode]write.[instructionArgumentNode]bytes",
+      transform: (node) =>
+        k.instructionArgumentNode({
+          ...node,
+          type: k.sizePrefixTypeNode(k.bytesTypeNode(), k.numberTypeNode("u64")),
+        }),
+    },
+    {
+      select: "[instructionNode]writeBatch.[instructionArgumentNode]segments",
+      transform: (node) =>
+        k.instructionArgumentNode({
+          ...node,
+          type: k.arrayTypeNode(
+            k.definedTypeLinkNode("writeSegment"),
+            k.prefixedCountNode(k.numberTypeNode("u64"))
+          ),
+        }),
+    },
+    {
+      select: "[definedTypeNode]writeSegment.[structFieldTypeNode]bytes",
+      transform: (node) =>
+        k.structFieldTypeNode({
+          ...node,
+          type: k.sizePrefixTypeNode(k.bytesTypeNode(), k.numberTypeNode("u64")),
+        }),
+    },
+  ])
+);
+
+// Optional accounts are omitted rather than replaced by the program ID, since
+// the program treats any account in that position as provided.
+kinobi.update(
+  k.updateInstructionsVisitor({
+    deploy: { optionalAccountStrategy: "omitted" },
+    truncate: { optionalAccountStrategy: "omitted" },
+  })
+);
+
 // Render JavaScript.
 const jsClient = path.join(__dirname, "..", "clients", "js");
 kinobi.accept(
@@ -28,6 +79,35 @@ kinobi.accept(
 );
 
 // Render Rust.
+// Borsh cannot express a `u64` length prefix, so use a hooked type instead.
+kinobi.update(
+  k.bottomUpTransformerVisitor([
+    {
+      select: "[instructionNode]write.[instructionArgumentNode]bytes",
+      transform: (node) =>
+        k.instructionArgumentNode({
+          ...node,
+          type: k.definedTypeLinkNode("u64PrefixBytes", "hooked"),
+        }),
+    },
+    {
+      select: "[instructionNode]writeBatch.[instructionArgumentNode]segments",
+      transform: (node) =>
+        k.instructionArgumentNode({
+          ...node,
+          type: k.definedTypeLinkNode("writeSegments", "hooked"),
+        }),
+    },
+    {
+      select: "[definedTypeNode]writeSegment.[structFieldTypeNode]bytes",
+      transform: (node) =>
+        k.structFieldTypeNode({
+          ...node,
+          type: k.definedTypeLinkNode("u64PrefixBytes", "hooked"),
+        }),
+    },
+  ])
+);
 const rustClient = path.join(__dirname, "..", "clients", "rust");
 kinobi.accept(
   renderRustVisitor(path.join(rustClient, "src", "generated"), {
@@ -0,0 +1,49 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import {
+  cliArguments,
+  getProgramFolders,
+  workingDirectory,
+} from '../utils.mjs';
+
+// Configure additional arguments here, e.g.:
+// ['--arg1', '--arg2', ...cliArguments()]
+const testArgs = [
+  '--features',
+  'bpf-entrypoint',
+  ...cliArguments(),
+  '--',
+  // Replaying fixtures does not produce new ones.
+  '--skip',
+  'replay_',
+  // Fixtures are named after their content, which includes the addresses
+  // handed out in order by `Pubkey::new_unique`.
+  '--test-threads=1',
+];
+
+// Regenerate the fuzz fixtures of the programs from their tests. Mollusk
+// serializes every instruction processed through
+// `process_and_validate_instruction` into the directories below, in the
+// Firedancer format the fixture tests replay. They are generated in a
+// temporary directory first and only copied into the corpus once all tests
+// passed, replacing the fixtures of the same name, so a failed run keeps the
+// corpus intact and existing fixtures are never deleted.
+for (const folder of getProgramFolders()) {
+  const manifestPath = path.join(workingDirectory, folder, 'Cargo.toml');
+  const fuzzDir = path.join(workingDirectory, folder, 'fuzz');
+  const tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), 'fixtures-'));
+  const tmpBlobDir = path.join(tmpDir, 'blob');
+  const tmpJsonDir = path.join(tmpDir, 'json');
+
+  try {
+    await $`mkdir -p ${tmpBlobDir} ${tmpJsonDir}`;
+    await $`RUST_LOG=error EJECT_FUZZ_FIXTURES_FD=${tmpBlobDir} EJECT_FUZZ_FIXTURES_JSON_FD=${tmpJsonDir} cargo test-sbf --manifest-path ${manifestPath} ${testArgs}`;
+    for (const dir of ['blob', 'json']) {
+      await fs.copy(path.join(tmpDir, dir), path.join(fuzzDir, dir), {
+        overwrite: true,
+      });
+    }
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}